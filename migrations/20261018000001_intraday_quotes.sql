@@ -0,0 +1,17 @@
+-- 創建 intraday_quotes 表 (盤中即時報價快照)
+CREATE TABLE IF NOT EXISTS intraday_quotes (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    quote_time TIMESTAMPTZ NOT NULL,
+    last_price NUMERIC(10, 2),
+    accumulated_volume NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    open NUMERIC(10, 2),
+    high NUMERIC(10, 2),
+    low NUMERIC(10, 2),
+    prev_close NUMERIC(10, 2),
+    bids JSONB NOT NULL DEFAULT '[]',
+    asks JSONB NOT NULL DEFAULT '[]',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, quote_time)
+);
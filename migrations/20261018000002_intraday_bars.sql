@@ -0,0 +1,18 @@
+-- 創建 intraday_bars 表 (盤中分鐘 K 棒)，依 start_time 分區
+CREATE TABLE IF NOT EXISTS intraday_bars (
+    id UUID NOT NULL,
+    stock_id UUID NOT NULL REFERENCES stocks(id),
+    bar_interval VARCHAR(5) NOT NULL,
+    start_time TIMESTAMPTZ NOT NULL,
+    open NUMERIC(10, 2) NOT NULL,
+    high NUMERIC(10, 2) NOT NULL,
+    low NUMERIC(10, 2) NOT NULL,
+    close NUMERIC(10, 2) NOT NULL,
+    volume NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (stock_id, bar_interval, start_time)
+) PARTITION BY RANGE (start_time);
+
+-- 每月分區由程式在寫入前自動建立，預設分區承接其餘資料
+CREATE TABLE IF NOT EXISTS intraday_bars_default PARTITION OF intraday_bars DEFAULT;
@@ -0,0 +1,16 @@
+-- 創建 index_levels 表 (大盤及類股指數每日點位)
+CREATE TABLE IF NOT EXISTS index_levels (
+    id UUID PRIMARY KEY,
+    index_code VARCHAR(30) NOT NULL,
+    index_name VARCHAR(100) NOT NULL,
+    date DATE NOT NULL,
+    open NUMERIC(12, 2),
+    high NUMERIC(12, 2),
+    low NUMERIC(12, 2),
+    close NUMERIC(12, 2) NOT NULL,
+    change NUMERIC(12, 2) NOT NULL DEFAULT 0.0,
+    change_percent NUMERIC(10, 2) NOT NULL DEFAULT 0.0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(index_code, date)
+);
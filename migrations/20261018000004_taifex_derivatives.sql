@@ -0,0 +1,57 @@
+-- 創建 futures_daily_prices 表 (期貨每日行情，一般交易時段)
+CREATE TABLE IF NOT EXISTS futures_daily_prices (
+    id UUID PRIMARY KEY,
+    contract_code VARCHAR(10) NOT NULL,
+    delivery_month VARCHAR(10) NOT NULL,
+    date DATE NOT NULL,
+    open NUMERIC(12, 2),
+    high NUMERIC(12, 2),
+    low NUMERIC(12, 2),
+    close NUMERIC(12, 2),
+    settlement_price NUMERIC(12, 2),
+    volume NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    open_interest NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(contract_code, delivery_month, date)
+);
+
+-- 創建 options_daily_prices 表 (選擇權每日行情，一般交易時段)
+CREATE TABLE IF NOT EXISTS options_daily_prices (
+    id UUID PRIMARY KEY,
+    contract_code VARCHAR(10) NOT NULL,
+    delivery_month VARCHAR(10) NOT NULL,
+    strike_price NUMERIC(12, 2) NOT NULL,
+    option_type VARCHAR(4) NOT NULL,
+    date DATE NOT NULL,
+    open NUMERIC(12, 2),
+    high NUMERIC(12, 2),
+    low NUMERIC(12, 2),
+    close NUMERIC(12, 2),
+    settlement_price NUMERIC(12, 2),
+    volume NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    open_interest NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(contract_code, delivery_month, strike_price, option_type, date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_options_daily_prices_contract_date
+    ON options_daily_prices (contract_code, date);
+
+-- 創建 futures_institutional_open_interest 表 (期貨三大法人未平倉)
+CREATE TABLE IF NOT EXISTS futures_institutional_open_interest (
+    id UUID PRIMARY KEY,
+    contract_code VARCHAR(10) NOT NULL,
+    date DATE NOT NULL,
+    institution_type VARCHAR(20) NOT NULL,
+    long_open_interest BIGINT NOT NULL DEFAULT 0,
+    short_open_interest BIGINT NOT NULL DEFAULT 0,
+    net_open_interest BIGINT NOT NULL DEFAULT 0,
+    long_value BIGINT NOT NULL DEFAULT 0,
+    short_value BIGINT NOT NULL DEFAULT 0,
+    net_value BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(contract_code, date, institution_type)
+);
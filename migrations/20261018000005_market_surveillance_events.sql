@@ -0,0 +1,19 @@
+-- 創建 market_surveillance_events 表 (注意股、處置股公告)
+CREATE TABLE IF NOT EXISTS market_surveillance_events (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    market VARCHAR(10) NOT NULL,
+    event_type VARCHAR(20) NOT NULL,
+    announced_date DATE NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    reason TEXT NOT NULL DEFAULT '',
+    measure VARCHAR(100),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(market, stock_code, event_type, start_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_market_surveillance_events_period
+    ON market_surveillance_events (start_date, end_date);
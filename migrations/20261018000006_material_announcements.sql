@@ -0,0 +1,14 @@
+-- 創建 material_announcements 表 (公開資訊觀測站重大訊息)
+CREATE TABLE IF NOT EXISTS material_announcements (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    company_name VARCHAR(100) NOT NULL,
+    announced_at TIMESTAMPTZ NOT NULL,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL DEFAULT '',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, announced_at, title)
+);
+
+CREATE INDEX IF NOT EXISTS idx_material_announcements_announced_at
+    ON material_announcements (announced_at);
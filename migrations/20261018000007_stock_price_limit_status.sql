@@ -0,0 +1,5 @@
+-- 記錄收盤價是否為漲停或跌停 (LIMIT_UP / LIMIT_DOWN)
+ALTER TABLE stock_prices ADD COLUMN IF NOT EXISTS limit_status VARCHAR(10);
+
+CREATE INDEX IF NOT EXISTS idx_stock_prices_limit_status ON stock_prices(date, limit_status)
+    WHERE limit_status IS NOT NULL;
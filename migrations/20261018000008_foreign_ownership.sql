@@ -0,0 +1,15 @@
+-- 創建 foreign_ownership 表 (個股外資及陸資持股比率)
+CREATE TABLE IF NOT EXISTS foreign_ownership (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    date DATE NOT NULL,
+    issued_shares NUMERIC(20, 0) NOT NULL,
+    available_shares NUMERIC(20, 0) NOT NULL,
+    foreign_held_shares NUMERIC(20, 0) NOT NULL,
+    foreign_held_ratio NUMERIC(6, 2) NOT NULL,
+    foreign_limit_ratio NUMERIC(6, 2) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, date)
+);
@@ -0,0 +1,14 @@
+-- 創建 day_trading_stats 表 (個股當日沖銷交易統計)
+CREATE TABLE IF NOT EXISTS day_trading_stats (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    date DATE NOT NULL,
+    volume NUMERIC(20, 0) NOT NULL,
+    buy_amount NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    sell_amount NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    sell_first_suspended BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, date)
+);
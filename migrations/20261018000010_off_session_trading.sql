@@ -0,0 +1,36 @@
+-- 創建 odd_lot_quotes 表 (盤中零股每日行情)
+CREATE TABLE IF NOT EXISTS odd_lot_quotes (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    date DATE NOT NULL,
+    open NUMERIC(10, 2),
+    high NUMERIC(10, 2),
+    low NUMERIC(10, 2),
+    close NUMERIC(10, 2),
+    volume NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    transactions INTEGER NOT NULL DEFAULT 0,
+    turnover NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    last_bid_price NUMERIC(10, 2),
+    last_ask_price NUMERIC(10, 2),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, date)
+);
+
+-- 創建 after_hours_trades 表 (盤後定價交易)
+CREATE TABLE IF NOT EXISTS after_hours_trades (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    date DATE NOT NULL,
+    price NUMERIC(10, 2),
+    volume NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    transactions INTEGER NOT NULL DEFAULT 0,
+    turnover NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    last_bid_volume NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    last_ask_volume NUMERIC(20, 0) NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, date)
+);
@@ -0,0 +1,15 @@
+-- 創建 block_trades 表 (鉅額交易成交紀錄)
+CREATE TABLE IF NOT EXISTS block_trades (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    date DATE NOT NULL,
+    trade_type VARCHAR(20) NOT NULL,
+    price NUMERIC(10, 2) NOT NULL,
+    volume NUMERIC(20, 0) NOT NULL,
+    turnover NUMERIC(20, 0) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_block_trades_stock_code_date ON block_trades (stock_code, date);
+CREATE INDEX IF NOT EXISTS idx_block_trades_date ON block_trades (date);
@@ -0,0 +1,15 @@
+-- 創建 insider_holdings 表 (董監事每月持股及設質彙總)
+CREATE TABLE IF NOT EXISTS insider_holdings (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    company_name VARCHAR(100) NOT NULL,
+    period DATE NOT NULL,
+    insider_shares NUMERIC(20, 0) NOT NULL,
+    pledged_shares NUMERIC(20, 0) NOT NULL,
+    issued_shares NUMERIC(20, 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, period)
+);
+
+CREATE INDEX IF NOT EXISTS idx_insider_holdings_period ON insider_holdings (period);
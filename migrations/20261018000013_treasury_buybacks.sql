@@ -0,0 +1,19 @@
+-- 創建 treasury_buybacks 表 (庫藏股買回計畫及執行情形)
+CREATE TABLE IF NOT EXISTS treasury_buybacks (
+    id UUID PRIMARY KEY,
+    stock_id UUID NOT NULL REFERENCES stocks(id),
+    company_name VARCHAR(100) NOT NULL,
+    resolution_date DATE NOT NULL,
+    purpose VARCHAR(100) NOT NULL,
+    planned_shares NUMERIC(20, 0) NOT NULL,
+    price_low NUMERIC(10, 2) NOT NULL,
+    price_high NUMERIC(10, 2) NOT NULL,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    executed_shares NUMERIC(20, 0) NOT NULL,
+    executed_amount NUMERIC(20, 0) NOT NULL,
+    is_completed BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_id, resolution_date)
+);
@@ -0,0 +1,22 @@
+-- 創建 public_subscriptions 表 (公開申購抽籤公告)
+CREATE TABLE IF NOT EXISTS public_subscriptions (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    market VARCHAR(20) NOT NULL,
+    subscription_start DATE NOT NULL,
+    subscription_end DATE NOT NULL,
+    lottery_date DATE NOT NULL,
+    listing_date DATE,
+    offer_price NUMERIC(10, 2) NOT NULL,
+    shares_offered NUMERIC(20, 0) NOT NULL,
+    shares_per_application NUMERIC(20, 0) NOT NULL,
+    win_rate NUMERIC(8, 4),
+    is_cancelled BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, lottery_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_public_subscriptions_lottery_date ON public_subscriptions (lottery_date);
+CREATE INDEX IF NOT EXISTS idx_public_subscriptions_listing_date ON public_subscriptions (listing_date);
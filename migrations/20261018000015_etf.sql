@@ -0,0 +1,28 @@
+-- 創建 etf_navs 表 (ETF 每日淨值)
+CREATE TABLE IF NOT EXISTS etf_navs (
+    id UUID PRIMARY KEY,
+    etf_code VARCHAR(10) NOT NULL,
+    etf_name VARCHAR(100) NOT NULL,
+    date DATE NOT NULL,
+    nav NUMERIC(12, 4) NOT NULL,
+    close NUMERIC(10, 2),
+    issued_units NUMERIC(20, 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(etf_code, date)
+);
+
+-- 創建 etf_constituents 表 (ETF 成分股及權重)
+CREATE TABLE IF NOT EXISTS etf_constituents (
+    id UUID PRIMARY KEY,
+    etf_code VARCHAR(10) NOT NULL,
+    date DATE NOT NULL,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    weight NUMERIC(8, 4) NOT NULL,
+    shares NUMERIC(20, 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(etf_code, date, stock_code)
+);
+
+CREATE INDEX IF NOT EXISTS idx_etf_constituents_stock_code ON etf_constituents (stock_code);
@@ -0,0 +1,30 @@
+-- 創建 convertible_bonds 表 (可轉換公司債發行資料)
+CREATE TABLE IF NOT EXISTS convertible_bonds (
+    id UUID PRIMARY KEY,
+    bond_code VARCHAR(10) NOT NULL UNIQUE,
+    bond_name VARCHAR(100) NOT NULL,
+    underlying_code VARCHAR(10) NOT NULL,
+    stock_id UUID REFERENCES stocks(id),
+    conversion_price NUMERIC(10, 2) NOT NULL,
+    outstanding_amount NUMERIC(20, 0) NOT NULL,
+    issue_date DATE,
+    maturity_date DATE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_convertible_bonds_underlying_code ON convertible_bonds (underlying_code);
+
+-- 創建 convertible_bond_quotes 表 (可轉換公司債每日行情及轉換溢價率)
+CREATE TABLE IF NOT EXISTS convertible_bond_quotes (
+    id UUID PRIMARY KEY,
+    bond_code VARCHAR(10) NOT NULL,
+    date DATE NOT NULL,
+    close NUMERIC(10, 2),
+    volume NUMERIC(20, 0) NOT NULL,
+    underlying_close NUMERIC(10, 2),
+    conversion_premium NUMERIC(10, 2),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(bond_code, date)
+);
@@ -0,0 +1,22 @@
+-- 創建 stock_shares 表 (已發行股數異動紀錄，每筆自 effective_date 起生效)
+CREATE TABLE IF NOT EXISTS stock_shares (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    effective_date DATE NOT NULL,
+    issued_shares NUMERIC(20, 0) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, effective_date)
+);
+
+-- 以既有外資持股資料中的發行股數回填，只保留股數變動的日期
+INSERT INTO stock_shares (id, stock_code, effective_date, issued_shares)
+SELECT gen_random_uuid(), stock_code, date, issued_shares
+FROM (
+    SELECT
+        stock_code, date, issued_shares,
+        LAG(issued_shares) OVER (PARTITION BY stock_code ORDER BY date) AS previous_shares
+    FROM foreign_ownership
+) f
+WHERE previous_shares IS NULL OR previous_shares <> issued_shares
+ON CONFLICT (stock_code, effective_date) DO NOTHING;
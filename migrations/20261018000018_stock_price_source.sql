@@ -0,0 +1,2 @@
+-- 記錄每筆股價的資料來源 (twse: 證交所, yahoo: Yahoo Finance 備援)
+ALTER TABLE stock_prices ADD COLUMN IF NOT EXISTS source VARCHAR(10) NOT NULL DEFAULT 'twse';
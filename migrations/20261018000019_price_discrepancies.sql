@@ -0,0 +1,17 @@
+-- 創建 price_discrepancies 表 (同一股票同一日期不同資料來源的 OHLCV 差異)
+CREATE TABLE IF NOT EXISTS price_discrepancies (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    date DATE NOT NULL,
+    field VARCHAR(10) NOT NULL,
+    primary_source VARCHAR(10) NOT NULL,
+    primary_value NUMERIC(20, 4) NOT NULL,
+    secondary_source VARCHAR(10) NOT NULL,
+    secondary_value NUMERIC(20, 4) NOT NULL,
+    diff_percent NUMERIC(10, 2) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, date, field, secondary_source)
+);
+
+CREATE INDEX IF NOT EXISTS idx_price_discrepancies_date ON price_discrepancies (date);
@@ -0,0 +1,17 @@
+-- 創建 quarantined_prices 表 (未通過驗證、不寫入 stock_prices 的日 K)
+CREATE TABLE IF NOT EXISTS quarantined_prices (
+    id UUID PRIMARY KEY,
+    stock_id UUID NOT NULL REFERENCES stocks(id),
+    date DATE NOT NULL,
+    source VARCHAR(10) NOT NULL,
+    open NUMERIC(10, 2) NOT NULL,
+    high NUMERIC(10, 2) NOT NULL,
+    low NUMERIC(10, 2) NOT NULL,
+    close NUMERIC(10, 2) NOT NULL,
+    volume NUMERIC(20, 0) NOT NULL,
+    turnover NUMERIC(20, 0) NOT NULL,
+    reasons TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_id, date, source)
+);
@@ -0,0 +1,14 @@
+-- 創建 price_gaps 表 (依交易日曆比對缺漏的個股日 K 及補抓紀錄)
+CREATE TABLE IF NOT EXISTS price_gaps (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    date DATE NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'missing',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_attempt_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_price_gaps_status ON price_gaps (status);
@@ -0,0 +1,24 @@
+-- 股票上市狀態 (active 上市中、suspended 暫停交易、delisted 已下市) 及狀態異動日期
+ALTER TABLE stocks ADD COLUMN IF NOT EXISTS status VARCHAR(10) NOT NULL DEFAULT 'active';
+ALTER TABLE stocks ADD COLUMN IF NOT EXISTS listed_date DATE;
+ALTER TABLE stocks ADD COLUMN IF NOT EXISTS status_changed_on DATE;
+
+-- 創建 stock_name_history 表 (股票更名紀錄)
+CREATE TABLE IF NOT EXISTS stock_name_history (
+    id UUID PRIMARY KEY,
+    stock_id UUID NOT NULL REFERENCES stocks(id),
+    previous_name VARCHAR(100) NOT NULL,
+    new_name VARCHAR(100) NOT NULL,
+    changed_on DATE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_id, changed_on, new_name)
+);
+
+-- 創建 isin_listings 表 (最近一次 ISIN 上市櫃清單快照，用於比對前後兩次清單的差異)
+CREATE TABLE IF NOT EXISTS isin_listings (
+    code VARCHAR(10) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    listed_date DATE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,18 @@
+-- ISIN 清單快照加入市場別及產業別，用於偵測轉上市及產業別變更
+ALTER TABLE isin_listings ADD COLUMN IF NOT EXISTS market VARCHAR(10);
+ALTER TABLE isin_listings ADD COLUMN IF NOT EXISTS industry VARCHAR(50);
+
+-- 創建 universe_changes 表 (上市櫃股票異動紀錄)
+CREATE TABLE IF NOT EXISTS universe_changes (
+    id UUID PRIMARY KEY,
+    stock_code VARCHAR(10) NOT NULL,
+    stock_name VARCHAR(100) NOT NULL,
+    change_type VARCHAR(20) NOT NULL,
+    previous_value VARCHAR(100),
+    new_value VARCHAR(100),
+    detected_on DATE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(stock_code, change_type, detected_on)
+);
+
+CREATE INDEX IF NOT EXISTS idx_universe_changes_detected_on ON universe_changes (detected_on);
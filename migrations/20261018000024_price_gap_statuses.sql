@@ -0,0 +1,2 @@
+-- 擴充 price_gaps.status 長度 (新增 no_trade 及 quarantined 狀態)
+ALTER TABLE price_gaps ALTER COLUMN status TYPE VARCHAR(12);
@@ -0,0 +1,5 @@
+-- 創建 insider_holding_periods 表 (已爬取完所有公司的董監持股月份)
+CREATE TABLE IF NOT EXISTS insider_holding_periods (
+    period DATE PRIMARY KEY,
+    completed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
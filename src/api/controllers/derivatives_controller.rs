@@ -0,0 +1,136 @@
+use crate::application::dtos::{
+    FuturesBasisDto, FuturesDailyPriceDto, InstitutionalOpenInterestDto, PutCallRatioDto,
+};
+use crate::application::services::DerivativesService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct DerivativesController {
+    derivatives_service: Arc<DerivativesService>,
+}
+
+impl DerivativesController {
+    pub fn new(derivatives_service: Arc<DerivativesService>) -> Self {
+        Self { derivatives_service }
+    }
+
+    pub async fn get_futures_prices(
+        &self,
+        contract_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<FuturesDailyPriceDto>> {
+        self.derivatives_service
+            .get_futures_prices(contract_code, start_date, end_date)
+            .await
+    }
+
+    pub async fn get_institutional_open_interest(
+        &self,
+        contract_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<InstitutionalOpenInterestDto>> {
+        self.derivatives_service
+            .get_institutional_open_interest(contract_code, start_date, end_date)
+            .await
+    }
+
+    pub async fn get_futures_basis(
+        &self,
+        contract_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<FuturesBasisDto>> {
+        self.derivatives_service
+            .get_futures_basis(contract_code, start_date, end_date)
+            .await
+    }
+
+    pub async fn get_put_call_ratios(
+        &self,
+        contract_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<PutCallRatioDto>> {
+        self.derivatives_service
+            .get_put_call_ratios(contract_code, start_date, end_date)
+            .await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct DerivativesQuery {
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_futures_prices(
+    State(state): State<AppState>,
+    Path(contract): Path<String>,
+    Query(query): Query<DerivativesQuery>,
+) -> std::result::Result<Json<Vec<FuturesDailyPriceDto>>, StatusCode> {
+    match state.derivatives_controller.get_futures_prices(
+        &contract,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(prices) => Ok(Json(prices)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+pub async fn get_institutional_open_interest(
+    State(state): State<AppState>,
+    Path(contract): Path<String>,
+    Query(query): Query<DerivativesQuery>,
+) -> std::result::Result<Json<Vec<InstitutionalOpenInterestDto>>, StatusCode> {
+    match state.derivatives_controller.get_institutional_open_interest(
+        &contract,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(positions) => Ok(Json(positions)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+pub async fn get_futures_basis(
+    State(state): State<AppState>,
+    Path(contract): Path<String>,
+    Query(query): Query<DerivativesQuery>,
+) -> std::result::Result<Json<Vec<FuturesBasisDto>>, StatusCode> {
+    match state.derivatives_controller.get_futures_basis(
+        &contract,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(basis) => Ok(Json(basis)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+pub async fn get_put_call_ratios(
+    State(state): State<AppState>,
+    Path(contract): Path<String>,
+    Query(query): Query<DerivativesQuery>,
+) -> std::result::Result<Json<Vec<PutCallRatioDto>>, StatusCode> {
+    match state.derivatives_controller.get_put_call_ratios(
+        &contract,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(ratios) => Ok(Json(ratios)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
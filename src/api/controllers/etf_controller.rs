@@ -0,0 +1,78 @@
+use crate::application::dtos::{EtfConstituentDto, EtfNavDto};
+use crate::application::services::EtfService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct EtfController {
+    etf_service: Arc<EtfService>,
+}
+
+impl EtfController {
+    pub fn new(etf_service: Arc<EtfService>) -> Self {
+        Self { etf_service }
+    }
+
+    pub async fn get_navs(
+        &self,
+        etf_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<EtfNavDto>> {
+        self.etf_service.get_navs(etf_code, start_date, end_date).await
+    }
+
+    pub async fn get_constituents(&self, etf_code: &str) -> DomainResult<Vec<EtfConstituentDto>> {
+        self.etf_service.get_constituents(etf_code).await
+    }
+
+    pub async fn get_holders(&self, stock_code: &str) -> DomainResult<Vec<EtfConstituentDto>> {
+        self.etf_service.get_holders(stock_code).await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct EtfNavQuery {
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_etf_navs(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<EtfNavQuery>,
+) -> std::result::Result<Json<Vec<EtfNavDto>>, StatusCode> {
+    match state.etf_controller.get_navs(&code, query.start_date, query.end_date).await {
+        Ok(navs) => Ok(Json(navs)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+pub async fn get_etf_constituents(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> std::result::Result<Json<Vec<EtfConstituentDto>>, StatusCode> {
+    match state.etf_controller.get_constituents(&code).await {
+        Ok(constituents) => Ok(Json(constituents)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+pub async fn get_etf_holders(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> std::result::Result<Json<Vec<EtfConstituentDto>>, StatusCode> {
+    match state.etf_controller.get_holders(&code).await {
+        Ok(holders) => Ok(Json(holders)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -0,0 +1,56 @@
+use crate::application::dtos::ForeignOwnershipDto;
+use crate::application::services::ForeignOwnershipService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct ForeignOwnershipController {
+    foreign_ownership_service: Arc<ForeignOwnershipService>,
+}
+
+impl ForeignOwnershipController {
+    pub fn new(foreign_ownership_service: Arc<ForeignOwnershipService>) -> Self {
+        Self { foreign_ownership_service }
+    }
+
+    pub async fn get_foreign_ownership(
+        &self,
+        stock_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<ForeignOwnershipDto>> {
+        self.foreign_ownership_service
+            .get_foreign_ownership(stock_code, start_date, end_date)
+            .await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct ForeignOwnershipQuery {
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_foreign_ownership(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<ForeignOwnershipQuery>,
+) -> std::result::Result<Json<Vec<ForeignOwnershipDto>>, StatusCode> {
+    match state.foreign_ownership_controller.get_foreign_ownership(
+        &code,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(records) => Ok(Json(records)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
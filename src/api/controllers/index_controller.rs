@@ -0,0 +1,56 @@
+use crate::application::dtos::IndexLevelDto;
+use crate::application::services::IndexService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct IndexController {
+    index_service: Arc<IndexService>,
+}
+
+impl IndexController {
+    pub fn new(index_service: Arc<IndexService>) -> Self {
+        Self { index_service }
+    }
+
+    pub async fn get_index_prices(
+        &self,
+        index_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<IndexLevelDto>> {
+        self.index_service
+            .get_index_prices(index_code, start_date, end_date)
+            .await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct IndexPriceQuery {
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_index_prices(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<IndexPriceQuery>,
+) -> std::result::Result<Json<Vec<IndexLevelDto>>, StatusCode> {
+    match state.index_controller.get_index_prices(
+        &code,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(prices) => Ok(Json(prices)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
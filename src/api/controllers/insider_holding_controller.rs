@@ -0,0 +1,36 @@
+use crate::application::dtos::InsiderHoldingDto;
+use crate::application::services::InsiderHoldingService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+
+#[derive(Clone)]
+pub struct InsiderHoldingController {
+    insider_holding_service: Arc<InsiderHoldingService>,
+}
+
+impl InsiderHoldingController {
+    pub fn new(insider_holding_service: Arc<InsiderHoldingService>) -> Self {
+        Self { insider_holding_service }
+    }
+
+    pub async fn get_insider_holdings(&self, stock_code: &str) -> DomainResult<Vec<InsiderHoldingDto>> {
+        self.insider_holding_service.get_insider_holdings(stock_code).await
+    }
+}
+
+// Axum 路由處理器
+pub async fn get_insider_holdings(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> std::result::Result<Json<Vec<InsiderHoldingDto>>, StatusCode> {
+    match state.insider_holding_controller.get_insider_holdings(&code).await {
+        Ok(holdings) => Ok(Json(holdings)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -0,0 +1,61 @@
+use crate::application::dtos::IntradayBarDto;
+use crate::application::services::IntradayBarService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct IntradayBarController {
+    intraday_bar_service: Arc<IntradayBarService>,
+}
+
+impl IntradayBarController {
+    pub fn new(intraday_bar_service: Arc<IntradayBarService>) -> Self {
+        Self { intraday_bar_service }
+    }
+
+    pub async fn get_bars_by_stock_code(
+        &self,
+        stock_code: &str,
+        interval: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Option<Vec<IntradayBarDto>>> {
+        self.intraday_bar_service
+            .get_bars_by_stock_code(stock_code, interval, start_date, end_date)
+            .await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct IntradayBarQuery {
+    interval: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_intraday_bars(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<IntradayBarQuery>,
+) -> std::result::Result<Json<Vec<IntradayBarDto>>, StatusCode> {
+    let interval = query.interval.unwrap_or_else(|| "1m".to_string());
+    match state.intraday_bar_controller.get_bars_by_stock_code(
+        &code,
+        &interval,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(Some(bars)) => Ok(Json(bars)),
+        Ok(None) => Err(StatusCode::NOT_FOUND),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
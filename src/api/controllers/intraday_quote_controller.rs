@@ -0,0 +1,45 @@
+use crate::application::dtos::IntradayQuoteDto;
+use crate::application::services::IntradayQuoteService;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+
+#[derive(Clone)]
+pub struct IntradayQuoteController {
+    intraday_quote_service: Arc<IntradayQuoteService>,
+}
+
+impl IntradayQuoteController {
+    pub fn new(intraday_quote_service: Arc<IntradayQuoteService>) -> Self {
+        Self { intraday_quote_service }
+    }
+
+    pub fn get_latest_quote(&self, stock_code: &str) -> Option<IntradayQuoteDto> {
+        self.intraday_quote_service.get_latest_quote(stock_code)
+    }
+
+    pub fn get_all_latest_quotes(&self) -> Vec<IntradayQuoteDto> {
+        self.intraday_quote_service.get_all_latest_quotes()
+    }
+}
+
+// Axum 路由處理器
+pub async fn get_all_intraday_quotes(
+    State(state): State<AppState>,
+) -> Json<Vec<IntradayQuoteDto>> {
+    Json(state.intraday_quote_controller.get_all_latest_quotes())
+}
+
+pub async fn get_intraday_quote(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> std::result::Result<Json<IntradayQuoteDto>, StatusCode> {
+    match state.intraday_quote_controller.get_latest_quote(&code) {
+        Some(quote) => Ok(Json(quote)),
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
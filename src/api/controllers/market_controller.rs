@@ -0,0 +1,30 @@
+use crate::application::dtos::MarketStatusDto;
+use crate::domain::services::MarketClock;
+use std::sync::Arc;
+use axum::{
+    extract::State,
+    Json,
+};
+use crate::AppState;
+
+#[derive(Clone)]
+pub struct MarketController {
+    market_clock: Arc<MarketClock>,
+}
+
+impl MarketController {
+    pub fn new(market_clock: Arc<MarketClock>) -> Self {
+        Self { market_clock }
+    }
+
+    pub fn get_market_status(&self) -> MarketStatusDto {
+        MarketStatusDto::from(self.market_clock.as_ref())
+    }
+}
+
+// Axum 路由處理器
+pub async fn get_market_status(
+    State(state): State<AppState>,
+) -> Json<MarketStatusDto> {
+    Json(state.market_controller.get_market_status())
+}
@@ -0,0 +1,38 @@
+use crate::application::dtos::MarketSurveillanceEventDto;
+use crate::application::services::MarketSurveillanceService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+
+#[derive(Clone)]
+pub struct MarketSurveillanceController {
+    market_surveillance_service: Arc<MarketSurveillanceService>,
+}
+
+impl MarketSurveillanceController {
+    pub fn new(market_surveillance_service: Arc<MarketSurveillanceService>) -> Self {
+        Self { market_surveillance_service }
+    }
+
+    pub async fn get_events_by_stock_code(&self, stock_code: &str) -> DomainResult<Vec<MarketSurveillanceEventDto>> {
+        self.market_surveillance_service
+            .get_events_by_stock_code(stock_code)
+            .await
+    }
+}
+
+// Axum 路由處理器
+pub async fn get_surveillance_events(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> std::result::Result<Json<Vec<MarketSurveillanceEventDto>>, StatusCode> {
+    match state.market_surveillance_controller.get_events_by_stock_code(&code).await {
+        Ok(events) => Ok(Json(events)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -0,0 +1,74 @@
+use crate::application::dtos::MaterialAnnouncementDto;
+use crate::application::services::MaterialAnnouncementService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct MaterialAnnouncementController {
+    material_announcement_service: Arc<MaterialAnnouncementService>,
+}
+
+impl MaterialAnnouncementController {
+    pub fn new(material_announcement_service: Arc<MaterialAnnouncementService>) -> Self {
+        Self { material_announcement_service }
+    }
+
+    pub async fn get_announcements(
+        &self,
+        stock_code: Option<&str>,
+        start_date: Option<String>,
+        end_date: Option<String>,
+        keywords: Option<String>,
+    ) -> DomainResult<Vec<MaterialAnnouncementDto>> {
+        self.material_announcement_service
+            .get_announcements(stock_code, start_date, end_date, keywords)
+            .await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct AnnouncementQuery {
+    start_date: Option<String>,
+    end_date: Option<String>,
+    keywords: Option<String>,   // 逗號分隔的關鍵字
+}
+
+// Axum 路由處理器
+pub async fn get_announcements(
+    State(state): State<AppState>,
+    Query(query): Query<AnnouncementQuery>,
+) -> std::result::Result<Json<Vec<MaterialAnnouncementDto>>, StatusCode> {
+    match state.material_announcement_controller.get_announcements(
+        None,
+        query.start_date,
+        query.end_date,
+        query.keywords,
+    ).await {
+        Ok(announcements) => Ok(Json(announcements)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+pub async fn get_stock_announcements(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<AnnouncementQuery>,
+) -> std::result::Result<Json<Vec<MaterialAnnouncementDto>>, StatusCode> {
+    match state.material_announcement_controller.get_announcements(
+        Some(&code),
+        query.start_date,
+        query.end_date,
+        query.keywords,
+    ).await {
+        Ok(announcements) => Ok(Json(announcements)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -1,2 +1,18 @@
 pub mod stock_controller;
 pub mod stock_price_controller;
+pub mod intraday_quote_controller;
+pub mod intraday_bar_controller;
+pub mod index_controller;
+pub mod derivatives_controller;
+pub mod market_controller;
+pub mod market_surveillance_controller;
+pub mod material_announcement_controller;
+pub mod foreign_ownership_controller;
+pub mod off_session_trading_controller;
+pub mod insider_holding_controller;
+pub mod treasury_buyback_controller;
+pub mod public_subscription_controller;
+pub mod etf_controller;
+pub mod price_reconciliation_controller;
+pub mod price_gap_controller;
+pub mod universe_controller;
@@ -0,0 +1,82 @@
+use crate::application::dtos::{AfterHoursTradeDto, OddLotQuoteDto};
+use crate::application::services::OffSessionTradingService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct OffSessionTradingController {
+    off_session_trading_service: Arc<OffSessionTradingService>,
+}
+
+impl OffSessionTradingController {
+    pub fn new(off_session_trading_service: Arc<OffSessionTradingService>) -> Self {
+        Self { off_session_trading_service }
+    }
+
+    pub async fn get_odd_lot_quotes(
+        &self,
+        stock_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<OddLotQuoteDto>> {
+        self.off_session_trading_service
+            .get_odd_lot_quotes(stock_code, start_date, end_date)
+            .await
+    }
+
+    pub async fn get_after_hours_trades(
+        &self,
+        stock_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<AfterHoursTradeDto>> {
+        self.off_session_trading_service
+            .get_after_hours_trades(stock_code, start_date, end_date)
+            .await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct OffSessionTradingQuery {
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_odd_lot_quotes(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<OffSessionTradingQuery>,
+) -> std::result::Result<Json<Vec<OddLotQuoteDto>>, StatusCode> {
+    match state.off_session_trading_controller.get_odd_lot_quotes(
+        &code,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(quotes) => Ok(Json(quotes)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+pub async fn get_after_hours_trades(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<OffSessionTradingQuery>,
+) -> std::result::Result<Json<Vec<AfterHoursTradeDto>>, StatusCode> {
+    match state.off_session_trading_controller.get_after_hours_trades(
+        &code,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(trades) => Ok(Json(trades)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -0,0 +1,57 @@
+use crate::application::dtos::PriceCoverageDto;
+use crate::application::services::PriceGapService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct PriceGapController {
+    price_gap_service: Arc<PriceGapService>,
+}
+
+impl PriceGapController {
+    pub fn new(price_gap_service: Arc<PriceGapService>) -> Self {
+        Self { price_gap_service }
+    }
+
+    pub async fn get_price_coverage(
+        &self,
+        stock_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Option<PriceCoverageDto>> {
+        self.price_gap_service
+            .get_coverage(stock_code, start_date, end_date)
+            .await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct PriceCoverageQuery {
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_price_coverage(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<PriceCoverageQuery>,
+) -> std::result::Result<Json<PriceCoverageDto>, StatusCode> {
+    match state.price_gap_controller.get_price_coverage(
+        &code,
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(Some(coverage)) => Ok(Json(coverage)),
+        Ok(None) => Err(StatusCode::NOT_FOUND),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -0,0 +1,53 @@
+use crate::application::dtos::PriceDiscrepancyDto;
+use crate::application::services::PriceReconciliationService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct PriceReconciliationController {
+    price_reconciliation_service: Arc<PriceReconciliationService>,
+}
+
+impl PriceReconciliationController {
+    pub fn new(price_reconciliation_service: Arc<PriceReconciliationService>) -> Self {
+        Self { price_reconciliation_service }
+    }
+
+    pub async fn get_price_discrepancies(
+        &self,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<PriceDiscrepancyDto>> {
+        self.price_reconciliation_service
+            .get_discrepancies(start_date, end_date)
+            .await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct PriceDiscrepancyQuery {
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_price_discrepancies(
+    State(state): State<AppState>,
+    Query(query): Query<PriceDiscrepancyQuery>,
+) -> std::result::Result<Json<Vec<PriceDiscrepancyDto>>, StatusCode> {
+    match state.price_reconciliation_controller.get_price_discrepancies(
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(discrepancies) => Ok(Json(discrepancies)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -0,0 +1,53 @@
+use crate::application::dtos::PublicSubscriptionDto;
+use crate::application::services::PublicSubscriptionService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct PublicSubscriptionController {
+    public_subscription_service: Arc<PublicSubscriptionService>,
+}
+
+impl PublicSubscriptionController {
+    pub fn new(public_subscription_service: Arc<PublicSubscriptionService>) -> Self {
+        Self { public_subscription_service }
+    }
+
+    pub async fn get_subscriptions(
+        &self,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Vec<PublicSubscriptionDto>> {
+        self.public_subscription_service
+            .get_subscriptions(start_date, end_date)
+            .await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct PublicSubscriptionQuery {
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_public_subscriptions(
+    State(state): State<AppState>,
+    Query(query): Query<PublicSubscriptionQuery>,
+) -> std::result::Result<Json<Vec<PublicSubscriptionDto>>, StatusCode> {
+    match state.public_subscription_controller.get_subscriptions(
+        query.start_date,
+        query.end_date,
+    ).await {
+        Ok(subscriptions) => Ok(Json(subscriptions)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -27,8 +27,8 @@ impl StockController {
         self.stock_service.get_stock_by_id(id).await
     }
 
-    pub async fn get_stock_by_code(&self, code: &str) -> DomainResult<Option<StockDto>> {
-        self.stock_service.get_stock_by_code(code).await
+    pub async fn get_stock_detail(&self, code: &str) -> DomainResult<Option<StockDto>> {
+        self.stock_service.get_stock_detail(code).await
     }
 
     pub async fn get_all_stocks(&self) -> DomainResult<Vec<StockDto>> {
@@ -54,7 +54,7 @@ pub async fn get_stock_by_code(
     State(state): State<AppState>,
     Path(code): Path<String>,
 ) -> std::result::Result<Json<Option<StockDto>>, StatusCode> {
-    match state.stock_controller.get_stock_by_code(&code).await {
+    match state.stock_controller.get_stock_detail(&code).await {
         Ok(stock) => Ok(Json(stock)),
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
@@ -1,4 +1,4 @@
-use crate::application::dtos::{CreateStockPriceDto, StockPriceDto};
+use crate::application::dtos::{CreateStockPriceDto, CreateStockPriceOutcome, PriceLimitDto, StockPriceDto};
 use crate::application::services::StockPriceService;
 use crate::domain::value_objects::Result as DomainResult;
 use std::sync::Arc;
@@ -20,7 +20,7 @@ impl StockPriceController {
         Self { stock_price_service }
     }
 
-    pub async fn create_stock_price(&self, dto: CreateStockPriceDto) -> DomainResult<StockPriceDto> {
+    pub async fn create_stock_price(&self, dto: CreateStockPriceDto) -> DomainResult<CreateStockPriceOutcome> {
         self.stock_price_service.create_stock_price(dto).await
     }
 
@@ -42,6 +42,21 @@ impl StockPriceController {
     pub async fn get_latest_price_by_stock_id(&self, stock_id: &str) -> DomainResult<Option<StockPriceDto>> {
         self.stock_price_service.get_latest_price_by_stock_id(stock_id).await
     }
+
+    pub async fn get_stock_prices_by_code(
+        &self,
+        stock_code: &str,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> DomainResult<Option<Vec<StockPriceDto>>> {
+        self.stock_price_service
+            .get_stock_prices_by_code(stock_code, start_date, end_date)
+            .await
+    }
+
+    pub async fn get_price_limit_by_code(&self, stock_code: &str) -> DomainResult<Option<PriceLimitDto>> {
+        self.stock_price_service.get_price_limit_by_code(stock_code).await
+    }
 }
 
 // 查詢參數結構體
@@ -52,17 +67,30 @@ pub struct StockPriceQuery {
 }
 
 // Axum 路由處理器
+// 路徑參數是股票代碼 (/api/stocks/:code/prices)，須先以代碼找到股票，不可直接當成股票 ID 解析
 pub async fn get_stock_prices_by_stock_id(
     State(state): State<AppState>,
     Path(code): Path<String>,
     Query(query): Query<StockPriceQuery>,
 ) -> std::result::Result<Json<Vec<StockPriceDto>>, StatusCode> {
-    match state.price_controller.get_stock_prices_by_stock_id(
+    match state.price_controller.get_stock_prices_by_code(
         &code,
         query.start_date,
         query.end_date,
     ).await {
-        Ok(prices) => Ok(Json(prices)),
+        Ok(Some(prices)) => Ok(Json(prices)),
+        Ok(None) => Err(StatusCode::NOT_FOUND),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+pub async fn get_price_limit(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> std::result::Result<Json<PriceLimitDto>, StatusCode> {
+    match state.price_controller.get_price_limit_by_code(&code).await {
+        Ok(Some(limit)) => Ok(Json(limit)),
+        Ok(None) => Err(StatusCode::NOT_FOUND),
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
@@ -0,0 +1,36 @@
+use crate::application::dtos::TreasuryBuybackDto;
+use crate::application::services::TreasuryBuybackService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+
+#[derive(Clone)]
+pub struct TreasuryBuybackController {
+    treasury_buyback_service: Arc<TreasuryBuybackService>,
+}
+
+impl TreasuryBuybackController {
+    pub fn new(treasury_buyback_service: Arc<TreasuryBuybackService>) -> Self {
+        Self { treasury_buyback_service }
+    }
+
+    pub async fn get_treasury_buybacks(&self, stock_code: &str) -> DomainResult<Vec<TreasuryBuybackDto>> {
+        self.treasury_buyback_service.get_buybacks(stock_code).await
+    }
+}
+
+// Axum 路由處理器
+pub async fn get_treasury_buybacks(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> std::result::Result<Json<Vec<TreasuryBuybackDto>>, StatusCode> {
+    match state.treasury_buyback_controller.get_treasury_buybacks(&code).await {
+        Ok(buybacks) => Ok(Json(buybacks)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
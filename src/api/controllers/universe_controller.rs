@@ -0,0 +1,43 @@
+use crate::application::dtos::UniverseChangeDto;
+use crate::application::services::StockListingService;
+use crate::domain::value_objects::Result as DomainResult;
+use std::sync::Arc;
+use axum::{
+    extract::{State, Query},
+    http::StatusCode,
+    Json,
+};
+use crate::AppState;
+use serde::Deserialize;
+
+#[derive(Clone)]
+pub struct UniverseController {
+    stock_listing_service: Arc<StockListingService>,
+}
+
+impl UniverseController {
+    pub fn new(stock_listing_service: Arc<StockListingService>) -> Self {
+        Self { stock_listing_service }
+    }
+
+    pub async fn get_universe_changes(&self, since: Option<String>) -> DomainResult<Vec<UniverseChangeDto>> {
+        self.stock_listing_service.get_changes(since).await
+    }
+}
+
+// 查詢參數結構體
+#[derive(Deserialize)]
+pub struct UniverseChangeQuery {
+    since: Option<String>,
+}
+
+// Axum 路由處理器
+pub async fn get_universe_changes(
+    State(state): State<AppState>,
+    Query(query): Query<UniverseChangeQuery>,
+) -> std::result::Result<Json<Vec<UniverseChangeDto>>, StatusCode> {
+    match state.universe_controller.get_universe_changes(query.since).await {
+        Ok(changes) => Ok(Json(changes)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -1,4 +1,4 @@
-use crate::application::dtos::{CreateStockDto, CreateStockPriceDto};
+use crate::application::dtos::{CreateStockDto, CreateStockPriceDto, CreateStockPriceOutcome};
 use crate::api::controllers::{stock_controller::StockController, stock_price_controller::StockPriceController};
 use axum::{
     extract::{Extension, Path, Query},
@@ -90,7 +90,7 @@ pub async fn get_stock_by_code(
     Path(code): Path<String>,
     Extension(controller): Extension<Arc<StockController>>,
 ) -> impl IntoResponse {
-    match controller.get_stock_by_code(&code).await {
+    match controller.get_stock_detail(&code).await {
         Ok(Some(stock)) => (StatusCode::OK, Json(stock)).into_response(),
         Ok(None) => (StatusCode::NOT_FOUND, "Stock not found").into_response(),
         Err(e) => (
@@ -168,7 +168,9 @@ async fn create_stock_price(
     Json(dto): Json<CreateStockPriceDto>,
 ) -> impl IntoResponse {
     match controller.create_stock_price(dto).await {
-        Ok(price) => (StatusCode::CREATED, Json(price)).into_response(),
+        Ok(outcome @ CreateStockPriceOutcome::Saved { .. }) => (StatusCode::CREATED, Json(outcome)).into_response(),
+        // 未通過驗證的日 K 已移至隔離區，屬於正常處理結果
+        Ok(outcome @ CreateStockPriceOutcome::Quarantined { .. }) => (StatusCode::ACCEPTED, Json(outcome)).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             format!("Failed to create stock price: {}", e),
@@ -0,0 +1,27 @@
+use crate::domain::models::BlockTrade;
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockTradeDto {
+    pub trade_type: String,
+    pub price: f64,
+    pub volume: u64,
+    pub turnover: u64,
+    pub premium_percent: Option<f64>,   // 相對當日收盤價的溢價 (正值) 或折價 (負值) 百分比
+}
+
+impl BlockTradeDto {
+    pub fn new(trade: BlockTrade, regular_close: Option<&BigDecimal>) -> Self {
+        let premium_percent = regular_close
+            .and_then(|close| trade.premium_percent(close))
+            .map(|v| v.to_string().parse::<f64>().unwrap_or(0.0));
+        Self {
+            trade_type: trade.trade_type.as_str().to_string(),
+            price: trade.price.to_string().parse::<f64>().unwrap_or(0.0),
+            volume: trade.volume,
+            turnover: trade.turnover,
+            premium_percent,
+        }
+    }
+}
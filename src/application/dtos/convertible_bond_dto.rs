@@ -0,0 +1,39 @@
+use crate::domain::models::{ConvertibleBond, ConvertibleBondQuote};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertibleBondDto {
+    pub bond_code: String,
+    pub bond_name: String,
+    pub underlying_code: String,
+    pub conversion_price: f64,
+    pub outstanding_amount: u64,
+    pub issue_date: Option<String>,
+    pub maturity_date: Option<String>,
+    pub quote_date: Option<String>,           // 最近一筆行情日期
+    pub close: Option<f64>,
+    pub conversion_premium: Option<f64>,      // 轉換溢價率 (%)
+}
+
+impl ConvertibleBondDto {
+    pub fn new(bond: ConvertibleBond, latest_quote: Option<ConvertibleBondQuote>) -> Self {
+        Self {
+            bond_code: bond.bond_code,
+            bond_name: bond.bond_name,
+            underlying_code: bond.underlying_code,
+            conversion_price: bond.conversion_price.to_string().parse::<f64>().unwrap_or(0.0),
+            outstanding_amount: bond.outstanding_amount,
+            issue_date: bond.issue_date.map(|d| d.to_string()),
+            maturity_date: bond.maturity_date.map(|d| d.to_string()),
+            quote_date: latest_quote.as_ref().map(|q| q.date.to_string()),
+            close: latest_quote
+                .as_ref()
+                .and_then(|q| q.close.as_ref())
+                .map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            conversion_premium: latest_quote
+                .as_ref()
+                .and_then(|q| q.conversion_premium.as_ref())
+                .map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::domain::models::{FuturesDailyPrice, InstitutionalOpenInterest};
+use crate::domain::value_objects::PutCallRatio;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuturesDailyPriceDto {
+    pub contract_code: String,
+    pub delivery_month: String,
+    pub date: String,
+    pub open: Option<f64>,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    pub close: Option<f64>,
+    pub settlement_price: Option<f64>,
+    pub volume: u64,
+    pub open_interest: u64,
+}
+
+impl From<FuturesDailyPrice> for FuturesDailyPriceDto {
+    fn from(price: FuturesDailyPrice) -> Self {
+        Self {
+            contract_code: price.contract_code,
+            delivery_month: price.delivery_month,
+            date: price.date.to_string(),
+            open: price.open.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            high: price.high.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            low: price.low.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            close: price.close.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            settlement_price: price.settlement_price.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            volume: price.volume,
+            open_interest: price.open_interest,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstitutionalOpenInterestDto {
+    pub contract_code: String,
+    pub date: String,
+    pub institution_type: String,
+    pub long_open_interest: i64,
+    pub short_open_interest: i64,
+    pub net_open_interest: i64,
+    pub long_value: i64,
+    pub short_value: i64,
+    pub net_value: i64,
+}
+
+impl From<InstitutionalOpenInterest> for InstitutionalOpenInterestDto {
+    fn from(position: InstitutionalOpenInterest) -> Self {
+        Self {
+            contract_code: position.contract_code,
+            date: position.date.to_string(),
+            institution_type: position.institution_type.as_str().to_string(),
+            long_open_interest: position.long_open_interest,
+            short_open_interest: position.short_open_interest,
+            net_open_interest: position.net_open_interest,
+            long_value: position.long_value,
+            short_value: position.short_value,
+            net_value: position.net_value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PutCallRatioDto {
+    pub date: String,
+    pub put_volume: u64,
+    pub call_volume: u64,
+    pub volume_ratio: Option<f64>,
+    pub put_open_interest: u64,
+    pub call_open_interest: u64,
+    pub open_interest_ratio: Option<f64>,
+}
+
+impl From<PutCallRatio> for PutCallRatioDto {
+    fn from(ratio: PutCallRatio) -> Self {
+        Self {
+            date: ratio.date.to_string(),
+            volume_ratio: ratio.volume_ratio(),
+            open_interest_ratio: ratio.open_interest_ratio(),
+            put_volume: ratio.put_volume,
+            call_volume: ratio.call_volume,
+            put_open_interest: ratio.put_open_interest,
+            call_open_interest: ratio.call_open_interest,
+        }
+    }
+}
+
+/// 期貨近月契約與加權指數的價差
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuturesBasisDto {
+    pub contract_code: String,
+    pub delivery_month: String,
+    pub date: String,
+    pub futures_close: f64,
+    pub index_close: f64,
+    pub basis: f64,
+}
@@ -0,0 +1,53 @@
+use crate::domain::models::{EtfConstituent, EtfNav};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EtfNavDto {
+    pub etf_code: String,
+    pub etf_name: String,
+    pub date: String,
+    pub nav: f64,
+    pub close: Option<f64>,
+    pub issued_units: Option<u64>,
+    pub premium_percent: Option<f64>,   // 收盤價相對淨值的溢價 (正值) 或折價 (負值) 百分比
+}
+
+impl From<EtfNav> for EtfNavDto {
+    fn from(nav: EtfNav) -> Self {
+        let premium_percent = nav
+            .premium_percent()
+            .map(|v| v.to_string().parse::<f64>().unwrap_or(0.0));
+        Self {
+            etf_code: nav.etf_code,
+            etf_name: nav.etf_name,
+            date: nav.date.to_string(),
+            nav: nav.nav.to_string().parse::<f64>().unwrap_or(0.0),
+            close: nav.close.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            issued_units: nav.issued_units,
+            premium_percent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EtfConstituentDto {
+    pub etf_code: String,
+    pub date: String,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub weight: f64,
+    pub shares: Option<u64>,
+}
+
+impl From<EtfConstituent> for EtfConstituentDto {
+    fn from(constituent: EtfConstituent) -> Self {
+        Self {
+            etf_code: constituent.etf_code,
+            date: constituent.date.to_string(),
+            stock_code: constituent.stock_code,
+            stock_name: constituent.stock_name,
+            weight: constituent.weight.to_string().parse::<f64>().unwrap_or(0.0),
+            shares: constituent.shares,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::domain::models::ForeignOwnership;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForeignOwnershipDto {
+    pub stock_code: String,
+    pub stock_name: String,
+    pub date: String,
+    pub issued_shares: u64,
+    pub available_shares: u64,
+    pub foreign_held_shares: u64,
+    pub foreign_held_ratio: f64,
+    pub foreign_limit_ratio: f64,
+    pub available_ratio: f64,
+}
+
+impl From<ForeignOwnership> for ForeignOwnershipDto {
+    fn from(ownership: ForeignOwnership) -> Self {
+        let available_ratio = ownership.available_ratio();
+        Self {
+            stock_code: ownership.stock_code,
+            stock_name: ownership.stock_name,
+            date: ownership.date.to_string(),
+            issued_shares: ownership.issued_shares,
+            available_shares: ownership.available_shares,
+            foreign_held_shares: ownership.foreign_held_shares,
+            foreign_held_ratio: ownership.foreign_held_ratio.to_string().parse::<f64>().unwrap_or(0.0),
+            foreign_limit_ratio: ownership.foreign_limit_ratio.to_string().parse::<f64>().unwrap_or(0.0),
+            available_ratio: available_ratio.to_string().parse::<f64>().unwrap_or(0.0),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::domain::models::IndexLevel;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexLevelDto {
+    pub index_code: String,
+    pub index_name: String,
+    pub date: String,
+    pub open: Option<f64>,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    pub close: f64,
+    pub change: f64,
+    pub change_percent: f64,
+}
+
+impl From<IndexLevel> for IndexLevelDto {
+    fn from(level: IndexLevel) -> Self {
+        Self {
+            index_code: level.index_code,
+            index_name: level.index_name,
+            date: level.date.to_string(),
+            open: level.open.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            high: level.high.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            low: level.low.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            close: level.close.to_string().parse::<f64>().unwrap_or(0.0),
+            change: level.change.to_string().parse::<f64>().unwrap_or(0.0),
+            change_percent: level.change_percent.to_string().parse::<f64>().unwrap_or(0.0),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::domain::models::InsiderHolding;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InsiderHoldingDto {
+    pub stock_code: String,
+    pub company_name: String,
+    pub period: String,                // 資料所屬月份，例如 "2024-09"
+    pub insider_shares: u64,
+    pub pledged_shares: u64,
+    pub issued_shares: Option<u64>,
+    pub holding_ratio: Option<f64>,    // 董監持股比率 (%)
+    pub pledge_ratio: Option<f64>,     // 董監設質比率 (%)
+}
+
+impl From<InsiderHolding> for InsiderHoldingDto {
+    fn from(holding: InsiderHolding) -> Self {
+        let holding_ratio = holding
+            .holding_ratio()
+            .map(|v| v.to_string().parse::<f64>().unwrap_or(0.0));
+        let pledge_ratio = holding
+            .pledge_ratio()
+            .map(|v| v.to_string().parse::<f64>().unwrap_or(0.0));
+        Self {
+            stock_code: holding.stock_code,
+            company_name: holding.company_name,
+            period: format!("{}-{:02}", holding.period.year(), holding.period.month() as u8),
+            insider_shares: holding.insider_shares,
+            pledged_shares: holding.pledged_shares,
+            issued_shares: holding.issued_shares,
+            holding_ratio,
+            pledge_ratio,
+        }
+    }
+}
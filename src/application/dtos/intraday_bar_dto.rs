@@ -0,0 +1,29 @@
+use crate::domain::models::IntradayBar;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntradayBarDto {
+    pub stock_id: String,
+    pub interval: String,
+    pub start_time: String,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: u64,
+}
+
+impl From<IntradayBar> for IntradayBarDto {
+    fn from(bar: IntradayBar) -> Self {
+        Self {
+            stock_id: bar.stock_id.to_string(),
+            interval: bar.interval.as_str().to_string(),
+            start_time: bar.start_time.to_string(),
+            open: bar.open.to_string().parse::<f64>().unwrap_or(0.0),
+            high: bar.high.to_string().parse::<f64>().unwrap_or(0.0),
+            low: bar.low.to_string().parse::<f64>().unwrap_or(0.0),
+            close: bar.close.to_string().parse::<f64>().unwrap_or(0.0),
+            volume: bar.volume,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::domain::models::{IntradayQuote, OrderBookLevel};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookLevelDto {
+    pub price: f64,
+    pub volume: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntradayQuoteDto {
+    pub stock_code: String,
+    pub stock_name: String,
+    pub quote_time: String,
+    pub last_price: Option<f64>,
+    pub accumulated_volume: u64,
+    pub open: Option<f64>,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    pub prev_close: Option<f64>,
+    pub bids: Vec<OrderBookLevelDto>,
+    pub asks: Vec<OrderBookLevelDto>,
+}
+
+impl From<OrderBookLevel> for OrderBookLevelDto {
+    fn from(level: OrderBookLevel) -> Self {
+        Self {
+            price: level.price.to_string().parse::<f64>().unwrap_or(0.0),
+            volume: level.volume,
+        }
+    }
+}
+
+impl From<IntradayQuote> for IntradayQuoteDto {
+    fn from(quote: IntradayQuote) -> Self {
+        Self {
+            stock_code: quote.stock_code,
+            stock_name: quote.stock_name,
+            quote_time: quote.quote_time.to_string(),
+            last_price: quote.last_price.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            accumulated_volume: quote.accumulated_volume,
+            open: quote.open.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            high: quote.high.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            low: quote.low.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            prev_close: quote.prev_close.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            bids: quote.bids.into_iter().map(OrderBookLevelDto::from).collect(),
+            asks: quote.asks.into_iter().map(OrderBookLevelDto::from).collect(),
+        }
+    }
+}
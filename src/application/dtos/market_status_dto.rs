@@ -0,0 +1,26 @@
+use crate::domain::services::MarketClock;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketStatusDto {
+    pub now: String,
+    pub date: String,
+    pub is_trading_day: bool,
+    pub session_phase: String,
+    pub odd_lot_session: bool,
+    pub next_trading_day: String,
+}
+
+impl From<&MarketClock> for MarketStatusDto {
+    fn from(clock: &MarketClock) -> Self {
+        let now = clock.now();
+        Self {
+            now: now.to_string(),
+            date: now.date().to_string(),
+            is_trading_day: clock.is_trading_day(now.date()),
+            session_phase: clock.session_phase_at(now).as_str().to_string(),
+            odd_lot_session: clock.is_odd_lot_session_at(now),
+            next_trading_day: clock.trading_calendar().next_trading_day(now.date()).to_string(),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::domain::models::MarketSurveillanceEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketSurveillanceEventDto {
+    pub stock_code: String,
+    pub stock_name: String,
+    pub market: String,
+    pub event_type: String,
+    pub announced_date: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub reason: String,
+    pub measure: Option<String>,
+}
+
+impl From<MarketSurveillanceEvent> for MarketSurveillanceEventDto {
+    fn from(event: MarketSurveillanceEvent) -> Self {
+        Self {
+            stock_code: event.stock_code,
+            stock_name: event.stock_name,
+            market: event.market,
+            event_type: event.event_type.as_str().to_string(),
+            announced_date: event.announced_date.to_string(),
+            start_date: event.start_date.to_string(),
+            end_date: event.end_date.to_string(),
+            reason: event.reason,
+            measure: event.measure,
+        }
+    }
+}
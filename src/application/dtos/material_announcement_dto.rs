@@ -0,0 +1,23 @@
+use crate::domain::models::MaterialAnnouncement;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaterialAnnouncementDto {
+    pub stock_code: String,
+    pub company_name: String,
+    pub announced_at: String,
+    pub title: String,
+    pub body: String,
+}
+
+impl From<MaterialAnnouncement> for MaterialAnnouncementDto {
+    fn from(announcement: MaterialAnnouncement) -> Self {
+        Self {
+            stock_code: announcement.stock_code,
+            company_name: announcement.company_name,
+            announced_at: announcement.announced_at.to_string(),
+            title: announcement.title,
+            body: announcement.body,
+        }
+    }
+}
@@ -1,5 +1,43 @@
 mod stock_dto;
 mod stock_price_dto;
+mod intraday_quote_dto;
+mod intraday_bar_dto;
+mod index_level_dto;
+mod derivatives_dto;
+mod market_status_dto;
+mod market_surveillance_dto;
+mod material_announcement_dto;
+mod foreign_ownership_dto;
+mod off_session_trading_dto;
+mod block_trade_dto;
+mod insider_holding_dto;
+mod treasury_buyback_dto;
+mod public_subscription_dto;
+mod etf_dto;
+mod convertible_bond_dto;
+mod price_discrepancy_dto;
+mod price_coverage_dto;
+mod universe_change_dto;
 
-pub use stock_dto::{CreateStockDto, StockDto};
-pub use stock_price_dto::{CreateStockPriceDto, StockPriceDto};
+pub use stock_dto::{CreateStockDto, StockDto, StockNameChangeDto};
+pub use stock_price_dto::{CreateStockPriceDto, CreateStockPriceOutcome, PriceLimitDto, StockPriceDto};
+pub use intraday_quote_dto::IntradayQuoteDto;
+pub use intraday_bar_dto::IntradayBarDto;
+pub use index_level_dto::IndexLevelDto;
+pub use derivatives_dto::{
+    FuturesBasisDto, FuturesDailyPriceDto, InstitutionalOpenInterestDto, PutCallRatioDto,
+};
+pub use market_status_dto::MarketStatusDto;
+pub use market_surveillance_dto::MarketSurveillanceEventDto;
+pub use material_announcement_dto::MaterialAnnouncementDto;
+pub use foreign_ownership_dto::ForeignOwnershipDto;
+pub use off_session_trading_dto::{AfterHoursTradeDto, OddLotQuoteDto};
+pub use block_trade_dto::BlockTradeDto;
+pub use insider_holding_dto::InsiderHoldingDto;
+pub use treasury_buyback_dto::TreasuryBuybackDto;
+pub use public_subscription_dto::PublicSubscriptionDto;
+pub use etf_dto::{EtfConstituentDto, EtfNavDto};
+pub use convertible_bond_dto::ConvertibleBondDto;
+pub use price_discrepancy_dto::PriceDiscrepancyDto;
+pub use price_coverage_dto::PriceCoverageDto;
+pub use universe_change_dto::UniverseChangeDto;
@@ -0,0 +1,66 @@
+use crate::domain::models::{AfterHoursTrade, OddLotQuote};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OddLotQuoteDto {
+    pub stock_code: String,
+    pub stock_name: String,
+    pub date: String,
+    pub open: Option<f64>,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    pub close: Option<f64>,
+    pub volume: u64,
+    pub transactions: u64,
+    pub turnover: u64,
+    pub last_bid_price: Option<f64>,
+    pub last_ask_price: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AfterHoursTradeDto {
+    pub stock_code: String,
+    pub stock_name: String,
+    pub date: String,
+    pub price: Option<f64>,
+    pub volume: u64,
+    pub transactions: u64,
+    pub turnover: u64,
+    pub last_bid_volume: u64,
+    pub last_ask_volume: u64,
+}
+
+impl From<OddLotQuote> for OddLotQuoteDto {
+    fn from(quote: OddLotQuote) -> Self {
+        Self {
+            stock_code: quote.stock_code,
+            stock_name: quote.stock_name,
+            date: quote.date.to_string(),
+            open: quote.open.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            high: quote.high.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            low: quote.low.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            close: quote.close.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            volume: quote.volume,
+            transactions: quote.transactions,
+            turnover: quote.turnover,
+            last_bid_price: quote.last_bid_price.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            last_ask_price: quote.last_ask_price.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+        }
+    }
+}
+
+impl From<AfterHoursTrade> for AfterHoursTradeDto {
+    fn from(trade: AfterHoursTrade) -> Self {
+        Self {
+            stock_code: trade.stock_code,
+            stock_name: trade.stock_name,
+            date: trade.date.to_string(),
+            price: trade.price.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            volume: trade.volume,
+            transactions: trade.transactions,
+            turnover: trade.turnover,
+            last_bid_volume: trade.last_bid_volume,
+            last_ask_volume: trade.last_ask_volume,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+
+/// 個股日 K 在期間內的完整度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceCoverageDto {
+    pub stock_code: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub expected_days: usize,       // 期間內的交易日數
+    pub stored_days: usize,         // 已保存日 K 的交易日數
+    pub coverage_percent: f64,      // 完整度 (%)
+    pub missing_dates: Vec<String>,
+    pub quarantined_dates: Vec<String>,   // 缺漏中已移至隔離區、待人工檢查的日期
+}
+
+impl PriceCoverageDto {
+    pub fn new(
+        stock_code: String,
+        start_date: Date,
+        end_date: Date,
+        expected_days: usize,
+        missing_dates: Vec<Date>,
+        quarantined_dates: Vec<Date>,
+    ) -> Self {
+        let stored_days = expected_days - missing_dates.len();
+        let coverage_percent = if expected_days == 0 {
+            100.0
+        } else {
+            (stored_days as f64 * 10000.0 / expected_days as f64).round() / 100.0
+        };
+        Self {
+            stock_code,
+            start_date: start_date.to_string(),
+            end_date: end_date.to_string(),
+            expected_days,
+            stored_days,
+            coverage_percent,
+            missing_dates: missing_dates.into_iter().map(|d| d.to_string()).collect(),
+            quarantined_dates: quarantined_dates.into_iter().map(|d| d.to_string()).collect(),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::domain::models::PriceDiscrepancy;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceDiscrepancyDto {
+    pub stock_code: String,
+    pub date: String,
+    pub field: String,
+    pub primary_source: String,
+    pub primary_value: f64,
+    pub secondary_source: String,
+    pub secondary_value: f64,
+    pub diff_percent: f64,   // 相對保存值的差異百分比 (%)
+}
+
+impl From<PriceDiscrepancy> for PriceDiscrepancyDto {
+    fn from(discrepancy: PriceDiscrepancy) -> Self {
+        Self {
+            stock_code: discrepancy.stock_code,
+            date: discrepancy.date.to_string(),
+            field: discrepancy.field,
+            primary_source: discrepancy.primary_source.as_str().to_string(),
+            primary_value: discrepancy.primary_value.to_string().parse::<f64>().unwrap_or(0.0),
+            secondary_source: discrepancy.secondary_source.as_str().to_string(),
+            secondary_value: discrepancy.secondary_value.to_string().parse::<f64>().unwrap_or(0.0),
+            diff_percent: discrepancy.diff_percent.to_string().parse::<f64>().unwrap_or(0.0),
+        }
+    }
+}
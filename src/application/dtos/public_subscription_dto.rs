@@ -0,0 +1,50 @@
+use crate::domain::models::PublicSubscription;
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicSubscriptionDto {
+    pub stock_code: String,
+    pub stock_name: String,
+    pub market: String,
+    pub subscription_start: String,
+    pub subscription_end: String,
+    pub lottery_date: String,
+    pub listing_date: Option<String>,
+    pub offer_price: f64,
+    pub shares_offered: u64,
+    pub shares_per_application: u64,
+    pub win_rate: Option<f64>,
+    pub is_cancelled: bool,
+    pub market_price: Option<f64>,      // 最近收盤價，尚未掛牌交易時為 None
+    pub premium_percent: Option<f64>,   // 市價相對承銷價的溢價百分比
+    pub expected_profit: Option<f64>,   // 中籤一個申購單位的預估價差 (元)
+}
+
+impl PublicSubscriptionDto {
+    pub fn new(subscription: PublicSubscription, market_price: Option<&BigDecimal>) -> Self {
+        let premium_percent = market_price
+            .and_then(|price| subscription.premium_percent(price))
+            .map(|v| v.to_string().parse::<f64>().unwrap_or(0.0));
+        let expected_profit = market_price
+            .map(|price| subscription.expected_profit(price))
+            .map(|v| v.to_string().parse::<f64>().unwrap_or(0.0));
+        Self {
+            stock_code: subscription.stock_code,
+            stock_name: subscription.stock_name,
+            market: subscription.market,
+            subscription_start: subscription.subscription_start.to_string(),
+            subscription_end: subscription.subscription_end.to_string(),
+            lottery_date: subscription.lottery_date.to_string(),
+            listing_date: subscription.listing_date.map(|d| d.to_string()),
+            offer_price: subscription.offer_price.to_string().parse::<f64>().unwrap_or(0.0),
+            shares_offered: subscription.shares_offered,
+            shares_per_application: subscription.shares_per_application,
+            win_rate: subscription.win_rate.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            is_cancelled: subscription.is_cancelled,
+            market_price: market_price.map(|v| v.to_string().parse::<f64>().unwrap_or(0.0)),
+            premium_percent,
+            expected_profit,
+        }
+    }
+}
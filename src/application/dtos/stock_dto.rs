@@ -1,6 +1,8 @@
-use crate::domain::models::Stock;
+use crate::application::dtos::ConvertibleBondDto;
+use crate::domain::models::{ListingStatus, Stock, StockNameChange};
 use serde::{Deserialize, Serialize};
-use time::OffsetDateTime;
+use time::format_description::well_known::Iso8601;
+use time::{Date, OffsetDateTime};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,6 +11,29 @@ pub struct StockDto {
     pub code: String,
     pub name: String,
     pub last_updated: String,
+    #[serde(default)]
+    pub status: String,                         // 上市狀態: active / suspended / delisted
+    #[serde(default)]
+    pub listed_date: Option<String>,            // 上市櫃日期
+    #[serde(default)]
+    pub status_changed_on: Option<String>,      // 最近一次上市狀態異動日期
+    #[serde(default)]
+    pub is_attention: bool,     // 注意股
+    #[serde(default)]
+    pub is_disposition: bool,   // 處置股
+    #[serde(default)]
+    pub foreign_ownership_ratio: Option<f64>,   // 外資及陸資持股比率 (%)
+    #[serde(default)]
+    pub convertible_bonds: Vec<ConvertibleBondDto>,   // 尚流通的可轉債，只在單一股票查詢時填入
+    #[serde(default)]
+    pub name_history: Vec<StockNameChangeDto>,        // 更名紀錄，只在單一股票查詢時填入
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockNameChangeDto {
+    pub previous_name: String,
+    pub new_name: String,
+    pub changed_on: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +49,24 @@ impl From<Stock> for StockDto {
             code: stock.code,
             name: stock.name,
             last_updated: stock.last_updated.to_string(),
+            status: stock.status.as_str().to_string(),
+            listed_date: stock.listed_date.map(|d| d.to_string()),
+            status_changed_on: stock.status_changed_on.map(|d| d.to_string()),
+            is_attention: false,
+            is_disposition: false,
+            foreign_ownership_ratio: None,
+            convertible_bonds: Vec::new(),
+            name_history: Vec::new(),
+        }
+    }
+}
+
+impl From<StockNameChange> for StockNameChangeDto {
+    fn from(change: StockNameChange) -> Self {
+        Self {
+            previous_name: change.previous_name,
+            new_name: change.new_name,
+            changed_on: change.changed_on.to_string(),
         }
     }
 }
@@ -37,6 +80,9 @@ impl TryFrom<StockDto> for Stock {
             code: dto.code,
             name: dto.name,
             last_updated: OffsetDateTime::parse(&dto.last_updated, &time::format_description::well_known::Rfc3339)?,
+            status: ListingStatus::parse(&dto.status).unwrap_or_default(),
+            listed_date: dto.listed_date.map(|d| Date::parse(&d, &Iso8601::DATE)).transpose()?,
+            status_changed_on: dto.status_changed_on.map(|d| Date::parse(&d, &Iso8601::DATE)).transpose()?,
         })
     }
 }
@@ -1,4 +1,6 @@
-use crate::domain::models::StockPrice;
+use crate::application::dtos::BlockTradeDto;
+use crate::domain::models::{PriceSource, StockPrice};
+use crate::domain::value_objects::{LimitStatus, PriceLimit};
 use serde::{Deserialize, Serialize};
 use time::Date;
 use uuid::Uuid;
@@ -26,6 +28,30 @@ pub struct StockPriceDto {
     pub foreign_buy: Option<i64>,
     pub trust_buy: Option<i64>,
     pub dealer_buy: Option<i64>,
+    #[serde(default)]
+    pub limit_status: Option<String>,
+    #[serde(default)]
+    pub day_trade_volume: Option<u64>,     // 當沖成交股數
+    #[serde(default)]
+    pub day_trade_ratio: Option<f64>,      // 當沖佔成交量比率 (%)
+    #[serde(default)]
+    pub issued_shares: Option<u64>,        // 已發行股數
+    #[serde(default)]
+    pub float_shares: Option<u64>,         // 流通股數 (扣除董監持股)
+    #[serde(default)]
+    pub turnover_ratio: Option<f64>,       // 週轉率 (%)
+    #[serde(default)]
+    pub source: Option<String>,            // 資料來源 (twse / yahoo)
+    #[serde(default)]
+    pub block_trades: Vec<BlockTradeDto>,  // 當日鉅額交易
+}
+
+/// 寫入日 K 的結果，未通過驗證的日 K 移至隔離區而不寫入 stock_prices
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum CreateStockPriceOutcome {
+    Saved { price: Box<StockPriceDto> },
+    Quarantined { reasons: Vec<String> },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,10 +74,15 @@ pub struct CreateStockPriceDto {
     pub foreign_buy: Option<i64>,
     pub trust_buy: Option<i64>,
     pub dealer_buy: Option<i64>,
+    #[serde(default)]
+    pub source: Option<String>,
 }
 
 impl From<StockPrice> for StockPriceDto {
     fn from(price: StockPrice) -> Self {
+        let day_trade_ratio = price.day_trade_ratio().map(|v| v.to_string().parse::<f64>().unwrap_or(0.0));
+        let float_shares = price.float_shares();
+        let turnover_ratio = price.turnover_ratio().map(|v| v.to_string().parse::<f64>().unwrap_or(0.0));
         Self {
             id: price.id.to_string(),
             stock_id: price.stock_id.to_string(),
@@ -72,6 +103,14 @@ impl From<StockPrice> for StockPriceDto {
             foreign_buy: price.foreign_buy,
             trust_buy: price.trust_buy,
             dealer_buy: price.dealer_buy,
+            limit_status: price.limit_status.map(|s| s.as_str().to_string()),
+            day_trade_volume: price.day_trade_volume,
+            day_trade_ratio,
+            issued_shares: price.issued_shares,
+            float_shares,
+            turnover_ratio,
+            source: Some(price.source.as_str().to_string()),
+            block_trades: Vec::new(),
         }
     }
 }
@@ -97,6 +136,7 @@ impl From<StockPrice> for CreateStockPriceDto {
             foreign_buy: price.foreign_buy,
             trust_buy: price.trust_buy,
             dealer_buy: price.dealer_buy,
+            source: Some(price.source.as_str().to_string()),
         }
     }
 }
@@ -142,6 +182,35 @@ impl TryFrom<StockPriceDto> for StockPrice {
             foreign_buy: dto.foreign_buy,
             trust_buy: dto.trust_buy,
             dealer_buy: dto.dealer_buy,
+            limit_status: dto.limit_status.as_deref().and_then(LimitStatus::parse),
+            day_trade_volume: dto.day_trade_volume,
+            issued_shares: dto.issued_shares,
+            insider_shares: None,
+            source: dto.source.as_deref().and_then(PriceSource::parse).unwrap_or_default(),
         })
     }
 }
+
+/// 漲跌停價格，以最新收盤價計算下一個交易日的漲跌停
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceLimitDto {
+    pub stock_code: String,
+    pub base_date: String,
+    pub previous_close: f64,
+    pub limit_up: f64,
+    pub limit_down: f64,
+    pub is_etf: bool,
+}
+
+impl PriceLimitDto {
+    pub fn new(stock_code: String, base_date: Date, limit: PriceLimit) -> Self {
+        Self {
+            stock_code,
+            base_date: base_date.to_string(),
+            previous_close: limit.previous_close.to_string().parse::<f64>().unwrap_or(0.0),
+            limit_up: limit.limit_up.to_string().parse::<f64>().unwrap_or(0.0),
+            limit_down: limit.limit_down.to_string().parse::<f64>().unwrap_or(0.0),
+            is_etf: limit.is_etf,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::domain::models::TreasuryBuyback;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreasuryBuybackDto {
+    pub stock_code: String,
+    pub company_name: String,
+    pub resolution_date: String,
+    pub purpose: String,
+    pub planned_shares: u64,
+    pub price_low: f64,
+    pub price_high: f64,
+    pub period_start: String,
+    pub period_end: String,
+    pub executed_shares: u64,
+    pub executed_amount: u64,
+    pub execution_ratio: Option<f64>,  // 已買回股數佔預定買回股數比例 (%)
+    pub is_completed: bool,
+}
+
+impl From<TreasuryBuyback> for TreasuryBuybackDto {
+    fn from(buyback: TreasuryBuyback) -> Self {
+        let execution_ratio = buyback
+            .execution_ratio()
+            .map(|v| v.to_string().parse::<f64>().unwrap_or(0.0));
+        Self {
+            stock_code: buyback.stock_code,
+            company_name: buyback.company_name,
+            resolution_date: buyback.resolution_date.to_string(),
+            purpose: buyback.purpose,
+            planned_shares: buyback.planned_shares,
+            price_low: buyback.price_low.to_string().parse::<f64>().unwrap_or(0.0),
+            price_high: buyback.price_high.to_string().parse::<f64>().unwrap_or(0.0),
+            period_start: buyback.period_start.to_string(),
+            period_end: buyback.period_end.to_string(),
+            executed_shares: buyback.executed_shares,
+            executed_amount: buyback.executed_amount,
+            execution_ratio,
+            is_completed: buyback.is_completed,
+        }
+    }
+}
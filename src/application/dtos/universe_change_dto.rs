@@ -0,0 +1,25 @@
+use crate::domain::models::UniverseChange;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UniverseChangeDto {
+    pub stock_code: String,
+    pub stock_name: String,
+    pub change_type: String,              // new_listing / delisting / name_change / industry_change / market_transfer
+    pub previous_value: Option<String>,
+    pub new_value: Option<String>,
+    pub detected_on: String,
+}
+
+impl From<UniverseChange> for UniverseChangeDto {
+    fn from(change: UniverseChange) -> Self {
+        Self {
+            stock_code: change.stock_code,
+            stock_name: change.stock_name,
+            change_type: change.change_type.as_str().to_string(),
+            previous_value: change.previous_value,
+            new_value: change.new_value,
+            detected_on: change.detected_on.to_string(),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::domain::models::BlockTrade;
+use crate::domain::repositories::BlockTradeRepository;
+use crate::domain::value_objects::Result;
+use std::sync::Arc;
+use time::Date;
+
+/// 鉅額交易服務，鉅額交易紀錄隨個股每日股價一併回傳
+pub struct BlockTradeService {
+    block_trade_repository: Arc<dyn BlockTradeRepository>,
+}
+
+impl BlockTradeService {
+    pub fn new(block_trade_repository: Arc<dyn BlockTradeRepository>) -> Self {
+        Self {
+            block_trade_repository,
+        }
+    }
+
+    pub async fn save_trades(&self, date: Date, trades: &[BlockTrade]) -> Result<()> {
+        self.block_trade_repository.replace_by_date(date, trades).await
+    }
+}
@@ -0,0 +1,90 @@
+use crate::application::dtos::ConvertibleBondDto;
+use crate::domain::models::{ConvertibleBond, ConvertibleBondQuote};
+use crate::domain::repositories::{ConvertibleBondRepository, StockPriceRepository, StockRepository};
+use crate::domain::value_objects::Result;
+use bigdecimal::BigDecimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use time::Date;
+
+/// 可轉換公司債服務，保存發行資料及行情並計算每日轉換溢價率
+pub struct ConvertibleBondService {
+    convertible_bond_repository: Arc<dyn ConvertibleBondRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    stock_price_repository: Arc<dyn StockPriceRepository>,
+}
+
+impl ConvertibleBondService {
+    pub fn new(
+        convertible_bond_repository: Arc<dyn ConvertibleBondRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        stock_price_repository: Arc<dyn StockPriceRepository>,
+    ) -> Self {
+        Self {
+            convertible_bond_repository,
+            stock_repository,
+            stock_price_repository,
+        }
+    }
+
+    pub async fn save_bonds(&self, bonds: &[ConvertibleBond]) -> Result<()> {
+        for bond in bonds {
+            self.convertible_bond_repository.save_bond(bond).await?;
+        }
+        Ok(())
+    }
+
+    /// 以標的股票當日收盤價計算轉換溢價率後保存行情
+    pub async fn save_quotes(&self, date: Date, bonds: &[ConvertibleBond], quotes: Vec<ConvertibleBondQuote>) -> Result<()> {
+        let bonds: HashMap<&str, &ConvertibleBond> = bonds.iter().map(|b| (b.bond_code.as_str(), b)).collect();
+        let mut underlying_closes: HashMap<String, Option<BigDecimal>> = HashMap::new();
+
+        for mut quote in quotes {
+            if let Some(bond) = bonds.get(quote.bond_code.as_str()) {
+                if !underlying_closes.contains_key(&bond.underlying_code) {
+                    let close = self.find_close(&bond.underlying_code, date).await?;
+                    underlying_closes.insert(bond.underlying_code.clone(), close);
+                }
+
+                quote.underlying_close = underlying_closes[&bond.underlying_code].clone();
+                quote.conversion_premium = match (&quote.close, &quote.underlying_close) {
+                    (Some(close), Some(underlying_close)) => bond.conversion_premium(close, underlying_close),
+                    _ => None,
+                };
+            }
+
+            self.convertible_bond_repository.save_quote(&quote).await?;
+        }
+
+        Ok(())
+    }
+
+    /// 標的股票指定日期的收盤價，股票或當日價格不存在時為 None
+    async fn find_close(&self, stock_code: &str, date: Date) -> Result<Option<BigDecimal>> {
+        let Some(stock) = self.stock_repository.find_by_code(stock_code).await? else {
+            return Ok(None);
+        };
+
+        let prices = self
+            .stock_price_repository
+            .find_by_stock_id_and_date_range(&stock.id, Some(date), Some(date))
+            .await?;
+        Ok(prices.into_iter().next().map(|p| p.close))
+    }
+
+    /// 標的股票尚流通的可轉債及其最近一筆行情
+    pub async fn get_outstanding_bonds(&self, stock_code: &str) -> Result<Vec<ConvertibleBondDto>> {
+        let bonds = self
+            .convertible_bond_repository
+            .find_outstanding_by_underlying(stock_code)
+            .await?;
+
+        let mut dtos = Vec::with_capacity(bonds.len());
+        for bond in bonds {
+            let latest_quote = self.convertible_bond_repository.find_latest_quote(&bond.bond_code).await?;
+            dtos.push(ConvertibleBondDto::new(bond, latest_quote));
+        }
+
+        Ok(dtos)
+    }
+}
@@ -0,0 +1,24 @@
+use crate::domain::models::DayTradingStat;
+use crate::domain::repositories::DayTradingRepository;
+use crate::domain::value_objects::Result;
+use std::sync::Arc;
+
+/// 當日沖銷交易統計服務，當沖量隨股價查詢一併回傳
+pub struct DayTradingService {
+    day_trading_repository: Arc<dyn DayTradingRepository>,
+}
+
+impl DayTradingService {
+    pub fn new(day_trading_repository: Arc<dyn DayTradingRepository>) -> Self {
+        Self {
+            day_trading_repository,
+        }
+    }
+
+    pub async fn save_stats(&self, stats: &[DayTradingStat]) -> Result<()> {
+        for stat in stats {
+            self.day_trading_repository.save(stat).await?;
+        }
+        Ok(())
+    }
+}
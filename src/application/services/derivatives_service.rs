@@ -0,0 +1,182 @@
+use crate::application::dtos::{
+    FuturesBasisDto, FuturesDailyPriceDto, InstitutionalOpenInterestDto, PutCallRatioDto,
+};
+use crate::domain::models::{FuturesDailyPrice, InstitutionalOpenInterest, OptionsDailyPrice};
+use crate::domain::repositories::{DerivativesRepository, IndexLevelRepository};
+use crate::domain::value_objects::{PutCallRatio, Result};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use time::Date;
+
+/// 期貨基差所對照的現貨指數
+const BASIS_INDEX_CODE: &str = "TAIEX";
+
+/// 期貨、選擇權及期貨法人未平倉服務
+pub struct DerivativesService {
+    derivatives_repository: Arc<dyn DerivativesRepository>,
+    index_level_repository: Arc<dyn IndexLevelRepository>,
+}
+
+impl DerivativesService {
+    pub fn new(
+        derivatives_repository: Arc<dyn DerivativesRepository>,
+        index_level_repository: Arc<dyn IndexLevelRepository>,
+    ) -> Self {
+        Self {
+            derivatives_repository,
+            index_level_repository,
+        }
+    }
+
+    fn parse_date(date_str: Option<String>) -> Result<Option<Date>> {
+        match date_str {
+            Some(date_str) => Ok(Some(Date::parse(
+                &date_str,
+                &time::format_description::well_known::Iso8601::DATE,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn save_futures_prices(&self, prices: &[FuturesDailyPrice]) -> Result<()> {
+        for price in prices {
+            self.derivatives_repository.save_futures_price(price).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn save_options_prices(&self, prices: &[OptionsDailyPrice]) -> Result<()> {
+        for price in prices {
+            self.derivatives_repository.save_options_price(price).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn save_institutional_open_interest(&self, positions: &[InstitutionalOpenInterest]) -> Result<()> {
+        for position in positions {
+            self.derivatives_repository.save_institutional_open_interest(position).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn get_futures_prices(
+        &self,
+        contract_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<FuturesDailyPriceDto>> {
+        let prices = self
+            .derivatives_repository
+            .find_futures_prices(
+                &contract_code.to_uppercase(),
+                Self::parse_date(start_date_str)?,
+                Self::parse_date(end_date_str)?,
+            )
+            .await?;
+
+        Ok(prices.into_iter().map(FuturesDailyPriceDto::from).collect())
+    }
+
+    pub async fn get_institutional_open_interest(
+        &self,
+        contract_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<InstitutionalOpenInterestDto>> {
+        let positions = self
+            .derivatives_repository
+            .find_institutional_open_interest(
+                &contract_code.to_uppercase(),
+                Self::parse_date(start_date_str)?,
+                Self::parse_date(end_date_str)?,
+            )
+            .await?;
+
+        Ok(positions.into_iter().map(InstitutionalOpenInterestDto::from).collect())
+    }
+
+    /// 計算區間內每日的 Put/Call Ratio，日期由新到舊
+    pub async fn get_put_call_ratios(
+        &self,
+        contract_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<PutCallRatioDto>> {
+        let options = self
+            .derivatives_repository
+            .find_options_prices(
+                &contract_code.to_uppercase(),
+                Self::parse_date(start_date_str)?,
+                Self::parse_date(end_date_str)?,
+            )
+            .await?;
+
+        let mut dates: Vec<Date> = options.iter().map(|o| o.date).collect();
+        dates.sort();
+        dates.dedup();
+
+        Ok(dates
+            .into_iter()
+            .rev()
+            .map(|date| PutCallRatioDto::from(PutCallRatio::from_options(date, &options)))
+            .collect())
+    }
+
+    /// 計算區間內每日近月期貨與加權指數的基差 (期貨收盤 - 指數收盤)
+    pub async fn get_futures_basis(
+        &self,
+        contract_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<FuturesBasisDto>> {
+        let start_date = Self::parse_date(start_date_str)?;
+        let end_date = Self::parse_date(end_date_str)?;
+
+        let prices = self
+            .derivatives_repository
+            .find_futures_prices(&contract_code.to_uppercase(), start_date, end_date)
+            .await?;
+
+        let index_closes: BTreeMap<Date, f64> = self
+            .index_level_repository
+            .find_by_index_code_and_date_range(BASIS_INDEX_CODE, start_date, end_date)
+            .await?
+            .into_iter()
+            .map(|level| (level.date, level.close.to_string().parse::<f64>().unwrap_or(0.0)))
+            .collect();
+
+        // 每個交易日取到期月份最近的月契約作為近月
+        let mut near_months: BTreeMap<Date, FuturesDailyPrice> = BTreeMap::new();
+        for price in prices.into_iter().filter(|p| p.is_monthly()) {
+            let is_nearer = near_months
+                .get(&price.date)
+                .is_none_or(|current| price.delivery_month < current.delivery_month);
+            if is_nearer {
+                near_months.insert(price.date, price);
+            }
+        }
+
+        Ok(near_months
+            .into_iter()
+            .rev()
+            .filter_map(|(date, price)| {
+                let index_close = *index_closes.get(&date)?;
+                let futures_close = price
+                    .close
+                    .or(price.settlement_price)?
+                    .to_string()
+                    .parse::<f64>()
+                    .ok()?;
+
+                Some(FuturesBasisDto {
+                    contract_code: price.contract_code,
+                    delivery_month: price.delivery_month,
+                    date: date.to_string(),
+                    futures_close,
+                    index_close,
+                    basis: ((futures_close - index_close) * 100.0).round() / 100.0,
+                })
+            })
+            .collect())
+    }
+}
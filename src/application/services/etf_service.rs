@@ -0,0 +1,65 @@
+use crate::application::dtos::{EtfConstituentDto, EtfNavDto};
+use crate::domain::models::{EtfConstituent, EtfNav};
+use crate::domain::repositories::EtfRepository;
+use crate::domain::value_objects::Result;
+use std::sync::Arc;
+use time::Date;
+
+/// ETF 淨值、折溢價及成分股服務
+pub struct EtfService {
+    etf_repository: Arc<dyn EtfRepository>,
+}
+
+impl EtfService {
+    pub fn new(etf_repository: Arc<dyn EtfRepository>) -> Self {
+        Self { etf_repository }
+    }
+
+    pub async fn save_navs(&self, navs: &[EtfNav]) -> Result<()> {
+        for nav in navs {
+            self.etf_repository.save_nav(nav).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn save_constituents(&self, etf_code: &str, date: Date, constituents: &[EtfConstituent]) -> Result<()> {
+        self.etf_repository.replace_constituents(etf_code, date, constituents).await
+    }
+
+    pub async fn get_navs(
+        &self,
+        etf_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<EtfNavDto>> {
+        let start_date = if let Some(date_str) = start_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let end_date = if let Some(date_str) = end_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let navs = self
+            .etf_repository
+            .find_navs(etf_code, start_date, end_date)
+            .await?;
+
+        Ok(navs.into_iter().map(EtfNavDto::from).collect())
+    }
+
+    pub async fn get_constituents(&self, etf_code: &str) -> Result<Vec<EtfConstituentDto>> {
+        let constituents = self.etf_repository.find_latest_constituents(etf_code).await?;
+        Ok(constituents.into_iter().map(EtfConstituentDto::from).collect())
+    }
+
+    /// 持有指定股票的 ETF 及其權重
+    pub async fn get_holders(&self, stock_code: &str) -> Result<Vec<EtfConstituentDto>> {
+        let holders = self.etf_repository.find_latest_holders(stock_code).await?;
+        Ok(holders.into_iter().map(EtfConstituentDto::from).collect())
+    }
+}
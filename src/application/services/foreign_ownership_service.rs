@@ -0,0 +1,83 @@
+use crate::application::dtos::ForeignOwnershipDto;
+use crate::domain::models::{ForeignOwnership, SharesOutstanding};
+use crate::domain::repositories::{ForeignOwnershipRepository, SharesOutstandingRepository};
+use crate::domain::value_objects::Result;
+use bigdecimal::BigDecimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use time::Date;
+
+/// 外資持股比例服務
+pub struct ForeignOwnershipService {
+    foreign_ownership_repository: Arc<dyn ForeignOwnershipRepository>,
+    shares_outstanding_repository: Arc<dyn SharesOutstandingRepository>,
+}
+
+impl ForeignOwnershipService {
+    pub fn new(
+        foreign_ownership_repository: Arc<dyn ForeignOwnershipRepository>,
+        shares_outstanding_repository: Arc<dyn SharesOutstandingRepository>,
+    ) -> Self {
+        Self {
+            foreign_ownership_repository,
+            shares_outstanding_repository,
+        }
+    }
+
+    /// 保存外資持股資料，並將其中的發行股數記錄為股本異動
+    pub async fn save_records(&self, records: &[ForeignOwnership]) -> Result<()> {
+        for record in records {
+            self.foreign_ownership_repository.save(record).await?;
+            let shares = SharesOutstanding::new(record.stock_code.clone(), record.date, record.issued_shares);
+            self.shares_outstanding_repository.record(&shares).await?;
+        }
+        Ok(())
+    }
+
+    /// 每支股票最新的外資持股比率 (%)，以股票代碼為鍵
+    pub async fn get_latest_ratios(&self) -> Result<HashMap<String, BigDecimal>> {
+        let records = self.foreign_ownership_repository.find_latest().await?;
+        Ok(records
+            .into_iter()
+            .map(|r| (r.stock_code, r.foreign_held_ratio))
+            .collect())
+    }
+
+    /// 單一股票最新的外資持股比率 (%)
+    pub async fn get_latest_ratio(&self, stock_code: &str) -> Result<Option<BigDecimal>> {
+        let record = self.foreign_ownership_repository.find_latest_by_stock_code(stock_code).await?;
+        Ok(record.map(|r| r.foreign_held_ratio))
+    }
+
+    /// 單一股票最新的發行股數
+    pub async fn get_latest_issued_shares(&self, stock_code: &str) -> Result<Option<u64>> {
+        let record = self.foreign_ownership_repository.find_latest_by_stock_code(stock_code).await?;
+        Ok(record.map(|r| r.issued_shares))
+    }
+
+    pub async fn get_foreign_ownership(
+        &self,
+        stock_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<ForeignOwnershipDto>> {
+        let start_date = if let Some(date_str) = start_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let end_date = if let Some(date_str) = end_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let records = self
+            .foreign_ownership_repository
+            .find_by_stock_code_and_date_range(stock_code, start_date, end_date)
+            .await?;
+
+        Ok(records.into_iter().map(ForeignOwnershipDto::from).collect())
+    }
+}
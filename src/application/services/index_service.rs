@@ -0,0 +1,48 @@
+use crate::application::dtos::IndexLevelDto;
+use crate::domain::models::IndexLevel;
+use crate::domain::repositories::IndexLevelRepository;
+use crate::domain::value_objects::Result;
+use std::sync::Arc;
+use time::Date;
+
+pub struct IndexService {
+    index_level_repository: Arc<dyn IndexLevelRepository>,
+}
+
+impl IndexService {
+    pub fn new(index_level_repository: Arc<dyn IndexLevelRepository>) -> Self {
+        Self {
+            index_level_repository,
+        }
+    }
+
+    pub async fn save_index_level(&self, index_level: &IndexLevel) -> Result<()> {
+        self.index_level_repository.save(index_level).await
+    }
+
+    pub async fn get_index_prices(
+        &self,
+        index_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<IndexLevelDto>> {
+        let start_date = if let Some(date_str) = start_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let end_date = if let Some(date_str) = end_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let index_levels = self
+            .index_level_repository
+            .find_by_index_code_and_date_range(&index_code.to_uppercase(), start_date, end_date)
+            .await?;
+
+        Ok(index_levels.into_iter().map(IndexLevelDto::from).collect())
+    }
+}
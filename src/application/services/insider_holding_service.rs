@@ -0,0 +1,86 @@
+use crate::application::dtos::InsiderHoldingDto;
+use crate::application::services::ForeignOwnershipService;
+use crate::domain::models::InsiderHolding;
+use crate::domain::repositories::InsiderHoldingRepository;
+use crate::domain::value_objects::{Result, Watchlist};
+use bigdecimal::BigDecimal;
+use std::collections::HashSet;
+use std::sync::Arc;
+use time::Date;
+
+/// 董監持股及質押服務，保存每月資料並挑出設質比率升破門檻的自選股
+pub struct InsiderHoldingService {
+    insider_holding_repository: Arc<dyn InsiderHoldingRepository>,
+    foreign_ownership_service: Arc<ForeignOwnershipService>,
+    watchlist: Watchlist,
+    pledge_alert_threshold: BigDecimal,
+}
+
+impl InsiderHoldingService {
+    pub fn new(
+        insider_holding_repository: Arc<dyn InsiderHoldingRepository>,
+        foreign_ownership_service: Arc<ForeignOwnershipService>,
+        watchlist: Watchlist,
+        pledge_alert_threshold: BigDecimal,
+    ) -> Self {
+        Self {
+            insider_holding_repository,
+            foreign_ownership_service,
+            watchlist,
+            pledge_alert_threshold,
+        }
+    }
+
+    pub fn pledge_alert_threshold(&self) -> &BigDecimal {
+        &self.pledge_alert_threshold
+    }
+
+    /// 指定月份是否已爬取完所有公司
+    pub async fn is_period_completed(&self, period: Date) -> Result<bool> {
+        self.insider_holding_repository.is_period_completed(period).await
+    }
+
+    /// 指定月份已保存資料的股票代碼，中斷後重新爬取時略過這些公司
+    pub async fn get_saved_stock_codes(&self, period: Date) -> Result<HashSet<String>> {
+        let codes = self.insider_holding_repository.find_stock_codes_by_period(period).await?;
+        Ok(codes.into_iter().collect())
+    }
+
+    pub async fn mark_period_completed(&self, period: Date) -> Result<()> {
+        self.insider_holding_repository.mark_period_completed(period).await
+    }
+
+    /// 補上發行股數後保存，設質比率由門檻以下升破門檻的自選股回傳保存的資料
+    pub async fn ingest(&self, mut holding: InsiderHolding) -> Result<Option<InsiderHolding>> {
+        holding.issued_shares = self.foreign_ownership_service.get_latest_issued_shares(&holding.stock_code).await?;
+        self.insider_holding_repository.save(&holding).await?;
+
+        if !self.watchlist.codes.contains(&holding.stock_code) || !self.exceeds_threshold(&holding) {
+            return Ok(None);
+        }
+
+        let previous = self
+            .insider_holding_repository
+            .find_previous(&holding.stock_code, holding.period)
+            .await?;
+        if previous.is_some_and(|p| self.exceeds_threshold(&p)) {
+            return Ok(None);
+        }
+        Ok(Some(holding))
+    }
+
+    fn exceeds_threshold(&self, holding: &InsiderHolding) -> bool {
+        holding
+            .pledge_ratio()
+            .is_some_and(|ratio| ratio > self.pledge_alert_threshold)
+    }
+
+    pub async fn get_insider_holdings(&self, stock_code: &str) -> Result<Vec<InsiderHoldingDto>> {
+        let holdings = self
+            .insider_holding_repository
+            .find_by_stock_code(stock_code)
+            .await?;
+
+        Ok(holdings.into_iter().map(InsiderHoldingDto::from).collect())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::application::dtos::IntradayBarDto;
+use crate::domain::models::IntradayQuote;
+use crate::domain::repositories::{IntradayBarRepository, StockRepository};
+use crate::domain::services::BarAggregator;
+use crate::domain::value_objects::{BarInterval, Result};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock};
+use time::Date;
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// 盤中 K 棒服務，將即時報價彙整為 1/5/15/60 分鐘 K 棒並儲存
+pub struct IntradayBarService {
+    intraday_bar_repository: Arc<dyn IntradayBarRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    bar_aggregator: Mutex<BarAggregator>,
+    // 股票代碼對應的股票 ID，避免每筆報價都查詢資料庫
+    stock_ids: RwLock<HashMap<String, Uuid>>,
+}
+
+impl IntradayBarService {
+    pub fn new(
+        intraday_bar_repository: Arc<dyn IntradayBarRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+    ) -> Self {
+        Self {
+            intraday_bar_repository,
+            stock_repository,
+            bar_aggregator: Mutex::new(BarAggregator::new(BarInterval::ALL.to_vec())),
+            stock_ids: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 將一批即時報價彙整進 K 棒，並寫入有變動的 K 棒
+    pub async fn ingest(&self, quotes: &[IntradayQuote]) -> Result<()> {
+        let mut updated_bars = Vec::new();
+
+        for quote in quotes {
+            let stock_id = match self.resolve_stock_id(&quote.stock_code).await? {
+                Some(stock_id) => stock_id,
+                None => {
+                    info!("資料庫中沒有股票 {}，略過 K 棒彙整", quote.stock_code);
+                    continue;
+                }
+            };
+
+            let mut bar_aggregator = self.bar_aggregator.lock().unwrap();
+            updated_bars.extend(bar_aggregator.on_quote(stock_id, quote));
+        }
+
+        for bar in &updated_bars {
+            if let Err(e) = self.intraday_bar_repository.save(bar).await {
+                error!("保存 K 棒失敗: {} {}, 錯誤: {}", bar.stock_id, bar.interval.as_str(), e);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_bars_by_stock_code(
+        &self,
+        stock_code: &str,
+        interval: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Option<Vec<IntradayBarDto>>> {
+        let interval = BarInterval::from_str(interval)?;
+
+        let stock_id = match self.resolve_stock_id(stock_code).await? {
+            Some(stock_id) => stock_id,
+            None => return Ok(None),
+        };
+
+        let start_date = if let Some(date_str) = start_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let end_date = if let Some(date_str) = end_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let bars = self
+            .intraday_bar_repository
+            .find_by_stock_id_and_date_range(&stock_id, interval, start_date, end_date)
+            .await?;
+
+        Ok(Some(bars.into_iter().map(IntradayBarDto::from).collect()))
+    }
+
+    async fn resolve_stock_id(&self, stock_code: &str) -> Result<Option<Uuid>> {
+        if let Some(stock_id) = self.stock_ids.read().unwrap().get(stock_code) {
+            return Ok(Some(*stock_id));
+        }
+
+        let stock = self.stock_repository.find_by_code(stock_code).await?;
+        if let Some(stock) = &stock {
+            self.stock_ids.write().unwrap().insert(stock_code.to_string(), stock.id);
+        }
+
+        Ok(stock.map(|s| s.id))
+    }
+}
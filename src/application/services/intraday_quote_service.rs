@@ -0,0 +1,67 @@
+use crate::application::dtos::IntradayQuoteDto;
+use crate::domain::models::IntradayQuote;
+use crate::domain::repositories::IntradayQuoteRepository;
+use crate::domain::value_objects::{Result, Watchlist};
+use crate::infra::external_services::twse_mis_service::TwseMisService;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tracing::error;
+
+/// 盤中即時報價服務，在記憶體中保存每支股票的最新報價，並可選擇寫入資料庫
+pub struct IntradayQuoteService {
+    mis_service: Arc<TwseMisService>,
+    intraday_quote_repository: Option<Arc<dyn IntradayQuoteRepository>>,
+    latest_quotes: RwLock<HashMap<String, IntradayQuote>>,
+}
+
+impl IntradayQuoteService {
+    /// 創建新的即時報價服務實例，未提供儲存庫時僅保存在記憶體
+    pub fn new(
+        mis_service: Arc<TwseMisService>,
+        intraday_quote_repository: Option<Arc<dyn IntradayQuoteRepository>>,
+    ) -> Self {
+        Self {
+            mis_service,
+            intraday_quote_repository,
+            latest_quotes: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 輪詢一次自選股的即時報價，更新記憶體中的最新報價
+    pub async fn poll(&self, watchlist: &Watchlist) -> Result<Vec<IntradayQuote>> {
+        let quotes = self.mis_service.fetch_quotes(&watchlist.codes).await?;
+
+        {
+            let mut latest_quotes = self.latest_quotes.write().unwrap();
+            for quote in &quotes {
+                latest_quotes.insert(quote.stock_code.clone(), quote.clone());
+            }
+        }
+
+        if let Some(repository) = &self.intraday_quote_repository {
+            for quote in &quotes {
+                if let Err(e) = repository.save(quote).await {
+                    error!("保存即時報價失敗: {}, 錯誤: {}", quote.stock_code, e);
+                }
+            }
+        }
+
+        Ok(quotes)
+    }
+
+    pub fn get_latest_quote(&self, stock_code: &str) -> Option<IntradayQuoteDto> {
+        let latest_quotes = self.latest_quotes.read().unwrap();
+        latest_quotes.get(stock_code).cloned().map(IntradayQuoteDto::from)
+    }
+
+    pub fn get_all_latest_quotes(&self) -> Vec<IntradayQuoteDto> {
+        let latest_quotes = self.latest_quotes.read().unwrap();
+        let mut quotes: Vec<IntradayQuoteDto> = latest_quotes
+            .values()
+            .cloned()
+            .map(IntradayQuoteDto::from)
+            .collect();
+        quotes.sort_by(|a, b| a.stock_code.cmp(&b.stock_code));
+        quotes
+    }
+}
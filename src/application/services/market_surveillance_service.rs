@@ -0,0 +1,73 @@
+use crate::application::dtos::MarketSurveillanceEventDto;
+use crate::domain::models::{MarketSurveillanceEvent, SurveillanceType};
+use crate::domain::repositories::MarketSurveillanceRepository;
+use crate::domain::services::MarketClock;
+use crate::domain::value_objects::Result;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 注意股、處置股公告服務
+pub struct MarketSurveillanceService {
+    market_surveillance_repository: Arc<dyn MarketSurveillanceRepository>,
+    market_clock: Arc<MarketClock>,
+}
+
+impl MarketSurveillanceService {
+    pub fn new(
+        market_surveillance_repository: Arc<dyn MarketSurveillanceRepository>,
+        market_clock: Arc<MarketClock>,
+    ) -> Self {
+        Self {
+            market_surveillance_repository,
+            market_clock,
+        }
+    }
+
+    pub async fn save_events(&self, events: &[MarketSurveillanceEvent]) -> Result<()> {
+        for event in events {
+            self.market_surveillance_repository.save(event).await?;
+        }
+        Ok(())
+    }
+
+    /// 目前有效的監視標記，以股票代碼為鍵
+    ///
+    /// 處置股以處置期間判斷；注意股於收盤後公告，公告日及下一個交易日都視為有效。
+    /// 指定股票代碼時只查詢這些股票，未指定時查詢全部
+    pub async fn get_current_flags(&self, stock_codes: Option<&[String]>) -> Result<HashMap<String, Vec<SurveillanceType>>> {
+        let today = self.market_clock.today();
+        let previous_trading_day = self.market_clock.trading_calendar().previous_trading_day(today);
+
+        let events = self
+            .market_surveillance_repository
+            .find_overlapping(previous_trading_day, today, stock_codes)
+            .await?;
+
+        let mut flags: HashMap<String, Vec<SurveillanceType>> = HashMap::new();
+        for event in events {
+            let is_active = match event.event_type {
+                SurveillanceType::Attention => true,
+                SurveillanceType::Disposition => event.is_active_on(today),
+            };
+            if !is_active {
+                continue;
+            }
+
+            let stock_flags = flags.entry(event.stock_code).or_default();
+            if !stock_flags.contains(&event.event_type) {
+                stock_flags.push(event.event_type);
+            }
+        }
+
+        Ok(flags)
+    }
+
+    pub async fn get_events_by_stock_code(&self, stock_code: &str) -> Result<Vec<MarketSurveillanceEventDto>> {
+        let events = self
+            .market_surveillance_repository
+            .find_by_stock_code(stock_code)
+            .await?;
+
+        Ok(events.into_iter().map(MarketSurveillanceEventDto::from).collect())
+    }
+}
@@ -0,0 +1,85 @@
+use crate::application::dtos::MaterialAnnouncementDto;
+use crate::domain::models::MaterialAnnouncement;
+use crate::domain::repositories::MaterialAnnouncementRepository;
+use crate::domain::value_objects::{KeywordFilter, Result, Watchlist};
+use std::sync::Arc;
+use time::Date;
+use tracing::error;
+
+/// 重大訊息服務，保存重大訊息並挑出自選股中符合關鍵字的新訊息
+pub struct MaterialAnnouncementService {
+    material_announcement_repository: Arc<dyn MaterialAnnouncementRepository>,
+    watchlist: Watchlist,
+    notify_filter: KeywordFilter,
+}
+
+impl MaterialAnnouncementService {
+    pub fn new(
+        material_announcement_repository: Arc<dyn MaterialAnnouncementRepository>,
+        watchlist: Watchlist,
+        notify_filter: KeywordFilter,
+    ) -> Self {
+        Self {
+            material_announcement_repository,
+            watchlist,
+            notify_filter,
+        }
+    }
+
+    /// 保存重大訊息，回傳需要推播的新訊息 (自選股且主旨或說明符合關鍵字)
+    pub async fn ingest(&self, announcements: &[MaterialAnnouncement]) -> Result<Vec<MaterialAnnouncement>> {
+        let mut to_notify = Vec::new();
+
+        for announcement in announcements {
+            // 單筆保存失敗時繼續處理其他訊息，避免已保存訊息的通知因提前返回而遺失
+            let is_new = match self.material_announcement_repository.save(announcement).await {
+                Ok(is_new) => is_new,
+                Err(e) => {
+                    error!("保存重大訊息失敗: {} {}, 錯誤: {}", announcement.stock_code, announcement.title, e);
+                    continue;
+                }
+            };
+            if !is_new || !self.watchlist.codes.contains(&announcement.stock_code) {
+                continue;
+            }
+
+            if self.notify_filter.matches(&announcement.title) || self.notify_filter.matches(&announcement.body) {
+                to_notify.push(announcement.clone());
+            }
+        }
+
+        Ok(to_notify)
+    }
+
+    pub async fn get_announcements(
+        &self,
+        stock_code: Option<&str>,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+        keywords: Option<String>,
+    ) -> Result<Vec<MaterialAnnouncementDto>> {
+        let start_date = if let Some(date_str) = start_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let end_date = if let Some(date_str) = end_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let filter = KeywordFilter::parse(&keywords.unwrap_or_default());
+        let announcements = self
+            .material_announcement_repository
+            .find_by_date_range(stock_code, start_date, end_date)
+            .await?;
+
+        Ok(announcements
+            .into_iter()
+            .filter(|a| filter.matches(&a.title) || filter.matches(&a.body))
+            .map(MaterialAnnouncementDto::from)
+            .collect())
+    }
+}
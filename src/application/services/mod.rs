@@ -1,7 +1,43 @@
 mod stock_service;
 mod stock_price_service;
 mod notification_service;
+mod intraday_quote_service;
+mod intraday_bar_service;
+mod index_service;
+mod derivatives_service;
+mod market_surveillance_service;
+mod material_announcement_service;
+mod foreign_ownership_service;
+mod day_trading_service;
+mod off_session_trading_service;
+mod block_trade_service;
+mod insider_holding_service;
+mod treasury_buyback_service;
+mod public_subscription_service;
+mod etf_service;
+mod convertible_bond_service;
+mod price_reconciliation_service;
+mod price_gap_service;
+mod stock_listing_service;
 
 pub use stock_service::StockService;
 pub use stock_price_service::StockPriceService;
 pub use notification_service::NotificationService;
+pub use intraday_quote_service::IntradayQuoteService;
+pub use intraday_bar_service::IntradayBarService;
+pub use index_service::IndexService;
+pub use derivatives_service::DerivativesService;
+pub use market_surveillance_service::MarketSurveillanceService;
+pub use material_announcement_service::MaterialAnnouncementService;
+pub use foreign_ownership_service::ForeignOwnershipService;
+pub use day_trading_service::DayTradingService;
+pub use off_session_trading_service::OffSessionTradingService;
+pub use block_trade_service::BlockTradeService;
+pub use insider_holding_service::InsiderHoldingService;
+pub use treasury_buyback_service::TreasuryBuybackService;
+pub use public_subscription_service::PublicSubscriptionService;
+pub use etf_service::EtfService;
+pub use convertible_bond_service::ConvertibleBondService;
+pub use price_reconciliation_service::PriceReconciliationService;
+pub use price_gap_service::PriceGapService;
+pub use stock_listing_service::StockListingService;
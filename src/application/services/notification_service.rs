@@ -1,10 +1,14 @@
-use crate::domain::models::{Stock, StockPrice};
-use crate::infra::external_services::line_notification_service::LineNotificationService;
+use crate::application::dtos::StockDto;
+use crate::domain::models::{InsiderHolding, ListingStatus, MaterialAnnouncement, PriceDiscrepancy, TreasuryBuyback, Stock, PriceSource, StockPrice, SurveillanceType, UniverseChange};
+use crate::infra::external_services::line_notification_service::{DailySummaryItem, LineNotificationService};
 use crate::application::services::{StockService, StockPriceService};
+use crate::domain::services::MarketClock;
+use crate::domain::value_objects::LimitStatus;
 use anyhow::{Result, Context};
 use std::sync::Arc;
 use bigdecimal::BigDecimal;
 use std::str::FromStr;
+use time::Date;
 use tracing::info;
 use uuid::Uuid;
 
@@ -13,6 +17,7 @@ pub struct NotificationService {
     stock_service: Arc<StockService>,
     price_service: Arc<StockPriceService>,
     line_service: Arc<LineNotificationService>,
+    market_clock: Arc<MarketClock>,
 }
 
 impl NotificationService {
@@ -20,6 +25,7 @@ impl NotificationService {
     pub fn new(
         stock_service: Arc<StockService>,
         price_service: Arc<StockPriceService>,
+        market_clock: Arc<MarketClock>,
     ) -> Result<Self> {
         // 從環境變數獲取 LINE 配置
         let channel_access_token = std::env::var("LINE_CHANNEL_ACCESS_TOKEN")
@@ -38,9 +44,22 @@ impl NotificationService {
             stock_service,
             price_service,
             line_service,
+            market_clock,
         })
     }
     
+    /// 由股票 DTO 取得注意股、處置股標記
+    fn surveillance_types(stock: &StockDto) -> Vec<SurveillanceType> {
+        let mut types = Vec::new();
+        if stock.is_attention {
+            types.push(SurveillanceType::Attention);
+        }
+        if stock.is_disposition {
+            types.push(SurveillanceType::Disposition);
+        }
+        types
+    }
+    
     /// 發送股票價格通知
     pub async fn send_stock_price_notification(&self, stock_code: &str) -> Result<()> {
         // 獲取股票資訊
@@ -53,8 +72,8 @@ impl NotificationService {
             .await?
             .context(format!("找不到股票 {} 的最新價格", stock_code))?;
         
-        // 當前日期
-        let date = time::OffsetDateTime::now_utc().date();
+        // 當前日期 (台北時間)
+        let date = self.market_clock.today();
         
         // 將 DTO 轉換為模型
         let price_model = StockPrice {
@@ -77,6 +96,11 @@ impl NotificationService {
             foreign_buy: latest_price.foreign_buy,
             trust_buy: latest_price.trust_buy,
             dealer_buy: latest_price.dealer_buy,
+            limit_status: latest_price.limit_status.as_deref().and_then(LimitStatus::parse),
+            day_trade_volume: latest_price.day_trade_volume,
+            issued_shares: latest_price.issued_shares,
+            insider_shares: None,
+            source: latest_price.source.as_deref().and_then(PriceSource::parse).unwrap_or_default(),
         };
         
         // 發送通知
@@ -85,17 +109,20 @@ impl NotificationService {
             code: stock.code.clone(),
             name: stock.name.clone(),
             last_updated: time::OffsetDateTime::now_utc(),
+            status: ListingStatus::parse(&stock.status).unwrap_or_default(),
+            listed_date: None,
+            status_changed_on: None,
         };
         
-        self.line_service.send_stock_price_notification(&stock_model, &price_model).await?;
+        self.line_service.send_stock_price_notification(&stock_model, &price_model, &Self::surveillance_types(&stock)).await?;
         
         Ok(())
     }
     
     /// 發送每日股票摘要通知
     pub async fn send_daily_summary(&self) -> Result<()> {
-        // 獲取當前日期
-        let today = time::OffsetDateTime::now_utc().date();
+        // 獲取當前日期 (台北時間)
+        let today = self.market_clock.today();
         
         // 獲取所有股票
         let stocks = self.stock_service.get_all_stocks().await?;
@@ -107,13 +134,18 @@ impl NotificationService {
         // 收集所有股票的最新價格
         let mut stock_prices = Vec::new();
         for stock in &stocks {
+            // 暫停交易或已下市的股票沒有當日價格，不列入摘要
+            if ListingStatus::parse(&stock.status).is_some_and(|status| status != ListingStatus::Active) {
+                continue;
+            }
+
             // 獲取最新價格
             let latest_price = self.price_service.get_latest_price_by_stock_id(&stock.id)
                 .await?
                 .context(format!("找不到股票 {} 的最新價格", stock.code))?;
             
-            // 當前日期
-            let date = time::OffsetDateTime::now_utc().date();
+            // 當前日期 (台北時間)
+            let date = today;
             
             // 將 DTO 轉換為模型
             let price_model = StockPrice {
@@ -136,6 +168,11 @@ impl NotificationService {
                 foreign_buy: latest_price.foreign_buy,
                 trust_buy: latest_price.trust_buy,
                 dealer_buy: latest_price.dealer_buy,
+                limit_status: latest_price.limit_status.as_deref().and_then(LimitStatus::parse),
+                day_trade_volume: latest_price.day_trade_volume,
+                issued_shares: latest_price.issued_shares,
+                insider_shares: None,
+                source: latest_price.source.as_deref().and_then(PriceSource::parse).unwrap_or_default(),
             };
             
             // 發送通知
@@ -144,9 +181,17 @@ impl NotificationService {
                 code: stock.code.clone(),
                 name: stock.name.clone(),
                 last_updated: time::OffsetDateTime::now_utc(),
+                status: ListingStatus::parse(&stock.status).unwrap_or_default(),
+                listed_date: None,
+                status_changed_on: None,
             };
             
-            stock_prices.push((stock_model, price_model));
+            stock_prices.push(DailySummaryItem {
+                stock: stock_model,
+                price: price_model,
+                surveillance: Self::surveillance_types(stock),
+                foreign_ownership_ratio: stock.foreign_ownership_ratio,
+            });
         }
         
         if stock_prices.is_empty() {
@@ -160,6 +205,41 @@ impl NotificationService {
         Ok(())
     }
     
+    /// 發送重大訊息通知
+    pub async fn send_material_announcement(&self, announcement: &MaterialAnnouncement) -> Result<()> {
+        self.line_service.send_material_announcement(announcement).await?;
+        
+        Ok(())
+    }
+    
+    /// 發送董監設質比率升破門檻通知
+    pub async fn send_pledge_alert(&self, holding: &InsiderHolding, threshold: &BigDecimal) -> Result<()> {
+        self.line_service.send_pledge_alert(holding, threshold).await?;
+        
+        Ok(())
+    }
+    
+    /// 發送庫藏股買回計畫通知
+    pub async fn send_treasury_buyback(&self, buyback: &TreasuryBuyback) -> Result<()> {
+        self.line_service.send_treasury_buyback(buyback).await?;
+        
+        Ok(())
+    }
+    
+    /// 發送跨來源股價差異彙總
+    pub async fn send_price_discrepancy_digest(&self, date: Date, discrepancies: &[PriceDiscrepancy]) -> Result<()> {
+        self.line_service.send_price_discrepancy_digest(date, discrepancies).await?;
+        
+        Ok(())
+    }
+    
+    /// 發送上市櫃股票異動彙總
+    pub async fn send_universe_change_digest(&self, date: Date, changes: &[UniverseChange]) -> Result<()> {
+        self.line_service.send_universe_change_digest(date, changes).await?;
+        
+        Ok(())
+    }
+    
     /// 發送自訂訊息
     pub async fn send_custom_message(&self, message: &str) -> Result<()> {
         info!("發送自訂訊息: {}", message);
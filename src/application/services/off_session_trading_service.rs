@@ -0,0 +1,71 @@
+use crate::application::dtos::{AfterHoursTradeDto, OddLotQuoteDto};
+use crate::domain::models::{AfterHoursTrade, OddLotQuote};
+use crate::domain::repositories::OffSessionTradingRepository;
+use crate::domain::value_objects::Result;
+use std::sync::Arc;
+use time::Date;
+
+/// 盤中零股及盤後定價交易服務
+pub struct OffSessionTradingService {
+    off_session_trading_repository: Arc<dyn OffSessionTradingRepository>,
+}
+
+impl OffSessionTradingService {
+    pub fn new(off_session_trading_repository: Arc<dyn OffSessionTradingRepository>) -> Self {
+        Self {
+            off_session_trading_repository,
+        }
+    }
+
+    fn parse_date(date_str: Option<String>) -> Result<Option<Date>> {
+        match date_str {
+            Some(date_str) => Ok(Some(Date::parse(
+                &date_str,
+                &time::format_description::well_known::Iso8601::DATE,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn save_odd_lot_quotes(&self, quotes: &[OddLotQuote]) -> Result<()> {
+        for quote in quotes {
+            self.off_session_trading_repository.save_odd_lot_quote(quote).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn save_after_hours_trades(&self, trades: &[AfterHoursTrade]) -> Result<()> {
+        for trade in trades {
+            self.off_session_trading_repository.save_after_hours_trade(trade).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn get_odd_lot_quotes(
+        &self,
+        stock_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<OddLotQuoteDto>> {
+        let quotes = self
+            .off_session_trading_repository
+            .find_odd_lot_quotes(stock_code, Self::parse_date(start_date_str)?, Self::parse_date(end_date_str)?)
+            .await?;
+
+        Ok(quotes.into_iter().map(OddLotQuoteDto::from).collect())
+    }
+
+    pub async fn get_after_hours_trades(
+        &self,
+        stock_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<AfterHoursTradeDto>> {
+        let trades = self
+            .off_session_trading_repository
+            .find_after_hours_trades(stock_code, Self::parse_date(start_date_str)?, Self::parse_date(end_date_str)?)
+            .await?;
+
+        Ok(trades.into_iter().map(AfterHoursTradeDto::from).collect())
+    }
+}
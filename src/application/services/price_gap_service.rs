@@ -0,0 +1,190 @@
+use crate::application::dtos::PriceCoverageDto;
+use crate::domain::models::{PriceGap, PriceGapStatus, Stock};
+use crate::domain::repositories::{PriceGapRepository, QuarantinedPriceRepository, StockPriceRepository, StockRepository};
+use crate::domain::services::MarketClock;
+use crate::domain::value_objects::Result;
+use std::collections::HashSet;
+use std::sync::Arc;
+use time::Date;
+use tracing::info;
+
+/// 每個缺漏日期最多補抓的次數，超過後只保留紀錄不再重試
+const MAX_REPAIR_ATTEMPTS: u32 = 3;
+
+/// 期間內日 K 的保存情形
+struct DateCoverage {
+    start_date: Date,          // 實際起日
+    expected_days: usize,      // 應有的交易日數
+    missing: Vec<Date>,        // 未保存的交易日，包含已移至隔離區的日期
+    quarantined: HashSet<Date>, // 已移至隔離區的日期，仍算缺漏但不需補抓
+}
+
+/// 日 K 缺漏偵測服務，依交易日曆比對各股已保存的日期
+pub struct PriceGapService {
+    price_gap_repository: Arc<dyn PriceGapRepository>,
+    stock_price_repository: Arc<dyn StockPriceRepository>,
+    quarantined_price_repository: Arc<dyn QuarantinedPriceRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    market_clock: Arc<MarketClock>,
+    lookback_days: i64,
+}
+
+impl PriceGapService {
+    pub fn new(
+        price_gap_repository: Arc<dyn PriceGapRepository>,
+        stock_price_repository: Arc<dyn StockPriceRepository>,
+        quarantined_price_repository: Arc<dyn QuarantinedPriceRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        market_clock: Arc<MarketClock>,
+        lookback_days: i64,
+    ) -> Self {
+        Self {
+            price_gap_repository,
+            stock_price_repository,
+            quarantined_price_repository,
+            stock_repository,
+            market_clock,
+            lookback_days,
+        }
+    }
+
+    /// 比對最近 lookback_days 天內每支股票的缺漏日期並記錄，回傳偵測到的缺漏數
+    pub async fn detect_gaps(&self) -> Result<usize> {
+        let today = self.market_clock.today();
+        let mut total = 0;
+
+        for stock in self.stock_repository.find_all().await? {
+            // 暫停交易或已下市的股票不會有新的日 K
+            if !stock.is_active() {
+                continue;
+            }
+            let coverage = self.missing_dates(&stock, None, today).await?;
+            let missing: Vec<Date> = coverage
+                .missing
+                .into_iter()
+                .filter(|date| !coverage.quarantined.contains(date))
+                .collect();
+            if missing.is_empty() {
+                continue;
+            }
+            self.price_gap_repository.record_missing(&stock.code, &missing).await?;
+            total += missing.len();
+        }
+
+        let repaired = self.price_gap_repository.mark_repaired_where_present().await?;
+        info!("日 K 缺漏偵測完成，缺漏 {} 筆，已補齊 {} 筆", total, repaired);
+        Ok(total)
+    }
+
+    /// 尚待補抓的缺漏日期
+    pub async fn get_open_gaps(&self) -> Result<Vec<PriceGap>> {
+        self.price_gap_repository.find_open(MAX_REPAIR_ATTEMPTS).await
+    }
+
+    /// 記錄一次補抓結果，無成交或已移至隔離區的日期之後不再補抓
+    pub async fn record_repair(&self, gap: &PriceGap, status: PriceGapStatus) -> Result<()> {
+        self.price_gap_repository.record_attempt(&gap.stock_code, gap.date, status).await
+    }
+
+    /// 個股在期間內的日 K 完整度，未指定期間時為最近 lookback_days 天
+    pub async fn get_coverage(
+        &self,
+        stock_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Option<PriceCoverageDto>> {
+        let Some(stock) = self.stock_repository.find_by_code(stock_code).await? else {
+            return Ok(None);
+        };
+
+        let start_date = if let Some(date_str) = start_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let end_date = if let Some(date_str) = end_date_str {
+            Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?
+        } else {
+            self.market_clock.today()
+        };
+
+        let coverage = self.missing_dates(&stock, start_date, end_date).await?;
+        let mut quarantined: Vec<Date> = coverage.quarantined.into_iter().collect();
+        quarantined.sort();
+        Ok(Some(PriceCoverageDto::new(
+            stock.code,
+            coverage.start_date,
+            end_date,
+            coverage.expected_days,
+            coverage.missing,
+            quarantined,
+        )))
+    }
+
+    /// 期間內應有卻未保存的交易日
+    ///
+    /// 未指定起日時以 lookback_days 天前與該股第一筆日 K 較晚者為起日，避免把上市前的日期當成缺漏；
+    /// 已確認無成交的日期不列入應有的交易日，已移至隔離區的日期仍算缺漏
+    async fn missing_dates(
+        &self,
+        stock: &Stock,
+        start_date: Option<Date>,
+        end_date: Date,
+    ) -> Result<DateCoverage> {
+        let stored = self
+            .stock_price_repository
+            .find_dates_by_stock_id(&stock.id, None, Some(end_date))
+            .await?;
+
+        let start_date = match start_date {
+            Some(date) => date,
+            None => {
+                let window_start = end_date - time::Duration::days(self.lookback_days);
+                match stored.first() {
+                    Some(first) => window_start.max(*first),
+                    None => {
+                        return Ok(DateCoverage {
+                            start_date: window_start,
+                            expected_days: 0,
+                            missing: Vec::new(),
+                            quarantined: HashSet::new(),
+                        });
+                    }
+                }
+            }
+        };
+
+        let no_trade: HashSet<Date> = self
+            .price_gap_repository
+            .find_no_trade_dates(&stock.code, start_date, end_date)
+            .await?
+            .into_iter()
+            .collect();
+
+        let stored: HashSet<Date> = stored.into_iter().collect();
+        let expected: Vec<Date> = self
+            .market_clock
+            .trading_calendar()
+            .trading_days_between(start_date, end_date)
+            .into_iter()
+            .filter(|date| !no_trade.contains(date))
+            .collect();
+        let missing: Vec<Date> = expected.iter().copied().filter(|date| !stored.contains(date)).collect();
+
+        let quarantined: HashSet<Date> = self
+            .quarantined_price_repository
+            .find_dates_by_stock_id(&stock.id, start_date, end_date)
+            .await?
+            .into_iter()
+            .filter(|date| missing.contains(date))
+            .collect();
+
+        Ok(DateCoverage {
+            start_date,
+            expected_days: expected.len(),
+            missing,
+            quarantined,
+        })
+    }
+}
@@ -0,0 +1,102 @@
+use crate::application::dtos::PriceDiscrepancyDto;
+use crate::domain::models::{PriceDiscrepancy, StockPrice};
+use crate::domain::repositories::{PriceDiscrepancyRepository, StockPriceRepository, StockRepository};
+use crate::domain::value_objects::Result;
+use bigdecimal::BigDecimal;
+use std::sync::Arc;
+use time::Date;
+
+/// 跨資料來源股價比對服務，找出保存的股價與其他來源差異過大的欄位
+pub struct PriceReconciliationService {
+    price_discrepancy_repository: Arc<dyn PriceDiscrepancyRepository>,
+    stock_price_repository: Arc<dyn StockPriceRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    price_tolerance_percent: BigDecimal,
+    volume_tolerance_percent: BigDecimal,
+}
+
+impl PriceReconciliationService {
+    pub fn new(
+        price_discrepancy_repository: Arc<dyn PriceDiscrepancyRepository>,
+        stock_price_repository: Arc<dyn StockPriceRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        price_tolerance_percent: BigDecimal,
+        volume_tolerance_percent: BigDecimal,
+    ) -> Self {
+        Self {
+            price_discrepancy_repository,
+            stock_price_repository,
+            stock_repository,
+            price_tolerance_percent,
+            volume_tolerance_percent,
+        }
+    }
+
+    /// 以其他來源的日 K 比對資料庫中同日期的股價，保存並回傳超過容許差異的欄位
+    ///
+    /// 保存的股價本身就來自同一來源時不比對
+    pub async fn reconcile(&self, stock_code: &str, secondary_prices: &[StockPrice]) -> Result<Vec<PriceDiscrepancy>> {
+        let Some(stock) = self.stock_repository.find_by_code(stock_code).await? else {
+            return Ok(Vec::new());
+        };
+        let start_date = secondary_prices.iter().map(|p| p.date).min();
+        let end_date = secondary_prices.iter().map(|p| p.date).max();
+        if start_date.is_none() {
+            return Ok(Vec::new());
+        }
+
+        let stored_prices = self
+            .stock_price_repository
+            .find_by_stock_id_and_date_range(&stock.id, start_date, end_date)
+            .await?;
+
+        let mut discrepancies = Vec::new();
+        for secondary in secondary_prices {
+            let Some(primary) = stored_prices.iter().find(|p| p.date == secondary.date) else {
+                continue;
+            };
+            if primary.source == secondary.source {
+                continue;
+            }
+
+            let found = PriceDiscrepancy::compare(
+                stock_code,
+                primary,
+                secondary,
+                &self.price_tolerance_percent,
+                &self.volume_tolerance_percent,
+            );
+            self.price_discrepancy_repository
+                .replace(stock_code, secondary.date, secondary.source, &found)
+                .await?;
+            discrepancies.extend(found);
+        }
+
+        Ok(discrepancies)
+    }
+
+    pub async fn get_discrepancies(
+        &self,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<PriceDiscrepancyDto>> {
+        let start_date = if let Some(date_str) = start_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let end_date = if let Some(date_str) = end_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let discrepancies = self
+            .price_discrepancy_repository
+            .find_by_date_range(start_date, end_date)
+            .await?;
+
+        Ok(discrepancies.into_iter().map(PriceDiscrepancyDto::from).collect())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::application::dtos::PublicSubscriptionDto;
+use crate::domain::models::{PublicSubscription, Stock};
+use crate::domain::repositories::{PublicSubscriptionRepository, StockPriceRepository, StockRepository};
+use crate::domain::services::MarketClock;
+use crate::domain::value_objects::Result;
+use std::sync::Arc;
+use time::Date;
+use tracing::info;
+
+/// 公開申購服務，保存抽籤公告、計算市價溢價，並在新股掛牌時建立股票資料
+pub struct PublicSubscriptionService {
+    public_subscription_repository: Arc<dyn PublicSubscriptionRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    stock_price_repository: Arc<dyn StockPriceRepository>,
+    market_clock: Arc<MarketClock>,
+}
+
+impl PublicSubscriptionService {
+    pub fn new(
+        public_subscription_repository: Arc<dyn PublicSubscriptionRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        stock_price_repository: Arc<dyn StockPriceRepository>,
+        market_clock: Arc<MarketClock>,
+    ) -> Self {
+        Self {
+            public_subscription_repository,
+            stock_repository,
+            stock_price_repository,
+            market_clock,
+        }
+    }
+
+    pub async fn save_subscriptions(&self, subscriptions: &[PublicSubscription]) -> Result<()> {
+        for subscription in subscriptions {
+            self.public_subscription_repository.save(subscription).await?;
+        }
+        Ok(())
+    }
+
+    /// 將最近 30 天內掛牌的新股加入 stocks，回傳新增的股票數
+    pub async fn sync_listed_stocks(&self) -> Result<usize> {
+        let today = self.market_clock.today();
+        let subscriptions = self
+            .public_subscription_repository
+            .find_by_listing_date_range(today - time::Duration::days(30), today)
+            .await?;
+
+        let mut created = 0;
+        for subscription in subscriptions.iter().filter(|s| !s.is_cancelled) {
+            if self.stock_repository.find_by_code(&subscription.stock_code).await?.is_some() {
+                continue;
+            }
+
+            let stock = Stock::new(subscription.stock_code.clone(), subscription.stock_name.clone());
+            self.stock_repository.save(&stock).await?;
+            info!("新股掛牌，建立股票資料: {} {}", stock.code, stock.name);
+            created += 1;
+        }
+
+        Ok(created)
+    }
+
+    /// 依抽籤日期查詢公開申購，未指定開始日期時只列出今天以後抽籤的案件
+    pub async fn get_subscriptions(
+        &self,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Vec<PublicSubscriptionDto>> {
+        let start_date = if let Some(date_str) = start_date_str {
+            Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?
+        } else {
+            self.market_clock.today()
+        };
+
+        let end_date = if let Some(date_str) = end_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let subscriptions = self
+            .public_subscription_repository
+            .find_by_lottery_date_range(Some(start_date), end_date)
+            .await?;
+
+        // 市價取該股票最近一個交易日的收盤價，現金增資或已掛牌的股票才有價格
+        let mut dtos = Vec::with_capacity(subscriptions.len());
+        for subscription in subscriptions {
+            let market_price = match self.stock_repository.find_by_code(&subscription.stock_code).await? {
+                Some(stock) => self
+                    .stock_price_repository
+                    .find_latest_by_stock_id(&stock.id)
+                    .await?
+                    .map(|price| price.close),
+                None => None,
+            };
+            dtos.push(PublicSubscriptionDto::new(subscription, market_price.as_ref()));
+        }
+
+        Ok(dtos)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::application::dtos::UniverseChangeDto;
+use crate::domain::models::{ListedSecurity, ListingStatus, ListingSync, MaterialAnnouncement, Stock, StockNameChange, UniverseChange, UniverseChangeType};
+use crate::domain::repositories::{IsinListingRepository, StockRepository, UniverseChangeRepository};
+use crate::domain::services::MarketClock;
+use crate::domain::value_objects::Result;
+use anyhow::anyhow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use time::Date;
+use tracing::info;
+
+/// 股票上市生命週期服務，比對前後兩次 ISIN 清單找出新上市、下市、更名、產業別及市場別變更，並依重大訊息標記暫停交易
+pub struct StockListingService {
+    stock_repository: Arc<dyn StockRepository>,
+    isin_listing_repository: Arc<dyn IsinListingRepository>,
+    universe_change_repository: Arc<dyn UniverseChangeRepository>,
+    market_clock: Arc<MarketClock>,
+}
+
+impl StockListingService {
+    pub fn new(
+        stock_repository: Arc<dyn StockRepository>,
+        isin_listing_repository: Arc<dyn IsinListingRepository>,
+        universe_change_repository: Arc<dyn UniverseChangeRepository>,
+        market_clock: Arc<MarketClock>,
+    ) -> Self {
+        Self {
+            stock_repository,
+            isin_listing_repository,
+            universe_change_repository,
+            market_clock,
+        }
+    }
+
+    /// 與上一次的清單快照比對並更新股票狀態，保存並回傳偵測到的異動，最後以本次清單取代快照
+    ///
+    /// 所有寫入在同一交易中完成，失敗時快照不變，下次比對會以原本的偵測日期重新偵測
+    pub async fn sync_listing(&self, listings: &[ListedSecurity]) -> Result<Vec<UniverseChange>> {
+        let today = self.market_clock.today();
+        let previous = self.isin_listing_repository.find_all().await?;
+
+        // 沒有快照時只建立快照，避免把整份清單都視為新上市
+        if previous.is_empty() {
+            self.isin_listing_repository.replace_all(listings).await?;
+            info!("已建立 ISIN 上市櫃清單快照，共 {} 支股票", listings.len());
+            return Ok(Vec::new());
+        }
+
+        // 清單大幅縮水多半是爬取不完整，不可據此判定下市
+        if listings.len() * 2 < previous.len() {
+            return Err(anyhow!(
+                "ISIN 清單僅 {} 支股票，遠少於上次的 {} 支，略過本次比對",
+                listings.len(),
+                previous.len()
+            ));
+        }
+
+        let previous_by_code: HashMap<&str, &ListedSecurity> =
+            previous.iter().map(|listing| (listing.code.as_str(), listing)).collect();
+        let current_by_code: HashMap<&str, &ListedSecurity> =
+            listings.iter().map(|listing| (listing.code.as_str(), listing)).collect();
+
+        let mut sync = ListingSync::new(listings.to_vec(), today);
+        let mut changes = Vec::new();
+        let change = |listing: &ListedSecurity, change_type, previous_value: Option<String>, new_value: Option<String>| {
+            UniverseChange::new(listing.code.clone(), listing.name.clone(), change_type, previous_value, new_value, today)
+        };
+
+        for listing in listings {
+            let Some(previous) = previous_by_code.get(listing.code.as_str()) else {
+                self.record_listing(listing, &mut sync).await?;
+                changes.push(change(listing, UniverseChangeType::NewListing, None, listing.market.clone()));
+                continue;
+            };
+
+            if previous.name != listing.name {
+                if let Some(stock) = self.stock_repository.find_by_code(&listing.code).await? {
+                    let name_change = StockNameChange::new(stock.id, previous.name.clone(), listing.name.clone(), today);
+                    sync.name_changes.push(name_change);
+                }
+                info!("股票 {} 更名: {} -> {}", listing.code, previous.name, listing.name);
+                changes.push(change(listing, UniverseChangeType::NameChange, Some(previous.name.clone()), Some(listing.name.clone())));
+            }
+
+            // 舊快照沒有市場別及產業別時不視為異動
+            if previous.industry.is_some() && previous.industry != listing.industry {
+                info!("股票 {} 產業別變更: {:?} -> {:?}", listing.code, previous.industry, listing.industry);
+                changes.push(change(listing, UniverseChangeType::IndustryChange, previous.industry.clone(), listing.industry.clone()));
+            }
+            if previous.market.is_some() && previous.market != listing.market {
+                info!("股票 {} 市場別變更: {:?} -> {:?}", listing.code, previous.market, listing.market);
+                changes.push(change(listing, UniverseChangeType::MarketTransfer, previous.market.clone(), listing.market.clone()));
+            }
+        }
+
+        for previous in &previous {
+            if current_by_code.contains_key(previous.code.as_str()) {
+                continue;
+            }
+            if let Some(stock) = self.stock_repository.find_by_code(&previous.code).await? {
+                sync.status_changes.push((stock.id, ListingStatus::Delisted));
+            }
+            info!("股票 {} {} 已自上市櫃清單移除，標記為下市", previous.code, previous.name);
+            changes.push(change(previous, UniverseChangeType::Delisting, previous.market.clone(), None));
+        }
+
+        sync.changes = changes.clone();
+        self.isin_listing_repository.apply_sync(&sync).await?;
+        info!("ISIN 清單比對完成，共 {} 筆異動", changes.len());
+
+        Ok(changes)
+    }
+
+    /// 查詢指定日期 (含) 之後偵測到的上市櫃異動，未指定日期時回傳全部
+    pub async fn get_changes(&self, since_str: Option<String>) -> Result<Vec<UniverseChangeDto>> {
+        let since = if let Some(date_str) = since_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let changes = self.universe_change_repository.find_since(since).await?;
+        Ok(changes.into_iter().map(UniverseChangeDto::from).collect())
+    }
+
+    /// 新出現在清單上的股票，已存在者 (例如重新上市) 恢復為上市中，否則新增
+    async fn record_listing(&self, listing: &ListedSecurity, sync: &mut ListingSync) -> Result<()> {
+        match self.stock_repository.find_by_code(&listing.code).await? {
+            Some(stock) => {
+                if stock.status != ListingStatus::Active {
+                    sync.status_changes.push((stock.id, ListingStatus::Active));
+                }
+            }
+            None => {
+                let mut stock = Stock::new(listing.code.clone(), listing.name.clone());
+                stock.listed_date = listing.listed_date;
+                stock.status_changed_on = Some(listing.listed_date.unwrap_or(sync.changed_on));
+                sync.new_stocks.push(stock);
+            }
+        }
+
+        info!("新上市股票: {} {}", listing.code, listing.name);
+        Ok(())
+    }
+
+    /// 依重大訊息主旨標記暫停交易或恢復交易，已下市的股票不受影響
+    pub async fn apply_announcement(&self, announcement: &MaterialAnnouncement) -> Result<()> {
+        let Some(status) = Self::status_from_title(&announcement.title) else {
+            return Ok(());
+        };
+        let Some(stock) = self.stock_repository.find_by_code(&announcement.stock_code).await? else {
+            return Ok(());
+        };
+        if stock.status == status || stock.status == ListingStatus::Delisted {
+            return Ok(());
+        }
+
+        let changed_on = announcement.announced_at.to_offset(MarketClock::offset()).date();
+        self.stock_repository.update_status(&stock.id, status, changed_on).await?;
+        info!("股票 {} 依重大訊息「{}」標記為 {}", stock.code, announcement.title, status.as_str());
+
+        Ok(())
+    }
+
+    fn status_from_title(title: &str) -> Option<ListingStatus> {
+        if title.contains("恢復交易") || title.contains("恢復買賣") {
+            Some(ListingStatus::Active)
+        } else if title.contains("暫停交易") || title.contains("停止買賣") || title.contains("停止交易") {
+            Some(ListingStatus::Suspended)
+        } else {
+            None
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use crate::application::dtos::{CreateStockPriceDto, StockPriceDto};
-use crate::domain::models::StockPrice;
-use crate::domain::repositories::StockPriceRepository;
-use crate::domain::value_objects::Result;
+use crate::application::dtos::{BlockTradeDto, CreateStockPriceDto, CreateStockPriceOutcome, PriceLimitDto, StockPriceDto};
+use crate::domain::models::{BlockTrade, PriceSource, QuarantinedPrice, StockPrice};
+use crate::domain::repositories::{BlockTradeRepository, QuarantinedPriceRepository, StockPriceRepository, StockRepository};
+use crate::domain::value_objects::{LimitStatus, PriceLimit, Result};
+use std::collections::HashMap;
 use std::sync::Arc;
 use time::Date;
 use uuid::Uuid;
@@ -10,16 +11,27 @@ use std::str::FromStr;
 
 pub struct StockPriceService {
     stock_price_repository: Arc<dyn StockPriceRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    block_trade_repository: Arc<dyn BlockTradeRepository>,
+    quarantined_price_repository: Arc<dyn QuarantinedPriceRepository>,
 }
 
 impl StockPriceService {
-    pub fn new(stock_price_repository: Arc<dyn StockPriceRepository>) -> Self {
+    pub fn new(
+        stock_price_repository: Arc<dyn StockPriceRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        block_trade_repository: Arc<dyn BlockTradeRepository>,
+        quarantined_price_repository: Arc<dyn QuarantinedPriceRepository>,
+    ) -> Self {
         Self {
             stock_price_repository,
+            stock_repository,
+            block_trade_repository,
+            quarantined_price_repository,
         }
     }
 
-    pub async fn create_stock_price(&self, dto: CreateStockPriceDto) -> Result<StockPriceDto> {
+    pub async fn create_stock_price(&self, dto: CreateStockPriceDto) -> Result<CreateStockPriceOutcome> {
         let stock_id = Uuid::parse_str(&dto.stock_id)?;
         let date = Date::parse(&dto.date, &time::format_description::well_known::Iso8601::DATE)?;
 
@@ -64,9 +76,23 @@ impl StockPriceService {
         } else {
             stock_price
         };
+        
+        // 依前一個交易日收盤價標記漲跌停
+        let mut stock_price = stock_price;
+        stock_price.source = dto.source.as_deref().and_then(PriceSource::parse).unwrap_or_default();
+        
+        // 未通過驗證的日 K 移至隔離區，不寫入 stock_prices
+        let issues = stock_price.validation_issues();
+        if !issues.is_empty() {
+            self.quarantined_price_repository
+                .save(&QuarantinedPrice::new(&stock_price, issues.clone()))
+                .await?;
+            return Ok(CreateStockPriceOutcome::Quarantined { reasons: issues });
+        }
+        stock_price.limit_status = self.limit_status(&stock_price).await?;
 
         let _created_price = self.stock_price_repository.create(&stock_price).await?;
-        Ok(StockPriceDto::from(stock_price))
+        Ok(CreateStockPriceOutcome::Saved { price: Box::new(StockPriceDto::from(stock_price)) })
     }
 
     pub async fn get_stock_price_by_id(&self, id: &str) -> Result<Option<StockPriceDto>> {
@@ -112,4 +138,99 @@ impl StockPriceService {
 
         Ok(stock_price.map(StockPriceDto::from))
     }
+
+    /// 依股票代碼查詢每日價格，並附上當日的鉅額交易，日期範圍皆為選填
+    pub async fn get_stock_prices_by_code(
+        &self,
+        stock_code: &str,
+        start_date_str: Option<String>,
+        end_date_str: Option<String>,
+    ) -> Result<Option<Vec<StockPriceDto>>> {
+        let Some(stock) = self.stock_repository.find_by_code(stock_code).await? else {
+            return Ok(None);
+        };
+
+        let start_date = if let Some(date_str) = start_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let end_date = if let Some(date_str) = end_date_str {
+            Some(Date::parse(&date_str, &time::format_description::well_known::Iso8601::DATE)?)
+        } else {
+            None
+        };
+
+        let stock_prices = self
+            .stock_price_repository
+            .find_by_stock_id_and_date_range(&stock.id, start_date, end_date)
+            .await?;
+
+        // 鉅額交易依日期分組，溢價及折價以當日一般交易收盤價計算
+        let mut block_trades: HashMap<Date, Vec<BlockTrade>> = HashMap::new();
+        for trade in self
+            .block_trade_repository
+            .find_by_stock_code(&stock.code, start_date, end_date)
+            .await?
+        {
+            block_trades.entry(trade.date).or_default().push(trade);
+        }
+
+        let prices = stock_prices
+            .into_iter()
+            .map(|price| {
+                let trades = block_trades.remove(&price.date).unwrap_or_default();
+                let close = price.close.clone();
+                let mut dto = StockPriceDto::from(price);
+                dto.block_trades = trades
+                    .into_iter()
+                    .map(|trade| BlockTradeDto::new(trade, Some(&close)))
+                    .collect();
+                dto
+            })
+            .collect();
+
+        Ok(Some(prices))
+    }
+
+    /// 以最新收盤價計算下一個交易日的漲跌停價格
+    pub async fn get_price_limit_by_code(&self, stock_code: &str) -> Result<Option<PriceLimitDto>> {
+        let Some(stock) = self.stock_repository.find_by_code(stock_code).await? else {
+            return Ok(None);
+        };
+        let Some(latest_price) = self.stock_price_repository.find_latest_by_stock_id(&stock.id).await? else {
+            return Ok(None);
+        };
+
+        let limit = PriceLimit::from_previous_close(latest_price.close, stock.is_etf());
+        Ok(Some(PriceLimitDto::new(stock.code, latest_price.date, limit)))
+    }
+
+    /// 判斷收盤價是否漲停或跌停
+    ///
+    /// 前一個交易日的價格未入庫時，以收盤價減去漲跌推算前一日收盤價
+    async fn limit_status(&self, stock_price: &StockPrice) -> Result<Option<LimitStatus>> {
+        let previous_close = match self
+            .stock_price_repository
+            .find_previous_by_stock_id(&stock_price.stock_id, stock_price.date)
+            .await?
+        {
+            Some(previous) => previous.close,
+            None if stock_price.change != 0 => &stock_price.close - &stock_price.change,
+            None => return Ok(None),
+        };
+        if previous_close <= 0 {
+            return Ok(None);
+        }
+
+        let is_etf = self
+            .stock_repository
+            .find_by_id(&stock_price.stock_id)
+            .await?
+            .map(|stock| stock.is_etf())
+            .unwrap_or(false);
+
+        Ok(PriceLimit::from_previous_close(previous_close, is_etf).status(&stock_price.close))
+    }
 }
@@ -1,5 +1,6 @@
-use crate::application::dtos::{CreateStockDto, StockDto};
-use crate::domain::models::Stock;
+use crate::application::dtos::{CreateStockDto, StockDto, StockNameChangeDto};
+use crate::application::services::{ConvertibleBondService, ForeignOwnershipService, MarketSurveillanceService};
+use crate::domain::models::{Stock, SurveillanceType};
 use crate::domain::repositories::StockRepository;
 use crate::domain::value_objects::Result;
 use std::sync::Arc;
@@ -7,11 +8,51 @@ use uuid::Uuid;
 
 pub struct StockService {
     stock_repository: Arc<dyn StockRepository>,
+    market_surveillance_service: Arc<MarketSurveillanceService>,
+    foreign_ownership_service: Arc<ForeignOwnershipService>,
+    convertible_bond_service: Arc<ConvertibleBondService>,
 }
 
 impl StockService {
-    pub fn new(stock_repository: Arc<dyn StockRepository>) -> Self {
-        Self { stock_repository }
+    pub fn new(
+        stock_repository: Arc<dyn StockRepository>,
+        market_surveillance_service: Arc<MarketSurveillanceService>,
+        foreign_ownership_service: Arc<ForeignOwnershipService>,
+        convertible_bond_service: Arc<ConvertibleBondService>,
+    ) -> Self {
+        Self {
+            stock_repository,
+            market_surveillance_service,
+            foreign_ownership_service,
+            convertible_bond_service,
+        }
+    }
+
+    /// 標記注意股及處置股，只查詢這些股票的監視公告
+    async fn flag_stocks(&self, stocks: &mut [StockDto]) -> Result<()> {
+        if stocks.is_empty() {
+            return Ok(());
+        }
+        let codes: Vec<String> = stocks.iter().map(|s| s.code.clone()).collect();
+        let flags = self.market_surveillance_service.get_current_flags(Some(&codes)).await?;
+        for stock in stocks.iter_mut() {
+            if let Some(types) = flags.get(&stock.code) {
+                stock.is_attention = types.contains(&SurveillanceType::Attention);
+                stock.is_disposition = types.contains(&SurveillanceType::Disposition);
+            }
+        }
+        Ok(())
+    }
+
+    /// 填入最新的外資持股比率
+    async fn fill_foreign_ownership(&self, stocks: &mut [StockDto]) -> Result<()> {
+        let ratios = self.foreign_ownership_service.get_latest_ratios().await?;
+        for stock in stocks.iter_mut() {
+            stock.foreign_ownership_ratio = ratios
+                .get(&stock.code)
+                .and_then(|r| r.to_string().parse::<f64>().ok());
+        }
+        Ok(())
     }
 
     pub async fn create_stock(&self, dto: CreateStockDto) -> Result<StockDto> {
@@ -20,24 +61,74 @@ impl StockService {
         Ok(StockDto::from(stock))
     }
 
+    /// 單一股票只查詢該股票最新的外資持股比率
+    async fn fill_foreign_ownership_ratio(&self, stock: &mut StockDto) -> Result<()> {
+        stock.foreign_ownership_ratio = self
+            .foreign_ownership_service
+            .get_latest_ratio(&stock.code)
+            .await?
+            .and_then(|r| r.to_string().parse::<f64>().ok());
+        Ok(())
+    }
+
     pub async fn get_stock_by_id(&self, id: &str) -> Result<Option<StockDto>> {
         let uuid = Uuid::parse_str(id)?;
         let stock = self.stock_repository.find_by_id(&uuid).await?;
-        Ok(stock.map(StockDto::from))
+        let mut stocks: Vec<StockDto> = stock.map(StockDto::from).into_iter().collect();
+        self.flag_stocks(&mut stocks).await?;
+        for stock in stocks.iter_mut() {
+            self.fill_foreign_ownership_ratio(stock).await?;
+        }
+        Ok(stocks.pop())
     }
 
     pub async fn get_stock_by_code(&self, code: &str) -> Result<Option<StockDto>> {
         let stock = self.stock_repository.find_by_code(code).await?;
-        Ok(stock.map(StockDto::from))
+        let mut stocks: Vec<StockDto> = stock.map(StockDto::from).into_iter().collect();
+        self.flag_stocks(&mut stocks).await?;
+        for stock in stocks.iter_mut() {
+            self.fill_foreign_ownership_ratio(stock).await?;
+        }
+        Ok(stocks.pop())
+    }
+
+    /// 單一股票的詳細資料，另外填入尚流通的可轉債，只供 API 查詢使用
+    pub async fn get_stock_detail(&self, code: &str) -> Result<Option<StockDto>> {
+        let Some(mut stock) = self.get_stock_by_code(code).await? else {
+            return Ok(None);
+        };
+        stock.convertible_bonds = self.convertible_bond_service.get_outstanding_bonds(&stock.code).await?;
+        stock.name_history = self
+            .stock_repository
+            .find_name_history(&Uuid::parse_str(&stock.id)?)
+            .await?
+            .into_iter()
+            .map(StockNameChangeDto::from)
+            .collect();
+        Ok(Some(stock))
     }
 
     pub async fn get_all_stocks(&self) -> Result<Vec<StockDto>> {
         let stocks = self.stock_repository.find_all().await?;
-        Ok(stocks.into_iter().map(StockDto::from).collect())
+        let mut stocks: Vec<StockDto> = stocks.into_iter().map(StockDto::from).collect();
+        self.flag_stocks(&mut stocks).await?;
+        self.fill_foreign_ownership(&mut stocks).await?;
+        Ok(stocks)
+    }
+
+    /// 依代碼查詢股票本身，不附加注意股、外資持股等資訊，供爬蟲及排程任務使用
+    pub async fn find_stock_by_code(&self, code: &str) -> Result<Option<Stock>> {
+        self.stock_repository.find_by_code(code).await
+    }
+
+    /// 所有股票本身，不附加注意股、外資持股等資訊，供爬蟲及排程任務使用
+    pub async fn find_all_stocks(&self) -> Result<Vec<Stock>> {
+        self.stock_repository.find_all().await
     }
 
+    /// 標記為下市，保留歷史價格等資料
     pub async fn delete_stock(&self, id: &str) -> Result<()> {
         let uuid = Uuid::parse_str(id)?;
-        self.stock_repository.delete(&uuid).await
+        self.stock_repository.mark_delisted(&uuid).await
     }
 }
@@ -0,0 +1,56 @@
+use crate::application::dtos::TreasuryBuybackDto;
+use crate::domain::models::TreasuryBuyback;
+use crate::domain::repositories::TreasuryBuybackRepository;
+use crate::domain::value_objects::{Result, Watchlist};
+use std::sync::Arc;
+use time::Date;
+use tracing::error;
+
+/// 董事會決議日期在最近幾天內的新計畫才推播，避免首次部署或停機後把補抓到的舊計畫全部推播
+const NOTIFY_WITHIN_DAYS: i64 = 3;
+
+/// 庫藏股買回服務，保存買回計畫及執行情形並挑出自選股新公告的買回計畫
+pub struct TreasuryBuybackService {
+    treasury_buyback_repository: Arc<dyn TreasuryBuybackRepository>,
+    watchlist: Watchlist,
+}
+
+impl TreasuryBuybackService {
+    pub fn new(treasury_buyback_repository: Arc<dyn TreasuryBuybackRepository>, watchlist: Watchlist) -> Self {
+        Self {
+            treasury_buyback_repository,
+            watchlist,
+        }
+    }
+
+    /// 保存買回計畫，回傳需要推播的自選股新計畫，只包含最近決議的計畫
+    pub async fn ingest(&self, buybacks: &[TreasuryBuyback], today: Date) -> Result<Vec<TreasuryBuyback>> {
+        let notify_since = today - time::Duration::days(NOTIFY_WITHIN_DAYS);
+        let mut to_notify = Vec::new();
+
+        for buyback in buybacks {
+            // 單筆保存失敗時繼續處理其他計畫，避免已保存計畫的通知因提前返回而遺失
+            let is_new = match self.treasury_buyback_repository.save(buyback).await {
+                Ok(is_new) => is_new,
+                Err(e) => {
+                    error!("保存庫藏股買回計畫失敗: {} {}, 錯誤: {}", buyback.stock_code, buyback.resolution_date, e);
+                    continue;
+                }
+            };
+            if is_new && buyback.resolution_date >= notify_since && self.watchlist.codes.contains(&buyback.stock_code) {
+                to_notify.push(buyback.clone());
+            }
+        }
+
+        Ok(to_notify)
+    }
+
+    pub async fn get_buybacks(&self, stock_code: &str) -> Result<Vec<TreasuryBuybackDto>> {
+        let buybacks = self
+            .treasury_buyback_repository
+            .find_by_stock_code(stock_code)
+            .await?;
+
+        Ok(buybacks.into_iter().map(TreasuryBuybackDto::from).collect())
+    }
+}
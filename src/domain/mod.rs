@@ -1,3 +1,4 @@
 pub mod models;
 pub mod repositories;
+pub mod services;
 pub mod value_objects;
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 盤後定價交易每日成交資訊，以當日收盤價撮合，與 stock_prices 分開保存
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AfterHoursTrade {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub date: Date,
+    pub price: Option<BigDecimal>,    // 成交價，無成交時為 None
+    pub volume: u64,                  // 成交股數
+    pub transactions: u64,            // 成交筆數
+    pub turnover: u64,                // 成交金額
+    pub last_bid_volume: u64,         // 最後揭示買量
+    pub last_ask_volume: u64,         // 最後揭示賣量
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 鉅額交易類型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockTradeType {
+    Paired,     // 配對交易
+    NonPaired,  // 非配對交易 (逐筆或單一證券)
+}
+
+impl BlockTradeType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BlockTradeType::Paired => "paired",
+            BlockTradeType::NonPaired => "non_paired",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "paired" => Some(BlockTradeType::Paired),
+            "non_paired" => Some(BlockTradeType::NonPaired),
+            _ => None,
+        }
+    }
+
+    /// 依證交所報表的交易別判斷，「非配對」也含有「配對」兩字，需先判斷
+    pub fn from_label(label: &str) -> Self {
+        if label.contains("非配對") || !label.contains("配對") {
+            BlockTradeType::NonPaired
+        } else {
+            BlockTradeType::Paired
+        }
+    }
+}
+
+/// 鉅額交易成交紀錄
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockTrade {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub date: Date,
+    pub trade_type: BlockTradeType,
+    pub price: BigDecimal,            // 成交價
+    pub volume: u64,                  // 成交股數
+    pub turnover: u64,                // 成交金額
+}
+
+impl BlockTrade {
+    /// 相對一般交易收盤價的溢價 (正值) 或折價 (負值) 百分比
+    pub fn premium_percent(&self, regular_close: &BigDecimal) -> Option<BigDecimal> {
+        if *regular_close <= 0 {
+            return None;
+        }
+        Some(((&self.price - regular_close) * BigDecimal::from(100) / regular_close).round(2))
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 可轉換公司債發行資料 (櫃買中心)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertibleBond {
+    pub id: Uuid,
+    pub bond_code: String,               // 債券代號，例如 "23301"
+    pub bond_name: String,
+    pub underlying_code: String,         // 標的股票代碼
+    pub conversion_price: BigDecimal,    // 最新轉換價格
+    pub outstanding_amount: u64,         // 流通餘額 (元)
+    pub issue_date: Option<Date>,
+    pub maturity_date: Option<Date>,
+}
+
+impl ConvertibleBond {
+    /// 由債券代號推得標的股票代碼，可轉債代號為股票代碼加上一碼序號
+    pub fn underlying_code_of(bond_code: &str) -> Option<String> {
+        let code = bond_code.trim();
+        if code.len() < 5 || !code.is_ascii() {
+            return None;
+        }
+        Some(code[..code.len() - 1].to_string())
+    }
+
+    /// 轉換價值：以標的股價轉換成股票的價值，以面額 100 元計
+    pub fn conversion_value(&self, underlying_price: &BigDecimal) -> Option<BigDecimal> {
+        if self.conversion_price == 0 {
+            return None;
+        }
+        Some(underlying_price * BigDecimal::from(100) / &self.conversion_price)
+    }
+
+    /// 轉換溢價率 (%)：可轉債價格相對轉換價值的溢價
+    pub fn conversion_premium(&self, bond_price: &BigDecimal, underlying_price: &BigDecimal) -> Option<BigDecimal> {
+        let value = self.conversion_value(underlying_price)?;
+        if value == 0 {
+            return None;
+        }
+        Some(((bond_price - &value) * BigDecimal::from(100) / value).round(2))
+    }
+}
+
+/// 可轉換公司債每日行情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertibleBondQuote {
+    pub id: Uuid,
+    pub bond_code: String,
+    pub date: Date,
+    pub close: Option<BigDecimal>,                  // 收盤價，當日無成交時為 None
+    pub volume: u64,                                // 成交張數
+    pub underlying_close: Option<BigDecimal>,       // 標的股票當日收盤價
+    pub conversion_premium: Option<BigDecimal>,     // 轉換溢價率 (%)
+}
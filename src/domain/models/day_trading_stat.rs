@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+/// 個股當日沖銷交易統計 (證交所 TWTB4U)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayTradingStat {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub date: Date,
+    pub volume: u64,                    // 當日沖銷交易成交股數
+    pub buy_amount: u64,                // 當日沖銷交易買進成交金額
+    pub sell_amount: u64,               // 當日沖銷交易賣出成交金額
+    pub sell_first_suspended: bool,     // 暫停現股賣出後現款買進當沖
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// ETF 每日淨值 (證交所基本市況報導網站 ETF 預估淨值，收盤後即為當日收盤的預估淨值)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EtfNav {
+    pub id: Uuid,
+    pub etf_code: String,
+    pub etf_name: String,
+    pub date: Date,
+    pub nav: BigDecimal,                // 每單位淨值
+    pub close: Option<BigDecimal>,      // 收盤價，當日無成交時為 None
+    pub issued_units: Option<u64>,      // 已發行受益權單位數
+}
+
+impl EtfNav {
+    /// 收盤價相對淨值的溢價 (正值) 或折價 (負值) 百分比
+    pub fn premium_percent(&self) -> Option<BigDecimal> {
+        let close = self.close.as_ref()?;
+        if self.nav == 0 {
+            return None;
+        }
+        Some(((close - &self.nav) * BigDecimal::from(100) / &self.nav).round(2))
+    }
+}
+
+/// ETF 成分股及權重
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EtfConstituent {
+    pub id: Uuid,
+    pub etf_code: String,
+    pub date: Date,                     // 持股資料的爬取日期
+    pub stock_code: String,
+    pub stock_name: String,
+    pub weight: BigDecimal,             // 投資比例 (%)
+    pub shares: Option<u64>,            // 持有股數
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 個股外資及陸資持股統計 (證交所 MI_QFIIS)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForeignOwnership {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub date: Date,
+    pub issued_shares: u64,               // 發行股數
+    pub available_shares: u64,            // 外資及陸資尚可投資股數
+    pub foreign_held_shares: u64,         // 全體外資及陸資持有股數
+    pub foreign_held_ratio: BigDecimal,   // 全體外資及陸資持股比率 (%)
+    pub foreign_limit_ratio: BigDecimal,  // 外資及陸資共用法令投資上限比率 (%)
+}
+
+impl ForeignOwnership {
+    /// 尚可投資比率 (%)，即投資上限與目前持股比率的差距
+    pub fn available_ratio(&self) -> BigDecimal {
+        &self.foreign_limit_ratio - &self.foreign_held_ratio
+    }
+}
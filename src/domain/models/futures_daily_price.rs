@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 期貨契約每日行情 (一般交易時段)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuturesDailyPrice {
+    pub id: Uuid,
+    pub contract_code: String,               // 契約代碼，例如 TX、MTX
+    pub delivery_month: String,              // 到期月份，週契約帶週別，例如 202501、202501W2
+    pub date: Date,
+    pub open: Option<BigDecimal>,            // 無成交時為 None
+    pub high: Option<BigDecimal>,
+    pub low: Option<BigDecimal>,
+    pub close: Option<BigDecimal>,
+    pub settlement_price: Option<BigDecimal>, // 結算價
+    pub volume: u64,                         // 成交口數
+    pub open_interest: u64,                  // 未沖銷契約數
+}
+
+impl FuturesDailyPrice {
+    /// 是否為月契約 (非週契約)
+    pub fn is_monthly(&self) -> bool {
+        self.delivery_month.len() == 6 && self.delivery_month.chars().all(|c| c.is_ascii_digit())
+    }
+}
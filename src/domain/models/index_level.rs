@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 大盤及類股指數的每日點位
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexLevel {
+    pub id: Uuid,
+    pub index_code: String,           // 指數代碼，例如 TAIEX、TPEX、SEMICONDUCTOR
+    pub index_name: String,           // 指數中文名稱
+    pub date: Date,
+    pub open: Option<BigDecimal>,     // 部分指數只公布收盤點位
+    pub high: Option<BigDecimal>,
+    pub low: Option<BigDecimal>,
+    pub close: BigDecimal,
+    pub change: BigDecimal,           // 漲跌點數
+    pub change_percent: BigDecimal,   // 漲跌百分比
+}
+
+impl IndexLevel {
+    pub fn new(
+        index_code: String,
+        index_name: String,
+        date: Date,
+        close: BigDecimal,
+        change: BigDecimal,
+        change_percent: BigDecimal,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            index_code,
+            index_name,
+            date,
+            open: None,
+            high: None,
+            low: None,
+            close,
+            change,
+            change_percent,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 公司董事及監察人每月持股與設質彙總 (公開資訊觀測站 董監事持股餘額明細)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InsiderHolding {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub company_name: String,
+    pub period: Date,                 // 資料所屬月份，以該月第一天表示
+    pub insider_shares: u64,          // 董監事目前持股合計
+    pub pledged_shares: u64,          // 董監事設質股數合計
+    pub issued_shares: Option<u64>,   // 發行股數，取自最近一筆外資持股資料
+}
+
+impl InsiderHolding {
+    /// 董監持股比率 (%)，缺少發行股數時為 None
+    pub fn holding_ratio(&self) -> Option<BigDecimal> {
+        let issued_shares = self.issued_shares.filter(|shares| *shares > 0)?;
+        Some((BigDecimal::from(self.insider_shares) * BigDecimal::from(100) / BigDecimal::from(issued_shares)).round(2))
+    }
+
+    /// 設質比率 (%)，即設質股數佔董監持股的比例，董監未持股時為 None
+    pub fn pledge_ratio(&self) -> Option<BigDecimal> {
+        if self.insider_shares == 0 {
+            return None;
+        }
+        Some((BigDecimal::from(self.pledged_shares) * BigDecimal::from(100) / BigDecimal::from(self.insider_shares)).round(2))
+    }
+}
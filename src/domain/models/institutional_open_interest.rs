@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+/// 三大法人身份別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InstitutionType {
+    Dealer,             // 自營商
+    InvestmentTrust,    // 投信
+    ForeignInvestor,    // 外資及陸資
+}
+
+impl InstitutionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstitutionType::Dealer => "dealer",
+            InstitutionType::InvestmentTrust => "investment_trust",
+            InstitutionType::ForeignInvestor => "foreign_investor",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "dealer" | "自營商" => Some(InstitutionType::Dealer),
+            "investment_trust" | "投信" => Some(InstitutionType::InvestmentTrust),
+            "foreign_investor" | "外資" | "外資及陸資" => Some(InstitutionType::ForeignInvestor),
+            _ => None,
+        }
+    }
+}
+
+/// 期貨三大法人未平倉部位
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstitutionalOpenInterest {
+    pub id: Uuid,
+    pub contract_code: String,
+    pub date: Date,
+    pub institution_type: InstitutionType,
+    pub long_open_interest: i64,      // 多方未平倉口數
+    pub short_open_interest: i64,     // 空方未平倉口數
+    pub net_open_interest: i64,       // 多空未平倉口數淨額
+    pub long_value: i64,              // 多方未平倉契約金額 (千元)
+    pub short_value: i64,             // 空方未平倉契約金額 (千元)
+    pub net_value: i64,               // 多空未平倉契約金額淨額 (千元)
+}
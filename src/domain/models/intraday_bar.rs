@@ -0,0 +1,48 @@
+use crate::domain::value_objects::BarInterval;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 盤中分鐘 K 棒
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntradayBar {
+    pub id: Uuid,
+    pub stock_id: Uuid,
+    pub interval: BarInterval,
+    pub start_time: OffsetDateTime,  // K 棒起始時間
+    pub open: BigDecimal,
+    pub high: BigDecimal,
+    pub low: BigDecimal,
+    pub close: BigDecimal,
+    pub volume: u64,                 // 區間成交股數
+}
+
+impl IntradayBar {
+    /// 以第一筆成交開始一根新的 K 棒
+    pub fn new(stock_id: Uuid, interval: BarInterval, start_time: OffsetDateTime, price: BigDecimal, volume: u64) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            stock_id,
+            interval,
+            start_time,
+            open: price.clone(),
+            high: price.clone(),
+            low: price.clone(),
+            close: price,
+            volume,
+        }
+    }
+
+    /// 以新的成交更新 K 棒
+    pub fn update(&mut self, price: &BigDecimal, volume: u64) {
+        if *price > self.high {
+            self.high = price.clone();
+        }
+        if *price < self.low {
+            self.low = price.clone();
+        }
+        self.close = price.clone();
+        self.volume += volume;
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 五檔委買委賣中的一檔
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookLevel {
+    pub price: BigDecimal,
+    pub volume: u64,    // 委託股數
+}
+
+/// 盤中即時報價快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntradayQuote {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub quote_time: OffsetDateTime,
+    pub last_price: Option<BigDecimal>,  // 最近成交價，該快照無成交時為 None
+    pub accumulated_volume: u64,         // 累積成交股數
+    pub open: Option<BigDecimal>,
+    pub high: Option<BigDecimal>,
+    pub low: Option<BigDecimal>,
+    pub prev_close: Option<BigDecimal>,  // 昨收價
+    pub bids: Vec<OrderBookLevel>,       // 最佳五檔委買
+    pub asks: Vec<OrderBookLevel>,       // 最佳五檔委賣
+}
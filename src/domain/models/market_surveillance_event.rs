@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+/// 市場監視公告類型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SurveillanceType {
+    Attention,      // 注意股
+    Disposition,    // 處置股，改以分盤集合競價撮合
+}
+
+impl SurveillanceType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SurveillanceType::Attention => "attention",
+            SurveillanceType::Disposition => "disposition",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "attention" => Some(SurveillanceType::Attention),
+            "disposition" => Some(SurveillanceType::Disposition),
+            _ => None,
+        }
+    }
+
+    /// 通知訊息中顯示的標記
+    pub fn label(&self) -> &'static str {
+        match self {
+            SurveillanceType::Attention => "注意股",
+            SurveillanceType::Disposition => "處置股",
+        }
+    }
+}
+
+/// 證交所及櫃買中心公布的注意股、處置股公告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketSurveillanceEvent {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub market: String,                 // TWSE 或 TPEX
+    pub event_type: SurveillanceType,
+    pub announced_date: Date,           // 公告日期
+    pub start_date: Date,               // 注意股為公告當日，處置股為處置起日
+    pub end_date: Date,                 // 注意股為公告當日，處置股為處置迄日
+    pub reason: String,                 // 注意交易資訊或處置條件
+    pub measure: Option<String>,        // 處置措施，例如「第一次處置」
+}
+
+impl MarketSurveillanceEvent {
+    /// 指定日期是否在公告期間內
+    pub fn is_active_on(&self, date: Date) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+}
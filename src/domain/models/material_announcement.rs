@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// 公開資訊觀測站的重大訊息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaterialAnnouncement {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub company_name: String,
+    pub announced_at: OffsetDateTime,   // 發言日期及時間
+    pub title: String,                  // 主旨
+    pub body: String,                   // 說明
+}
@@ -1,5 +1,51 @@
 mod stock;
 mod stock_price;
+mod intraday_quote;
+mod intraday_bar;
+mod index_level;
+mod futures_daily_price;
+mod options_daily_price;
+mod institutional_open_interest;
+mod market_surveillance_event;
+mod material_announcement;
+mod foreign_ownership;
+mod day_trading_stat;
+mod odd_lot_quote;
+mod after_hours_trade;
+mod block_trade;
+mod insider_holding;
+mod treasury_buyback;
+mod public_subscription;
+mod etf;
+mod convertible_bond;
+mod shares_outstanding;
+mod price_discrepancy;
+mod quarantined_price;
+mod price_gap;
+mod universe_change;
 
-pub use stock::Stock;
-pub use stock_price::StockPrice;
+pub use stock::{ListedSecurity, ListingStatus, Stock, StockNameChange};
+pub use stock_price::{PriceSource, StockPrice};
+pub use intraday_quote::{IntradayQuote, OrderBookLevel};
+pub use intraday_bar::IntradayBar;
+pub use index_level::IndexLevel;
+pub use futures_daily_price::FuturesDailyPrice;
+pub use options_daily_price::{OptionType, OptionsDailyPrice};
+pub use institutional_open_interest::{InstitutionType, InstitutionalOpenInterest};
+pub use market_surveillance_event::{MarketSurveillanceEvent, SurveillanceType};
+pub use material_announcement::MaterialAnnouncement;
+pub use foreign_ownership::ForeignOwnership;
+pub use day_trading_stat::DayTradingStat;
+pub use odd_lot_quote::OddLotQuote;
+pub use after_hours_trade::AfterHoursTrade;
+pub use block_trade::{BlockTrade, BlockTradeType};
+pub use insider_holding::InsiderHolding;
+pub use treasury_buyback::TreasuryBuyback;
+pub use public_subscription::PublicSubscription;
+pub use etf::{EtfConstituent, EtfNav};
+pub use convertible_bond::{ConvertibleBond, ConvertibleBondQuote};
+pub use shares_outstanding::SharesOutstanding;
+pub use price_discrepancy::PriceDiscrepancy;
+pub use quarantined_price::QuarantinedPrice;
+pub use price_gap::{PriceGap, PriceGapStatus};
+pub use universe_change::{ListingSync, UniverseChange, UniverseChangeType};
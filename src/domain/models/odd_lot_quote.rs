@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 盤中零股每日行情，與整股的 stock_prices 分開保存
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OddLotQuote {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub date: Date,
+    pub open: Option<BigDecimal>,     // 無成交時為 None
+    pub high: Option<BigDecimal>,
+    pub low: Option<BigDecimal>,
+    pub close: Option<BigDecimal>,
+    pub volume: u64,                  // 成交股數
+    pub transactions: u64,            // 成交筆數
+    pub turnover: u64,                // 成交金額
+    pub last_bid_price: Option<BigDecimal>,   // 最後揭示買價
+    pub last_ask_price: Option<BigDecimal>,   // 最後揭示賣價
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 選擇權買賣權別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+impl OptionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OptionType::Call => "call",
+            OptionType::Put => "put",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "call" | "買權" => Some(OptionType::Call),
+            "put" | "賣權" => Some(OptionType::Put),
+            _ => None,
+        }
+    }
+}
+
+/// 選擇權序列每日行情 (一般交易時段)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptionsDailyPrice {
+    pub id: Uuid,
+    pub contract_code: String,               // 契約代碼，例如 TXO
+    pub delivery_month: String,
+    pub strike_price: BigDecimal,            // 履約價
+    pub option_type: OptionType,
+    pub date: Date,
+    pub open: Option<BigDecimal>,
+    pub high: Option<BigDecimal>,
+    pub low: Option<BigDecimal>,
+    pub close: Option<BigDecimal>,
+    pub settlement_price: Option<BigDecimal>,
+    pub volume: u64,
+    pub open_interest: u64,
+}
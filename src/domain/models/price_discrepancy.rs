@@ -0,0 +1,126 @@
+use crate::domain::models::{PriceSource, StockPrice};
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 同一股票同一日期，不同資料來源的 OHLCV 欄位差異
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceDiscrepancy {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub date: Date,
+    pub field: String,                 // open / high / low / close / volume
+    pub primary_source: PriceSource,   // 資料庫中保存的來源
+    pub primary_value: BigDecimal,
+    pub secondary_source: PriceSource, // 用來比對的來源
+    pub secondary_value: BigDecimal,
+    pub diff_percent: BigDecimal,      // 相對保存值的差異百分比 (%)
+}
+
+impl PriceDiscrepancy {
+    /// 逐欄比較兩個來源的日 K，回傳差異超過容許百分比的欄位
+    ///
+    /// 各來源對盤後交易是否計入成交量的口徑不同，成交量使用較寬的容許百分比
+    pub fn compare(
+        stock_code: &str,
+        primary: &StockPrice,
+        secondary: &StockPrice,
+        price_tolerance_percent: &BigDecimal,
+        volume_tolerance_percent: &BigDecimal,
+    ) -> Vec<PriceDiscrepancy> {
+        let fields = [
+            ("open", primary.open.clone(), secondary.open.clone(), price_tolerance_percent),
+            ("high", primary.high.clone(), secondary.high.clone(), price_tolerance_percent),
+            ("low", primary.low.clone(), secondary.low.clone(), price_tolerance_percent),
+            ("close", primary.close.clone(), secondary.close.clone(), price_tolerance_percent),
+            ("volume", BigDecimal::from(primary.volume), BigDecimal::from(secondary.volume), volume_tolerance_percent),
+        ];
+
+        fields
+            .into_iter()
+            .filter_map(|(field, primary_value, secondary_value, tolerance_percent)| {
+                let diff_percent = Self::diff_percent(&primary_value, &secondary_value);
+                if &diff_percent <= tolerance_percent {
+                    return None;
+                }
+                Some(PriceDiscrepancy {
+                    id: Uuid::new_v4(),
+                    stock_code: stock_code.to_string(),
+                    date: primary.date,
+                    field: field.to_string(),
+                    primary_source: primary.source,
+                    primary_value,
+                    secondary_source: secondary.source,
+                    secondary_value,
+                    diff_percent,
+                })
+            })
+            .collect()
+    }
+
+    /// 保存值為 0 時，只要比對值不為 0 即視為 100% 差異
+    fn diff_percent(primary_value: &BigDecimal, secondary_value: &BigDecimal) -> BigDecimal {
+        let zero = BigDecimal::from(0);
+        if primary_value == &zero {
+            return if secondary_value == &zero { zero } else { BigDecimal::from(100) };
+        }
+        ((primary_value - secondary_value).abs() * BigDecimal::from(100) / primary_value.abs()).round(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn price(close: &str, volume: u64, source: PriceSource) -> StockPrice {
+        let close = BigDecimal::from_str(close).unwrap();
+        let mut price = StockPrice::new(
+            Uuid::nil(),
+            Date::from_calendar_date(2026, time::Month::October, 16).unwrap(),
+            close.clone(),
+            close.clone(),
+            close.clone(),
+            close,
+            volume,
+            0,
+            0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        price.source = source;
+        price
+    }
+
+    fn compare(primary: &StockPrice, secondary: &StockPrice) -> Vec<String> {
+        PriceDiscrepancy::compare("2330", primary, secondary, &BigDecimal::from_str("0.5").unwrap(), &BigDecimal::from(5))
+            .into_iter()
+            .map(|d| d.field)
+            .collect()
+    }
+
+    #[test]
+    fn volume_uses_its_own_tolerance() {
+        let primary = price("100", 10_000, PriceSource::Twse);
+        // 成交量差 3% 在成交量容許範圍內
+        assert!(compare(&primary, &price("100", 10_300, PriceSource::Yahoo)).is_empty());
+        // 成交量差 6% 超過成交量容許範圍
+        assert_eq!(compare(&primary, &price("100", 10_600, PriceSource::Yahoo)), vec!["volume"]);
+    }
+
+    #[test]
+    fn prices_use_the_price_tolerance() {
+        let primary = price("100", 10_000, PriceSource::Twse);
+        assert!(compare(&primary, &price("100.5", 10_000, PriceSource::Yahoo)).is_empty());
+        assert_eq!(
+            compare(&primary, &price("101", 10_000, PriceSource::Yahoo)),
+            vec!["open", "high", "low", "close"]
+        );
+    }
+}
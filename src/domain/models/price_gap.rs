@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+/// 缺漏日 K 的處理狀態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceGapStatus {
+    Missing,   // 尚未補抓
+    Repaired,  // 已補齊
+    Failed,    // 補抓失敗，下次排程再試
+    NoTrade,   // 來源有當月資料但當日無成交，不再視為缺漏
+    Quarantined, // 補抓的日 K 未通過驗證，已移至隔離區
+}
+
+impl PriceGapStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceGapStatus::Missing => "missing",
+            PriceGapStatus::Repaired => "repaired",
+            PriceGapStatus::Failed => "failed",
+            PriceGapStatus::NoTrade => "no_trade",
+            PriceGapStatus::Quarantined => "quarantined",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "missing" => Some(PriceGapStatus::Missing),
+            "repaired" => Some(PriceGapStatus::Repaired),
+            "failed" => Some(PriceGapStatus::Failed),
+            "no_trade" => Some(PriceGapStatus::NoTrade),
+            "quarantined" => Some(PriceGapStatus::Quarantined),
+            _ => None,
+        }
+    }
+}
+
+/// 交易日曆上應有、但 stock_prices 中沒有的個股日 K
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceGap {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub date: Date,
+    pub status: PriceGapStatus,
+    pub attempts: u32,   // 已補抓次數
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 新股上市櫃及現金增資的公開申購抽籤公告 (證交所 公開申購公告)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicSubscription {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub market: String,                   // 發行市場，例如 "初上市"、"上市增資"
+    pub subscription_start: Date,         // 申購開始日
+    pub subscription_end: Date,           // 申購結束日
+    pub lottery_date: Date,               // 抽籤日期
+    pub listing_date: Option<Date>,       // 撥券日期 (上市、上櫃日期)
+    pub offer_price: BigDecimal,          // 承銷價，已公告實際承銷價時以實際承銷價為準
+    pub shares_offered: u64,              // 承銷股數
+    pub shares_per_application: u64,      // 每一申購單位股數
+    pub win_rate: Option<BigDecimal>,     // 中籤率 (%)，抽籤後才公告
+    pub is_cancelled: bool,               // 取消公開抽籤
+}
+
+impl PublicSubscription {
+    /// 市價相對承銷價的溢價百分比
+    pub fn premium_percent(&self, market_price: &BigDecimal) -> Option<BigDecimal> {
+        if self.offer_price == 0 {
+            return None;
+        }
+        Some(((market_price - &self.offer_price) * BigDecimal::from(100) / &self.offer_price).round(2))
+    }
+
+    /// 中籤一個申購單位以市價賣出的預估價差 (元)
+    pub fn expected_profit(&self, market_price: &BigDecimal) -> BigDecimal {
+        ((market_price - &self.offer_price) * BigDecimal::from(self.shares_per_application)).round(0)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::domain::models::{PriceSource, StockPrice};
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 未通過驗證的日 K，保存原始數值及原因以便人工檢查，不寫入 stock_prices
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarantinedPrice {
+    pub id: Uuid,
+    pub stock_id: Uuid,
+    pub date: Date,
+    pub source: PriceSource,
+    pub open: BigDecimal,
+    pub high: BigDecimal,
+    pub low: BigDecimal,
+    pub close: BigDecimal,
+    pub volume: u64,
+    pub turnover: u64,
+    pub reasons: Vec<String>,
+}
+
+impl QuarantinedPrice {
+    pub fn new(price: &StockPrice, reasons: Vec<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            stock_id: price.stock_id,
+            date: price.date,
+            source: price.source,
+            open: price.open.clone(),
+            high: price.high.clone(),
+            low: price.low.clone(),
+            close: price.close.clone(),
+            volume: price.volume,
+            turnover: price.turnover,
+            reasons,
+        }
+    }
+}
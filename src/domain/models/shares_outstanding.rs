@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+/// 已發行股數，自 effective_date 起生效直到下一筆異動
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharesOutstanding {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub effective_date: Date,
+    pub issued_shares: u64,
+}
+
+impl SharesOutstanding {
+    pub fn new(stock_code: String, effective_date: Date, issued_shares: u64) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            stock_code,
+            effective_date,
+            issued_shares,
+        }
+    }
+}
@@ -1,13 +1,44 @@
 use serde::{Deserialize, Serialize};
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 use uuid::Uuid;
 
+/// 股票上市狀態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ListingStatus {
+    #[default]
+    Active,     // 上市櫃交易中
+    Suspended,  // 暫停交易
+    Delisted,   // 已下市櫃
+}
+
+impl ListingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListingStatus::Active => "active",
+            ListingStatus::Suspended => "suspended",
+            ListingStatus::Delisted => "delisted",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(ListingStatus::Active),
+            "suspended" => Some(ListingStatus::Suspended),
+            "delisted" => Some(ListingStatus::Delisted),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Stock {
     pub id: Uuid,
     pub code: String,
     pub name: String,
     pub last_updated: OffsetDateTime,
+    pub status: ListingStatus,
+    pub listed_date: Option<Date>,         // 上市櫃日期
+    pub status_changed_on: Option<Date>,   // 最近一次上市狀態異動日期
 }
 
 impl Stock {
@@ -17,6 +48,51 @@ impl Stock {
             code,
             name,
             last_updated: OffsetDateTime::now_utc(),
+            status: ListingStatus::Active,
+            listed_date: None,
+            status_changed_on: None,
         }
     }
+
+    /// 是否為 ETF (證券代號以 00 開頭)，ETF 適用不同的升降單位
+    pub fn is_etf(&self) -> bool {
+        self.code.starts_with("00")
+    }
+
+    /// 是否仍在市場上交易 (未暫停交易且未下市)
+    pub fn is_active(&self) -> bool {
+        self.status == ListingStatus::Active
+    }
+}
+
+/// 股票更名紀錄
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockNameChange {
+    pub id: Uuid,
+    pub stock_id: Uuid,
+    pub previous_name: String,
+    pub new_name: String,
+    pub changed_on: Date,
+}
+
+impl StockNameChange {
+    pub fn new(stock_id: Uuid, previous_name: String, new_name: String, changed_on: Date) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            stock_id,
+            previous_name,
+            new_name,
+            changed_on,
+        }
+    }
+}
+
+/// ISIN 上市櫃清單中的一筆證券
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListedSecurity {
+    pub code: String,
+    pub name: String,
+    pub listed_date: Option<Date>,
+    pub market: Option<String>,     // 市場別: 上市 / 上櫃
+    pub industry: Option<String>,   // 產業別
 }
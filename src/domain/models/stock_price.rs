@@ -2,6 +2,33 @@ use serde::{Deserialize, Serialize};
 use time::Date;
 use uuid::Uuid;
 use bigdecimal::BigDecimal;
+use crate::domain::value_objects::LimitStatus;
+use std::str::FromStr;
+
+/// 股價資料來源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PriceSource {
+    #[default]
+    Twse,   // 證交所
+    Yahoo,  // Yahoo Finance，證交所無法取得時的備援
+}
+
+impl PriceSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceSource::Twse => "twse",
+            PriceSource::Yahoo => "yahoo",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "twse" => Some(PriceSource::Twse),
+            "yahoo" => Some(PriceSource::Yahoo),
+            _ => None,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockPrice {
@@ -24,6 +51,11 @@ pub struct StockPrice {
     pub foreign_buy: Option<i64>,    // 外資買賣超
     pub trust_buy: Option<i64>,      // 投信買賣超
     pub dealer_buy: Option<i64>,     // 自營商買賣超
+    pub limit_status: Option<LimitStatus>, // 漲跌停狀態
+    pub day_trade_volume: Option<u64>, // 當日沖銷交易成交股數
+    pub issued_shares: Option<u64>,  // 當日已發行股數
+    pub insider_shares: Option<u64>, // 董監持股股數
+    pub source: PriceSource,         // 資料來源
 }
 
 impl Default for StockPrice {
@@ -48,6 +80,11 @@ impl Default for StockPrice {
             foreign_buy: None,
             trust_buy: None,
             dealer_buy: None,
+            limit_status: None,
+            day_trade_volume: None,
+            issued_shares: None,
+            insider_shares: None,
+            source: PriceSource::Twse,
         }
     }
 }
@@ -91,6 +128,11 @@ impl StockPrice {
             foreign_buy,
             trust_buy,
             dealer_buy,
+            limit_status: None,
+            day_trade_volume: None,
+            issued_shares: None,
+            insider_shares: None,
+            source: PriceSource::Twse,
         }
     }
 
@@ -134,7 +176,78 @@ impl StockPrice {
             foreign_buy,
             trust_buy,
             dealer_buy,
+            limit_status: None,
+            day_trade_volume: None,
+            issued_shares: None,
+            insider_shares: None,
+            source: PriceSource::Twse,
+        }
+    }
+
+    /// 當沖成交股數佔總成交股數的比率 (%)
+    pub fn day_trade_ratio(&self) -> Option<BigDecimal> {
+        let day_trade_volume = self.day_trade_volume?;
+        if self.volume == 0 {
+            return None;
+        }
+        Some((BigDecimal::from(day_trade_volume) * BigDecimal::from(100) / BigDecimal::from(self.volume)).round(2))
+    }
+
+    /// 流通股數：已發行股數扣除董監持股
+    pub fn float_shares(&self) -> Option<u64> {
+        let issued_shares = self.issued_shares?;
+        Some(issued_shares.saturating_sub(self.insider_shares.unwrap_or(0)))
+    }
+
+    /// 週轉率：成交股數佔已發行股數的比率 (%)
+    pub fn turnover_ratio(&self) -> Option<BigDecimal> {
+        let issued_shares = self.issued_shares?;
+        if issued_shares == 0 {
+            return None;
+        }
+        Some((BigDecimal::from(self.volume) * BigDecimal::from(100) / BigDecimal::from(issued_shares)).round(2))
+    }
+
+    /// 以收盤價乘以已發行股數計算的市值
+    pub fn derived_market_cap(&self) -> Option<u64> {
+        let issued_shares = self.issued_shares?;
+        (self.close.clone() * BigDecimal::from(issued_shares)).round(0).to_string().parse::<u64>().ok()
+    }
+
+    /// 檢查日 K 是否合理，回傳不合理的原因，空陣列表示通過驗證
+    ///
+    /// 無成交 (暫停交易) 的日期沒有價格，不應產生日 K
+    pub fn validation_issues(&self) -> Vec<String> {
+        let zero = BigDecimal::from(0);
+        let mut issues = Vec::new();
+
+        if [&self.open, &self.high, &self.low, &self.close].iter().any(|price| **price <= zero) {
+            issues.push("開高低收價格為 0 或負值".to_string());
+            return issues;
+        }
+        if self.high < self.open || self.high < self.close {
+            issues.push("最高價低於開盤價或收盤價".to_string());
+        }
+        if self.low > self.open || self.low > self.close {
+            issues.push("最低價高於開盤價或收盤價".to_string());
         }
+
+        // 成交金額除以成交股數的均價應落在最高價與最低價之間，未提供成交金額的來源不檢查
+        if self.turnover > 0 {
+            if self.volume == 0 {
+                issues.push("有成交金額但成交股數為 0".to_string());
+            } else {
+                let average_price = BigDecimal::from(self.turnover) / BigDecimal::from(self.volume);
+                let tolerance = BigDecimal::from_str("0.01").unwrap();
+                let upper = &self.high * (BigDecimal::from(1) + &tolerance);
+                let lower = &self.low * (BigDecimal::from(1) - &tolerance);
+                if average_price > upper || average_price < lower {
+                    issues.push("成交金額與成交股數不一致".to_string());
+                }
+            }
+        }
+
+        issues
     }
 
     pub fn calculate_change(&mut self, prev_close: BigDecimal) {
@@ -144,3 +257,78 @@ impl StockPrice {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(open: &str, high: &str, low: &str, close: &str, volume: u64, turnover: u64) -> StockPrice {
+        StockPrice::new(
+            Uuid::nil(),
+            Date::from_calendar_date(2026, time::Month::October, 16).unwrap(),
+            BigDecimal::from_str(open).unwrap(),
+            BigDecimal::from_str(high).unwrap(),
+            BigDecimal::from_str(low).unwrap(),
+            BigDecimal::from_str(close).unwrap(),
+            volume,
+            turnover,
+            0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn valid_bar_has_no_issues() {
+        assert!(price("100", "105", "98", "102", 1_000, 101_500).validation_issues().is_empty());
+    }
+
+    #[test]
+    fn zero_price_is_rejected_without_further_checks() {
+        let issues = price("0", "0", "0", "0", 0, 0).validation_issues();
+        assert_eq!(issues, vec!["開高低收價格為 0 或負值".to_string()]);
+    }
+
+    #[test]
+    fn high_below_open_or_close_is_rejected() {
+        let issues = price("100", "99", "95", "98", 1_000, 0).validation_issues();
+        assert_eq!(issues, vec!["最高價低於開盤價或收盤價".to_string()]);
+    }
+
+    #[test]
+    fn low_above_open_or_close_is_rejected() {
+        let issues = price("100", "105", "101", "102", 1_000, 0).validation_issues();
+        assert_eq!(issues, vec!["最低價高於開盤價或收盤價".to_string()]);
+    }
+
+    #[test]
+    fn average_price_outside_high_low_band_is_rejected() {
+        // 均價 120 高於最高價 105 的 1% 容許範圍
+        let above = price("100", "105", "98", "102", 1_000, 120_000).validation_issues();
+        assert_eq!(above, vec!["成交金額與成交股數不一致".to_string()]);
+
+        // 均價 90 低於最低價 98 的 1% 容許範圍
+        let below = price("100", "105", "98", "102", 1_000, 90_000).validation_issues();
+        assert_eq!(below, vec!["成交金額與成交股數不一致".to_string()]);
+
+        // 均價 105.9 仍在最高價 105 的 1% 容許範圍內
+        assert!(price("100", "105", "98", "102", 1_000, 105_900).validation_issues().is_empty());
+    }
+
+    #[test]
+    fn zero_turnover_skips_the_average_price_check() {
+        assert!(price("100", "105", "98", "102", 1_000, 0).validation_issues().is_empty());
+        assert!(price("100", "105", "98", "102", 0, 0).validation_issues().is_empty());
+    }
+
+    #[test]
+    fn turnover_without_volume_is_rejected() {
+        let issues = price("100", "105", "98", "102", 0, 100_000).validation_issues();
+        assert_eq!(issues, vec!["有成交金額但成交股數為 0".to_string()]);
+    }
+}
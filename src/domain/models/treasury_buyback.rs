@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+
+/// 上市櫃公司庫藏股買回計畫及執行情形 (公開資訊觀測站 庫藏股買回彙總)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreasuryBuyback {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub company_name: String,
+    pub resolution_date: Date,      // 董事會決議日期
+    pub purpose: String,            // 買回目的
+    pub planned_shares: u64,        // 預定買回股數
+    pub price_low: BigDecimal,      // 買回價格區間下限
+    pub price_high: BigDecimal,     // 買回價格區間上限
+    pub period_start: Date,         // 預定買回期間起日
+    pub period_end: Date,           // 預定買回期間迄日
+    pub executed_shares: u64,       // 已買回股數
+    pub executed_amount: u64,       // 已買回總金額
+    pub is_completed: bool,         // 是否執行完畢
+}
+
+impl TreasuryBuyback {
+    /// 已買回股數佔預定買回股數的比例 (%)
+    pub fn execution_ratio(&self) -> Option<BigDecimal> {
+        if self.planned_shares == 0 {
+            return None;
+        }
+        Some((BigDecimal::from(self.executed_shares) * BigDecimal::from(100) / BigDecimal::from(self.planned_shares)).round(2))
+    }
+}
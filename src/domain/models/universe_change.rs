@@ -0,0 +1,94 @@
+use crate::domain::models::{ListedSecurity, ListingStatus, Stock, StockNameChange};
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+/// 上市櫃股票異動類型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UniverseChangeType {
+    NewListing,       // 新上市櫃
+    Delisting,        // 下市櫃
+    NameChange,       // 更名
+    IndustryChange,   // 產業別變更
+    MarketTransfer,   // 市場別變更 (例如上櫃轉上市)
+}
+
+impl UniverseChangeType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UniverseChangeType::NewListing => "new_listing",
+            UniverseChangeType::Delisting => "delisting",
+            UniverseChangeType::NameChange => "name_change",
+            UniverseChangeType::IndustryChange => "industry_change",
+            UniverseChangeType::MarketTransfer => "market_transfer",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "new_listing" => Some(UniverseChangeType::NewListing),
+            "delisting" => Some(UniverseChangeType::Delisting),
+            "name_change" => Some(UniverseChangeType::NameChange),
+            "industry_change" => Some(UniverseChangeType::IndustryChange),
+            "market_transfer" => Some(UniverseChangeType::MarketTransfer),
+            _ => None,
+        }
+    }
+}
+
+/// 比對前後兩次 ISIN 清單所偵測到的異動
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UniverseChange {
+    pub id: Uuid,
+    pub stock_code: String,
+    pub stock_name: String,
+    pub change_type: UniverseChangeType,
+    pub previous_value: Option<String>,   // 異動前的名稱、產業別或市場別
+    pub new_value: Option<String>,        // 異動後的名稱、產業別或市場別
+    pub detected_on: Date,
+}
+
+impl UniverseChange {
+    pub fn new(
+        stock_code: String,
+        stock_name: String,
+        change_type: UniverseChangeType,
+        previous_value: Option<String>,
+        new_value: Option<String>,
+        detected_on: Date,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            stock_code,
+            stock_name,
+            change_type,
+            previous_value,
+            new_value,
+            detected_on,
+        }
+    }
+}
+
+/// 一次 ISIN 清單比對要寫入的所有變更，須在同一交易中寫入，避免部分失敗後重新偵測出相同異動
+#[derive(Debug, Clone)]
+pub struct ListingSync {
+    pub new_stocks: Vec<Stock>,
+    pub status_changes: Vec<(Uuid, ListingStatus)>,   // 股票 ID 及新的上市狀態
+    pub name_changes: Vec<StockNameChange>,
+    pub changes: Vec<UniverseChange>,
+    pub listings: Vec<ListedSecurity>,                 // 取代快照的最新清單
+    pub changed_on: Date,
+}
+
+impl ListingSync {
+    pub fn new(listings: Vec<ListedSecurity>, changed_on: Date) -> Self {
+        Self {
+            new_stocks: Vec::new(),
+            status_changes: Vec::new(),
+            name_changes: Vec::new(),
+            changes: Vec::new(),
+            listings,
+            changed_on,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::domain::models::BlockTrade;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait BlockTradeRepository: Send + Sync {
+    /// 以當日爬取結果取代該日所有鉅額交易紀錄，同一股票同價同量可能有多筆，無法以唯一鍵判斷重複
+    async fn replace_by_date(&self, date: Date, trades: &[BlockTrade]) -> Result<()>;
+    async fn find_by_stock_code(
+        &self,
+        stock_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<BlockTrade>>;
+}
@@ -0,0 +1,13 @@
+use crate::domain::models::{ConvertibleBond, ConvertibleBondQuote};
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ConvertibleBondRepository: Send + Sync {
+    /// 新增或更新發行資料，標的股票在 stocks 表中時建立關聯
+    async fn save_bond(&self, bond: &ConvertibleBond) -> Result<()>;
+    async fn save_quote(&self, quote: &ConvertibleBondQuote) -> Result<()>;
+    /// 標的股票尚有流通餘額且未到期的可轉債
+    async fn find_outstanding_by_underlying(&self, stock_code: &str) -> Result<Vec<ConvertibleBond>>;
+    async fn find_latest_quote(&self, bond_code: &str) -> Result<Option<ConvertibleBondQuote>>;
+}
@@ -0,0 +1,8 @@
+use crate::domain::models::DayTradingStat;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait DayTradingRepository: Send + Sync {
+    async fn save(&self, stat: &DayTradingStat) -> Result<()>;
+}
@@ -0,0 +1,29 @@
+use crate::domain::models::{FuturesDailyPrice, InstitutionalOpenInterest, OptionsDailyPrice};
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait DerivativesRepository: Send + Sync {
+    async fn save_futures_price(&self, price: &FuturesDailyPrice) -> Result<()>;
+    async fn save_options_price(&self, price: &OptionsDailyPrice) -> Result<()>;
+    async fn save_institutional_open_interest(&self, position: &InstitutionalOpenInterest) -> Result<()>;
+    async fn find_futures_prices(
+        &self,
+        contract_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<FuturesDailyPrice>>;
+    async fn find_options_prices(
+        &self,
+        contract_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<OptionsDailyPrice>>;
+    async fn find_institutional_open_interest(
+        &self,
+        contract_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<InstitutionalOpenInterest>>;
+}
@@ -0,0 +1,21 @@
+use crate::domain::models::{EtfConstituent, EtfNav};
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait EtfRepository: Send + Sync {
+    async fn save_nav(&self, nav: &EtfNav) -> Result<()>;
+    async fn find_navs(
+        &self,
+        etf_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<EtfNav>>;
+    /// 以當日爬取結果取代該 ETF 當天的成分股
+    async fn replace_constituents(&self, etf_code: &str, date: Date, constituents: &[EtfConstituent]) -> Result<()>;
+    /// 該 ETF 最近一次爬取的成分股，依權重由高到低排列
+    async fn find_latest_constituents(&self, etf_code: &str) -> Result<Vec<EtfConstituent>>;
+    /// 各 ETF 最近一次爬取的成分股中持有指定股票者
+    async fn find_latest_holders(&self, stock_code: &str) -> Result<Vec<EtfConstituent>>;
+}
@@ -0,0 +1,19 @@
+use crate::domain::models::ForeignOwnership;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait ForeignOwnershipRepository: Send + Sync {
+    async fn save(&self, ownership: &ForeignOwnership) -> Result<()>;
+    async fn find_by_stock_code_and_date_range(
+        &self,
+        stock_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<ForeignOwnership>>;
+    /// 每支股票最近一筆外資持股資料
+    async fn find_latest(&self) -> Result<Vec<ForeignOwnership>>;
+    /// 單一股票最近一筆外資持股資料
+    async fn find_latest_by_stock_code(&self, stock_code: &str) -> Result<Option<ForeignOwnership>>;
+}
@@ -0,0 +1,15 @@
+use crate::domain::models::IndexLevel;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait IndexLevelRepository: Send + Sync {
+    async fn save(&self, index_level: &IndexLevel) -> Result<()>;
+    async fn find_by_index_code_and_date_range(
+        &self,
+        index_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<IndexLevel>>;
+}
@@ -0,0 +1,18 @@
+use crate::domain::models::InsiderHolding;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait InsiderHoldingRepository: Send + Sync {
+    async fn save(&self, holding: &InsiderHolding) -> Result<()>;
+    /// 依月份由新到舊排列
+    async fn find_by_stock_code(&self, stock_code: &str) -> Result<Vec<InsiderHolding>>;
+    /// 指定月份之前最近一個月的資料
+    async fn find_previous(&self, stock_code: &str, period: Date) -> Result<Option<InsiderHolding>>;
+    /// 指定月份已保存資料的股票代碼
+    async fn find_stock_codes_by_period(&self, period: Date) -> Result<Vec<String>>;
+    /// 指定月份是否已爬取完所有公司
+    async fn is_period_completed(&self, period: Date) -> Result<bool>;
+    async fn mark_period_completed(&self, period: Date) -> Result<()>;
+}
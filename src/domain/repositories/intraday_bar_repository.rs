@@ -0,0 +1,17 @@
+use crate::domain::models::IntradayBar;
+use crate::domain::value_objects::{BarInterval, Result};
+use async_trait::async_trait;
+use time::Date;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait IntradayBarRepository: Send + Sync {
+    async fn save(&self, bar: &IntradayBar) -> Result<()>;
+    async fn find_by_stock_id_and_date_range(
+        &self,
+        stock_id: &Uuid,
+        interval: BarInterval,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<IntradayBar>>;
+}
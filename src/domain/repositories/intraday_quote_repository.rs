@@ -0,0 +1,8 @@
+use crate::domain::models::IntradayQuote;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IntradayQuoteRepository: Send + Sync {
+    async fn save(&self, quote: &IntradayQuote) -> Result<()>;
+}
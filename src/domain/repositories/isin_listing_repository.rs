@@ -0,0 +1,13 @@
+use crate::domain::models::{ListedSecurity, ListingSync};
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IsinListingRepository: Send + Sync {
+    /// 上一次儲存的 ISIN 上市櫃清單快照
+    async fn find_all(&self) -> Result<Vec<ListedSecurity>>;
+    /// 以最新爬取的清單取代快照
+    async fn replace_all(&self, listings: &[ListedSecurity]) -> Result<()>;
+    /// 在同一交易中寫入股票新增、狀態及名稱變更、異動紀錄，並以新的清單取代快照
+    async fn apply_sync(&self, sync: &ListingSync) -> Result<()>;
+}
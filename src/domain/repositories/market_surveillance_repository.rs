@@ -0,0 +1,17 @@
+use crate::domain::models::MarketSurveillanceEvent;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait MarketSurveillanceRepository: Send + Sync {
+    async fn save(&self, event: &MarketSurveillanceEvent) -> Result<()>;
+    /// 查詢公告期間與起訖日有重疊的公告，指定股票代碼時只查詢這些股票
+    async fn find_overlapping(
+        &self,
+        start_date: Date,
+        end_date: Date,
+        stock_codes: Option<&[String]>,
+    ) -> Result<Vec<MarketSurveillanceEvent>>;
+    async fn find_by_stock_code(&self, stock_code: &str) -> Result<Vec<MarketSurveillanceEvent>>;
+}
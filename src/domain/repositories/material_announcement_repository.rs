@@ -0,0 +1,16 @@
+use crate::domain::models::MaterialAnnouncement;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait MaterialAnnouncementRepository: Send + Sync {
+    /// 儲存重大訊息，回傳是否為新資料
+    async fn save(&self, announcement: &MaterialAnnouncement) -> Result<bool>;
+    async fn find_by_date_range(
+        &self,
+        stock_code: Option<&str>,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<MaterialAnnouncement>>;
+}
@@ -1,5 +1,47 @@
 mod stock_repository;
 mod stock_price_repository;
+mod intraday_quote_repository;
+mod intraday_bar_repository;
+mod index_level_repository;
+mod derivatives_repository;
+mod market_surveillance_repository;
+mod material_announcement_repository;
+mod foreign_ownership_repository;
+mod day_trading_repository;
+mod off_session_trading_repository;
+mod block_trade_repository;
+mod insider_holding_repository;
+mod treasury_buyback_repository;
+mod public_subscription_repository;
+mod etf_repository;
+mod convertible_bond_repository;
+mod shares_outstanding_repository;
+mod price_discrepancy_repository;
+mod quarantined_price_repository;
+mod price_gap_repository;
+mod isin_listing_repository;
+mod universe_change_repository;
 
 pub use stock_repository::StockRepository;
 pub use stock_price_repository::StockPriceRepository;
+pub use intraday_quote_repository::IntradayQuoteRepository;
+pub use intraday_bar_repository::IntradayBarRepository;
+pub use index_level_repository::IndexLevelRepository;
+pub use derivatives_repository::DerivativesRepository;
+pub use market_surveillance_repository::MarketSurveillanceRepository;
+pub use material_announcement_repository::MaterialAnnouncementRepository;
+pub use foreign_ownership_repository::ForeignOwnershipRepository;
+pub use day_trading_repository::DayTradingRepository;
+pub use off_session_trading_repository::OffSessionTradingRepository;
+pub use block_trade_repository::BlockTradeRepository;
+pub use insider_holding_repository::InsiderHoldingRepository;
+pub use treasury_buyback_repository::TreasuryBuybackRepository;
+pub use public_subscription_repository::PublicSubscriptionRepository;
+pub use etf_repository::EtfRepository;
+pub use convertible_bond_repository::ConvertibleBondRepository;
+pub use shares_outstanding_repository::SharesOutstandingRepository;
+pub use price_discrepancy_repository::PriceDiscrepancyRepository;
+pub use quarantined_price_repository::QuarantinedPriceRepository;
+pub use price_gap_repository::PriceGapRepository;
+pub use isin_listing_repository::IsinListingRepository;
+pub use universe_change_repository::UniverseChangeRepository;
@@ -0,0 +1,23 @@
+use crate::domain::models::{AfterHoursTrade, OddLotQuote};
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+/// 盤中零股及盤後定價交易的儲存庫，與一般交易時段的股價分開
+#[async_trait]
+pub trait OffSessionTradingRepository: Send + Sync {
+    async fn save_odd_lot_quote(&self, quote: &OddLotQuote) -> Result<()>;
+    async fn save_after_hours_trade(&self, trade: &AfterHoursTrade) -> Result<()>;
+    async fn find_odd_lot_quotes(
+        &self,
+        stock_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<OddLotQuote>>;
+    async fn find_after_hours_trades(
+        &self,
+        stock_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<AfterHoursTrade>>;
+}
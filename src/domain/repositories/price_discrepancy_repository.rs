@@ -0,0 +1,22 @@
+use crate::domain::models::{PriceDiscrepancy, PriceSource};
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait PriceDiscrepancyRepository: Send + Sync {
+    /// 以最新一次比對結果取代該股票當天與指定來源的差異紀錄
+    async fn replace(
+        &self,
+        stock_code: &str,
+        date: Date,
+        secondary_source: PriceSource,
+        discrepancies: &[PriceDiscrepancy],
+    ) -> Result<()>;
+    /// 依日期由新到舊、股票代碼排列
+    async fn find_by_date_range(
+        &self,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<PriceDiscrepancy>>;
+}
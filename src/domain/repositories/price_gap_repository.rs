@@ -0,0 +1,18 @@
+use crate::domain::models::{PriceGap, PriceGapStatus};
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait PriceGapRepository: Send + Sync {
+    /// 記錄缺漏的日期，已記錄過的日期保留原本的狀態及補抓次數
+    async fn record_missing(&self, stock_code: &str, dates: &[Date]) -> Result<()>;
+    /// 將 stock_prices 中已有資料的缺漏紀錄標記為已補齊
+    async fn mark_repaired_where_present(&self) -> Result<u64>;
+    /// 期間內確認當日無成交的日期
+    async fn find_no_trade_dates(&self, stock_code: &str, start_date: Date, end_date: Date) -> Result<Vec<Date>>;
+    /// 尚未補齊且補抓次數未達上限的缺漏，依股票代碼及日期排列
+    async fn find_open(&self, max_attempts: u32) -> Result<Vec<PriceGap>>;
+    /// 記錄一次補抓結果，補抓次數加一
+    async fn record_attempt(&self, stock_code: &str, date: Date, status: PriceGapStatus) -> Result<()>;
+}
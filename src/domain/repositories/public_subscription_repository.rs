@@ -0,0 +1,16 @@
+use crate::domain::models::PublicSubscription;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait PublicSubscriptionRepository: Send + Sync {
+    async fn save(&self, subscription: &PublicSubscription) -> Result<()>;
+    /// 依抽籤日期查詢，由近到遠排列
+    async fn find_by_lottery_date_range(
+        &self,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<PublicSubscription>>;
+    async fn find_by_listing_date_range(&self, start_date: Date, end_date: Date) -> Result<Vec<PublicSubscription>>;
+}
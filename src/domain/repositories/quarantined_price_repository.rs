@@ -0,0 +1,12 @@
+use crate::domain::models::QuarantinedPrice;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait QuarantinedPriceRepository: Send + Sync {
+    async fn save(&self, price: &QuarantinedPrice) -> Result<()>;
+    /// 期間內已移至隔離區的日期
+    async fn find_dates_by_stock_id(&self, stock_id: &Uuid, start_date: Date, end_date: Date) -> Result<Vec<Date>>;
+}
@@ -0,0 +1,9 @@
+use crate::domain::models::SharesOutstanding;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait SharesOutstandingRepository: Send + Sync {
+    /// 股數與生效日前最近一筆不同時才寫入，回傳是否有寫入
+    async fn record(&self, shares: &SharesOutstanding) -> Result<bool>;
+}
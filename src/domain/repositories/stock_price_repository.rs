@@ -16,4 +16,13 @@ pub trait StockPriceRepository: Send + Sync {
         end_date: Option<Date>,
     ) -> Result<Vec<StockPrice>>;
     async fn find_latest_by_stock_id(&self, stock_id: &Uuid) -> Result<Option<StockPrice>>;
+    /// 已保存股價的日期，依日期遞增排列
+    async fn find_dates_by_stock_id(
+        &self,
+        stock_id: &Uuid,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<Date>>;
+    /// 查詢指定日期之前最近一個交易日的價格
+    async fn find_previous_by_stock_id(&self, stock_id: &Uuid, date: Date) -> Result<Option<StockPrice>>;
 }
@@ -1,6 +1,7 @@
-use crate::domain::models::Stock;
+use crate::domain::models::{ListingStatus, Stock, StockNameChange};
 use crate::domain::value_objects::Result;
 use async_trait::async_trait;
+use time::Date;
 use uuid::Uuid;
 
 #[async_trait]
@@ -9,5 +10,8 @@ pub trait StockRepository: Send + Sync {
     async fn find_by_id(&self, id: &Uuid) -> Result<Option<Stock>>;
     async fn find_by_code(&self, code: &str) -> Result<Option<Stock>>;
     async fn find_all(&self) -> Result<Vec<Stock>>;
-    async fn delete(&self, id: &Uuid) -> Result<()>;
+    /// 股票價格等資料仍參照此股票，因此只標記為下市而不實際刪除
+    async fn mark_delisted(&self, id: &Uuid) -> Result<()>;
+    async fn update_status(&self, id: &Uuid, status: ListingStatus, changed_on: Date) -> Result<()>;
+    async fn find_name_history(&self, stock_id: &Uuid) -> Result<Vec<StockNameChange>>;
 }
@@ -0,0 +1,11 @@
+use crate::domain::models::TreasuryBuyback;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait TreasuryBuybackRepository: Send + Sync {
+    /// 新增或更新執行情形，回傳是否為新的買回計畫；股票不在 stocks 表中時不保存並回傳 false
+    async fn save(&self, buyback: &TreasuryBuyback) -> Result<bool>;
+    /// 依董事會決議日期由新到舊排列
+    async fn find_by_stock_code(&self, stock_code: &str) -> Result<Vec<TreasuryBuyback>>;
+}
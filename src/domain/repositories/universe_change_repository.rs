@@ -0,0 +1,10 @@
+use crate::domain::models::UniverseChange;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use time::Date;
+
+#[async_trait]
+pub trait UniverseChangeRepository: Send + Sync {
+    /// 依偵測日期由新到舊、股票代碼排列
+    async fn find_since(&self, since: Option<Date>) -> Result<Vec<UniverseChange>>;
+}
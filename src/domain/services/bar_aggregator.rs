@@ -0,0 +1,172 @@
+use crate::domain::models::{IntradayBar, IntradayQuote};
+use crate::domain::services::MarketClock;
+use crate::domain::value_objects::BarInterval;
+use std::collections::HashMap;
+use time::Date;
+use uuid::Uuid;
+
+/// 將盤中即時報價彙整為各週期的 K 棒
+///
+/// 即時報價提供的是累積成交量，這裡以前後兩筆的差額作為區間成交量
+pub struct BarAggregator {
+    intervals: Vec<BarInterval>,
+    current_bars: HashMap<(Uuid, BarInterval), IntradayBar>,
+    last_accumulated_volume: HashMap<Uuid, (Date, u64)>,   // 各股最近一次計入的交易日及累積量
+}
+
+impl BarAggregator {
+    pub fn new(intervals: Vec<BarInterval>) -> Self {
+        Self {
+            intervals,
+            current_bars: HashMap::new(),
+            last_accumulated_volume: HashMap::new(),
+        }
+    }
+
+    /// 處理一筆即時報價，回傳有變動的 K 棒 (含尚未收盤的當前 K 棒)
+    pub fn on_quote(&mut self, stock_id: Uuid, quote: &IntradayQuote) -> Vec<IntradayBar> {
+        // 該快照沒有成交價時無法形成 K 棒，成交量留待下一筆有成交的報價一併計算
+        let price = match &quote.last_price {
+            Some(price) => price,
+            None => return Vec::new(),
+        };
+
+        // 第一筆報價 (例如盤中重新啟動) 只作為累積量的基準，否則當天先前的成交量會全部算進這根 K 棒；
+        // 換日後累積量從 0 重新起算；同一天累積量變小的是較舊的快照，不計成交量也不回退基準
+        let trading_date = quote.quote_time.to_offset(MarketClock::offset()).date();
+        let volume = match self.last_accumulated_volume.get(&stock_id).copied() {
+            None => {
+                self.last_accumulated_volume.insert(stock_id, (trading_date, quote.accumulated_volume));
+                0
+            }
+            Some((date, _)) if date != trading_date => {
+                self.last_accumulated_volume.insert(stock_id, (trading_date, quote.accumulated_volume));
+                quote.accumulated_volume
+            }
+            Some((_, previous)) if quote.accumulated_volume >= previous => {
+                self.last_accumulated_volume.insert(stock_id, (trading_date, quote.accumulated_volume));
+                quote.accumulated_volume - previous
+            }
+            Some(_) => 0,
+        };
+
+        let mut updated_bars = Vec::new();
+        for interval in &self.intervals {
+            let start_time = interval.bucket_start(quote.quote_time);
+            let key = (stock_id, *interval);
+
+            match self.current_bars.get_mut(&key) {
+                Some(bar) if bar.start_time == start_time => {
+                    bar.update(price, volume);
+                    updated_bars.push(bar.clone());
+                }
+                // 延遲到達的舊報價不再回頭修改已結束的 K 棒，但基準已前進，成交量計入當前 K 棒以免遺失
+                Some(bar) if bar.start_time > start_time => {
+                    if volume > 0 {
+                        bar.volume += volume;
+                        updated_bars.push(bar.clone());
+                    }
+                }
+                _ => {
+                    let bar = IntradayBar::new(stock_id, *interval, start_time, price.clone(), volume);
+                    updated_bars.push(bar.clone());
+                    self.current_bars.insert(key, bar);
+                }
+            }
+        }
+
+        updated_bars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bigdecimal::BigDecimal;
+    use time::OffsetDateTime;
+    use time::format_description::well_known::Rfc3339;
+
+    fn at(value: &str) -> OffsetDateTime {
+        OffsetDateTime::parse(value, &Rfc3339).unwrap()
+    }
+
+    fn quote(time: OffsetDateTime, price: i64, accumulated_volume: u64) -> IntradayQuote {
+        IntradayQuote {
+            id: Uuid::new_v4(),
+            stock_code: "2330".to_string(),
+            stock_name: "台積電".to_string(),
+            quote_time: time,
+            last_price: Some(BigDecimal::from(price)),
+            accumulated_volume,
+            open: None,
+            high: None,
+            low: None,
+            prev_close: None,
+            bids: Vec::new(),
+            asks: Vec::new(),
+        }
+    }
+
+    fn one_minute(bars: &[IntradayBar]) -> &IntradayBar {
+        bars.iter().find(|bar| bar.interval == BarInterval::OneMinute).unwrap()
+    }
+
+    #[test]
+    fn first_quote_after_restart_only_seeds_the_baseline() {
+        let mut aggregator = BarAggregator::new(vec![BarInterval::OneMinute]);
+        let stock_id = Uuid::new_v4();
+
+        let bars = aggregator.on_quote(stock_id, &quote(at("2026-10-16T11:00:05+08:00"), 1000, 5_000_000));
+        assert_eq!(one_minute(&bars).volume, 0);
+
+        let bars = aggregator.on_quote(stock_id, &quote(at("2026-10-16T11:00:10+08:00"), 1001, 5_002_000));
+        assert_eq!(one_minute(&bars).volume, 2_000);
+    }
+
+    #[test]
+    fn late_quote_volume_is_added_to_the_current_bar() {
+        let mut aggregator = BarAggregator::new(vec![BarInterval::OneMinute]);
+        let stock_id = Uuid::new_v4();
+
+        aggregator.on_quote(stock_id, &quote(at("2026-10-16T09:00:05+08:00"), 1000, 1_000));
+        aggregator.on_quote(stock_id, &quote(at("2026-10-16T09:01:05+08:00"), 1001, 3_000));
+
+        // 屬於 09:00 的報價晚到，K 棒已換到 09:01，成交量改計入 09:01
+        let bars = aggregator.on_quote(stock_id, &quote(at("2026-10-16T09:00:55+08:00"), 999, 3_500));
+        let bar = one_minute(&bars);
+        assert_eq!(bar.start_time, at("2026-10-16T09:01:00+08:00"));
+        assert_eq!(bar.volume, 2_500);
+        assert_eq!(bar.low, BigDecimal::from(1001));
+
+        let bars = aggregator.on_quote(stock_id, &quote(at("2026-10-16T09:01:30+08:00"), 1002, 4_000));
+        assert_eq!(one_minute(&bars).volume, 3_000);
+    }
+
+    #[test]
+    fn stale_snapshot_with_smaller_volume_does_not_reset_the_baseline() {
+        let mut aggregator = BarAggregator::new(vec![BarInterval::OneMinute]);
+        let stock_id = Uuid::new_v4();
+
+        aggregator.on_quote(stock_id, &quote(at("2026-10-16T09:00:05+08:00"), 1000, 1_000));
+        aggregator.on_quote(stock_id, &quote(at("2026-10-16T09:00:20+08:00"), 1000, 2_000));
+        let bars = aggregator.on_quote(stock_id, &quote(at("2026-10-16T09:00:10+08:00"), 1000, 1_500));
+        assert_eq!(one_minute(&bars).volume, 1_000);
+
+        let bars = aggregator.on_quote(stock_id, &quote(at("2026-10-16T09:00:30+08:00"), 1000, 2_500));
+        assert_eq!(one_minute(&bars).volume, 1_500);
+    }
+
+    #[test]
+    fn day_rollover_counts_the_new_day_from_zero() {
+        let mut aggregator = BarAggregator::new(vec![BarInterval::OneMinute]);
+        let stock_id = Uuid::new_v4();
+
+        aggregator.on_quote(stock_id, &quote(at("2026-10-15T13:29:55+08:00"), 1000, 1_000));
+        aggregator.on_quote(stock_id, &quote(at("2026-10-15T13:30:00+08:00"), 1000, 9_000_000));
+
+        let bars = aggregator.on_quote(stock_id, &quote(at("2026-10-16T09:00:05+08:00"), 1010, 20_000));
+        let bar = one_minute(&bars);
+        assert_eq!(bar.start_time, at("2026-10-16T09:00:00+08:00"));
+        assert_eq!(bar.volume, 20_000);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::domain::services::TradingCalendar;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+use time::{Date, OffsetDateTime, UtcOffset};
+
+/// 台股交易時段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionPhase {
+    PreOpen,              // 盤前試撮 08:30 ~ 09:00
+    Continuous,           // 盤中逐筆交易 09:00 ~ 13:25
+    ClosingCall,          // 收盤集合競價 13:25 ~ 13:30
+    AfterHoursOddLot,     // 盤後零股交易 13:40 ~ 14:00
+    AfterHoursFixedPrice, // 盤後定價交易 14:00 ~ 14:30，盤後零股同時進行
+    Closed,               // 非交易時段或非交易日
+}
+
+impl SessionPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionPhase::PreOpen => "pre_open",
+            SessionPhase::Continuous => "continuous",
+            SessionPhase::ClosingCall => "closing_call",
+            SessionPhase::AfterHoursOddLot => "after_hours_odd_lot",
+            SessionPhase::AfterHoursFixedPrice => "after_hours_fixed_price",
+            SessionPhase::Closed => "closed",
+        }
+    }
+
+    /// 一般交易時段 (含收盤集合競價)
+    pub fn is_regular_session(&self) -> bool {
+        matches!(self, SessionPhase::Continuous | SessionPhase::ClosingCall)
+    }
+}
+
+/// 以時:分表示的當日分鐘數
+const fn minute_of_day(hour: u16, minute: u16) -> u16 {
+    hour * 60 + minute
+}
+
+/// 台股市場時鐘，以 Asia/Taipei (UTC+8，無日光節約時間) 判斷日期及交易時段
+///
+/// 交易日曆可在執行期間替換，以便跨年或調整檔更新後重新載入
+#[derive(Debug)]
+pub struct MarketClock {
+    trading_calendar: RwLock<Arc<TradingCalendar>>,
+}
+
+impl MarketClock {
+    pub fn new(trading_calendar: Arc<TradingCalendar>) -> Self {
+        Self { trading_calendar: RwLock::new(trading_calendar) }
+    }
+
+    /// 台北時區
+    pub fn offset() -> UtcOffset {
+        UtcOffset::from_hms(8, 0, 0).unwrap()
+    }
+
+    /// 目前的台北時間
+    pub fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc().to_offset(Self::offset())
+    }
+
+    /// 台北時間的今天
+    pub fn today(&self) -> Date {
+        Self::date_at(self.now())
+    }
+
+    /// 指定時間點在台北時間的日期
+    pub fn date_at(time: OffsetDateTime) -> Date {
+        time.to_offset(Self::offset()).date()
+    }
+
+    pub fn trading_calendar(&self) -> Arc<TradingCalendar> {
+        self.trading_calendar.read().unwrap().clone()
+    }
+
+    /// 以重新載入的交易日曆取代目前的日曆
+    pub fn replace_trading_calendar(&self, trading_calendar: TradingCalendar) {
+        *self.trading_calendar.write().unwrap() = Arc::new(trading_calendar);
+    }
+
+    pub fn is_trading_day(&self, date: Date) -> bool {
+        self.trading_calendar().is_trading_day(date)
+    }
+
+    /// 目前所處的交易時段
+    pub fn session_phase(&self) -> SessionPhase {
+        self.session_phase_at(self.now())
+    }
+
+    /// 指定時間點所處的交易時段
+    pub fn session_phase_at(&self, time: OffsetDateTime) -> SessionPhase {
+        let local = time.to_offset(Self::offset());
+        if !self.is_trading_day(local.date()) {
+            return SessionPhase::Closed;
+        }
+
+        let minutes = minute_of_day(local.hour() as u16, local.minute() as u16);
+        match minutes {
+            m if (minute_of_day(8, 30)..minute_of_day(9, 0)).contains(&m) => SessionPhase::PreOpen,
+            m if (minute_of_day(9, 0)..minute_of_day(13, 25)).contains(&m) => SessionPhase::Continuous,
+            m if (minute_of_day(13, 25)..minute_of_day(13, 30)).contains(&m) => SessionPhase::ClosingCall,
+            m if (minute_of_day(13, 40)..minute_of_day(14, 0)).contains(&m) => SessionPhase::AfterHoursOddLot,
+            m if (minute_of_day(14, 0)..minute_of_day(14, 30)).contains(&m) => SessionPhase::AfterHoursFixedPrice,
+            _ => SessionPhase::Closed,
+        }
+    }
+
+    /// 指定時間點是否可交易零股 (盤中零股 09:00 ~ 13:30、盤後零股 13:40 ~ 14:30)
+    pub fn is_odd_lot_session_at(&self, time: OffsetDateTime) -> bool {
+        match self.session_phase_at(time) {
+            SessionPhase::Continuous
+            | SessionPhase::ClosingCall
+            | SessionPhase::AfterHoursOddLot
+            | SessionPhase::AfterHoursFixedPrice => true,
+            SessionPhase::PreOpen | SessionPhase::Closed => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+    use time::format_description::well_known::Rfc3339;
+
+    fn at(value: &str) -> OffsetDateTime {
+        OffsetDateTime::parse(value, &Rfc3339).unwrap()
+    }
+
+    fn clock() -> MarketClock {
+        MarketClock::new(Arc::new(TradingCalendar::new()))
+    }
+
+    #[test]
+    fn session_phase_boundaries() {
+        let clock = clock();
+        // 2026-10-16 為週五
+        let cases = [
+            ("2026-10-16T08:29:59+08:00", SessionPhase::Closed),
+            ("2026-10-16T08:30:00+08:00", SessionPhase::PreOpen),
+            ("2026-10-16T08:59:59+08:00", SessionPhase::PreOpen),
+            ("2026-10-16T09:00:00+08:00", SessionPhase::Continuous),
+            ("2026-10-16T13:24:59+08:00", SessionPhase::Continuous),
+            ("2026-10-16T13:25:00+08:00", SessionPhase::ClosingCall),
+            ("2026-10-16T13:30:00+08:00", SessionPhase::Closed),
+            ("2026-10-16T13:40:00+08:00", SessionPhase::AfterHoursOddLot),
+            ("2026-10-16T14:00:00+08:00", SessionPhase::AfterHoursFixedPrice),
+            ("2026-10-16T14:29:59+08:00", SessionPhase::AfterHoursFixedPrice),
+            ("2026-10-16T14:30:00+08:00", SessionPhase::Closed),
+        ];
+        for (time, phase) in cases {
+            assert_eq!(clock.session_phase_at(at(time)), phase, "{}", time);
+        }
+    }
+
+    #[test]
+    fn session_phase_uses_taipei_time_for_utc_input() {
+        // UTC 01:00 為台北 09:00
+        assert_eq!(clock().session_phase_at(at("2026-10-16T01:00:00Z")), SessionPhase::Continuous);
+    }
+
+    #[test]
+    fn non_trading_days_are_closed() {
+        let mut calendar = TradingCalendar::new();
+        calendar.add_holiday(Date::from_calendar_date(2026, Month::October, 16).unwrap());
+        let clock = MarketClock::new(Arc::new(calendar));
+
+        assert_eq!(clock.session_phase_at(at("2026-10-16T10:00:00+08:00")), SessionPhase::Closed);
+        assert_eq!(clock.session_phase_at(at("2026-10-17T10:00:00+08:00")), SessionPhase::Closed);
+    }
+
+    #[test]
+    fn odd_lot_session_spans_regular_and_after_hours() {
+        let clock = clock();
+        assert!(!clock.is_odd_lot_session_at(at("2026-10-16T08:45:00+08:00")));
+        assert!(clock.is_odd_lot_session_at(at("2026-10-16T09:00:00+08:00")));
+        assert!(!clock.is_odd_lot_session_at(at("2026-10-16T13:35:00+08:00")));
+        assert!(clock.is_odd_lot_session_at(at("2026-10-16T14:15:00+08:00")));
+    }
+
+    #[test]
+    fn taipei_date_rolls_over_at_utc_sixteen() {
+        let date = |day| Date::from_calendar_date(2026, Month::October, day).unwrap();
+        assert_eq!(MarketClock::date_at(at("2026-10-15T15:59:59Z")), date(15));
+        assert_eq!(MarketClock::date_at(at("2026-10-15T16:00:00Z")), date(16));
+    }
+
+    #[test]
+    fn replaced_calendar_takes_effect() {
+        let clock = clock();
+        let date = Date::from_calendar_date(2026, Month::October, 16).unwrap();
+        assert!(clock.is_trading_day(date));
+
+        let mut calendar = TradingCalendar::new();
+        calendar.add_holiday(date);
+        clock.replace_trading_calendar(calendar);
+        assert!(!clock.is_trading_day(date));
+    }
+}
@@ -1,2 +1,8 @@
 // 領域服務模組
-// 這裡可以添加領域特定的服務實現
+mod bar_aggregator;
+mod trading_calendar;
+mod market_clock;
+
+pub use bar_aggregator::BarAggregator;
+pub use trading_calendar::{TradingCalendar, TradingCalendarOverrides};
+pub use market_clock::MarketClock;
@@ -0,0 +1,198 @@
+use crate::domain::value_objects::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use time::{Date, Weekday};
+
+/// 單筆人工調整的日期，例如颱風停止交易
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarOverride {
+    pub date: String,        // YYYY-MM-DD
+    #[serde(default)]
+    pub reason: String,
+}
+
+/// 本地交易日調整檔，補充證交所休市表未涵蓋的臨時休市或補行交易
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TradingCalendarOverrides {
+    #[serde(default)]
+    pub holidays: Vec<CalendarOverride>,
+    #[serde(default)]
+    pub trading_days: Vec<CalendarOverride>,
+}
+
+/// 台股交易日曆
+///
+/// 週一至週五為交易日，但排除休市日；週末若為補行交易日則照常交易。
+#[derive(Debug, Clone, Default)]
+pub struct TradingCalendar {
+    holidays: BTreeSet<Date>,
+    makeup_days: BTreeSet<Date>,
+}
+
+impl TradingCalendar {
+    /// 創建只以週一至週五判斷交易日的日曆
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 加入休市日
+    pub fn add_holiday(&mut self, date: Date) {
+        self.makeup_days.remove(&date);
+        self.holidays.insert(date);
+    }
+
+    /// 加入補行交易日 (週末開市)
+    pub fn add_makeup_day(&mut self, date: Date) {
+        self.holidays.remove(&date);
+        self.makeup_days.insert(date);
+    }
+
+    /// 套用本地調整檔，調整檔優先於證交所休市表
+    pub fn apply_overrides(&mut self, overrides: &TradingCalendarOverrides) -> Result<()> {
+        let format = time::format_description::well_known::Iso8601::DATE;
+
+        for holiday in &overrides.holidays {
+            self.add_holiday(Date::parse(&holiday.date, &format)?);
+        }
+        for trading_day in &overrides.trading_days {
+            self.add_makeup_day(Date::parse(&trading_day.date, &format)?);
+        }
+
+        Ok(())
+    }
+
+    /// 是否為交易日
+    pub fn is_trading_day(&self, date: Date) -> bool {
+        if self.makeup_days.contains(&date) {
+            return true;
+        }
+        if self.holidays.contains(&date) {
+            return false;
+        }
+        !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+    }
+
+    /// 指定日期之後 (不含當日) 的第一個交易日
+    pub fn next_trading_day(&self, date: Date) -> Date {
+        let mut next = date;
+        while let Some(day) = next.next_day() {
+            next = day;
+            if self.is_trading_day(next) {
+                break;
+            }
+        }
+        next
+    }
+
+    /// 指定日期之前 (不含當日) 的最後一個交易日
+    pub fn previous_trading_day(&self, date: Date) -> Date {
+        let mut previous = date;
+        while let Some(day) = previous.previous_day() {
+            previous = day;
+            if self.is_trading_day(previous) {
+                break;
+            }
+        }
+        previous
+    }
+
+    /// 起訖日之間 (皆包含) 的所有交易日，依日期遞增排序
+    pub fn trading_days_between(&self, start_date: Date, end_date: Date) -> Vec<Date> {
+        let mut days = Vec::new();
+        let mut current = start_date;
+
+        while current <= end_date {
+            if self.is_trading_day(current) {
+                days.push(current);
+            }
+            match current.next_day() {
+                Some(day) => current = day,
+                None => break,
+            }
+        }
+
+        days
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    fn date(day: u8) -> Date {
+        Date::from_calendar_date(2026, Month::October, day).unwrap()
+    }
+
+    fn overrides(holidays: &[&str], trading_days: &[&str]) -> TradingCalendarOverrides {
+        let entry = |date: &&str| CalendarOverride { date: date.to_string(), reason: String::new() };
+        TradingCalendarOverrides {
+            holidays: holidays.iter().map(entry).collect(),
+            trading_days: trading_days.iter().map(entry).collect(),
+        }
+    }
+
+    #[test]
+    fn next_trading_day_skips_weekends() {
+        let calendar = TradingCalendar::new();
+        // 10/9 (五) 的下一個交易日為 10/12 (一)
+        assert_eq!(calendar.next_trading_day(date(9)), date(12));
+        assert_eq!(calendar.next_trading_day(date(12)), date(13));
+        assert_eq!(calendar.previous_trading_day(date(12)), date(9));
+    }
+
+    #[test]
+    fn next_trading_day_skips_holidays_and_includes_makeup_days() {
+        let mut calendar = TradingCalendar::new();
+        calendar.add_holiday(date(12));
+        assert_eq!(calendar.next_trading_day(date(9)), date(13));
+
+        calendar.add_makeup_day(date(10));
+        assert_eq!(calendar.next_trading_day(date(9)), date(10));
+        assert_eq!(calendar.previous_trading_day(date(13)), date(10));
+    }
+
+    #[test]
+    fn trading_days_between_is_inclusive_and_follows_the_calendar() {
+        let mut calendar = TradingCalendar::new();
+        assert_eq!(calendar.trading_days_between(date(9), date(12)), vec![date(9), date(12)]);
+
+        calendar.add_holiday(date(9));
+        calendar.add_makeup_day(date(10));
+        assert_eq!(calendar.trading_days_between(date(9), date(12)), vec![date(10), date(12)]);
+        assert!(calendar.trading_days_between(date(12), date(9)).is_empty());
+    }
+
+    #[test]
+    fn overrides_add_closures_and_makeup_days() {
+        let mut calendar = TradingCalendar::new();
+        calendar.apply_overrides(&overrides(&["2026-10-14"], &["2026-10-17"])).unwrap();
+
+        assert!(!calendar.is_trading_day(date(14)));
+        assert!(calendar.is_trading_day(date(17)));
+        assert_eq!(calendar.next_trading_day(date(13)), date(15));
+        assert_eq!(calendar.next_trading_day(date(16)), date(17));
+        assert_eq!(
+            calendar.trading_days_between(date(12), date(18)),
+            vec![date(12), date(13), date(15), date(16), date(17)]
+        );
+    }
+
+    #[test]
+    fn overrides_take_precedence_over_the_holiday_schedule() {
+        let mut calendar = TradingCalendar::new();
+        calendar.add_makeup_day(date(10));
+        calendar.add_holiday(date(13));
+
+        // 颱風取消補行交易，並恢復原定休市日開市
+        calendar.apply_overrides(&overrides(&["2026-10-10"], &["2026-10-13"])).unwrap();
+        assert!(!calendar.is_trading_day(date(10)));
+        assert!(calendar.is_trading_day(date(13)));
+    }
+
+    #[test]
+    fn malformed_override_date_is_rejected() {
+        let mut calendar = TradingCalendar::new();
+        assert!(calendar.apply_overrides(&overrides(&["2026/10/14"], &[])).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use time::OffsetDateTime;
+
+/// K 棒週期
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BarInterval {
+    OneMinute,
+    FiveMinutes,
+    FifteenMinutes,
+    SixtyMinutes,
+}
+
+impl BarInterval {
+    pub const ALL: [BarInterval; 4] = [
+        BarInterval::OneMinute,
+        BarInterval::FiveMinutes,
+        BarInterval::FifteenMinutes,
+        BarInterval::SixtyMinutes,
+    ];
+
+    pub fn minutes(&self) -> i64 {
+        match self {
+            BarInterval::OneMinute => 1,
+            BarInterval::FiveMinutes => 5,
+            BarInterval::FifteenMinutes => 15,
+            BarInterval::SixtyMinutes => 60,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BarInterval::OneMinute => "1m",
+            BarInterval::FiveMinutes => "5m",
+            BarInterval::FifteenMinutes => "15m",
+            BarInterval::SixtyMinutes => "60m",
+        }
+    }
+
+    /// 計算時間點所屬 K 棒的起始時間
+    ///
+    /// 台北時間與 UTC 相差整數小時，直接以 UTC 分鐘數對齊即可
+    pub fn bucket_start(&self, time: OffsetDateTime) -> OffsetDateTime {
+        let seconds = time.unix_timestamp();
+        let aligned = seconds - seconds.rem_euclid(self.minutes() * 60);
+        OffsetDateTime::from_unix_timestamp(aligned)
+            .map(|t| t.to_offset(time.offset()))
+            .unwrap_or(time)
+    }
+}
+
+impl FromStr for BarInterval {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1m" => Ok(BarInterval::OneMinute),
+            "5m" => Ok(BarInterval::FiveMinutes),
+            "15m" => Ok(BarInterval::FifteenMinutes),
+            "60m" => Ok(BarInterval::SixtyMinutes),
+            _ => Err(anyhow::anyhow!("不支援的 K 棒週期: {}", s)),
+        }
+    }
+}
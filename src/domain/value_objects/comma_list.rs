@@ -0,0 +1,26 @@
+/// 解析逗號分隔的設定值，去除前後空白、空項目及重複項目，保留原本的順序
+pub fn parse_comma_list(text: &str) -> Vec<String> {
+    let mut items: Vec<String> = Vec::new();
+    for item in text.split(',').map(|i| i.trim()) {
+        if !item.is_empty() && !items.iter().any(|i| i == item) {
+            items.push(item.to_string());
+        }
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trims_and_drops_empty_and_duplicate_items() {
+        assert_eq!(parse_comma_list(" 2330, 2317,,2330 ,0050,"), vec!["2330", "2317", "0050"]);
+    }
+
+    #[test]
+    fn empty_text_yields_no_items() {
+        assert!(parse_comma_list("").is_empty());
+        assert!(parse_comma_list(" , ").is_empty());
+    }
+}
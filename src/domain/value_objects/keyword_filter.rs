@@ -0,0 +1,19 @@
+use crate::domain::value_objects::comma_list::parse_comma_list;
+use serde::{Deserialize, Serialize};
+
+/// 關鍵字過濾條件，任一關鍵字出現即符合；未設定關鍵字時全部符合
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KeywordFilter {
+    pub keywords: Vec<String>,
+}
+
+impl KeywordFilter {
+    /// 從逗號分隔的字串解析關鍵字，例如 "澄清,減資,合併"
+    pub fn parse(text: &str) -> Self {
+        Self { keywords: parse_comma_list(text) }
+    }
+
+    pub fn matches(&self, text: &str) -> bool {
+        self.keywords.is_empty() || self.keywords.iter().any(|k| text.contains(k.as_str()))
+    }
+}
@@ -1,4 +1,15 @@
+mod bar_interval;
+mod comma_list;
 mod date_range;
+mod keyword_filter;
+mod price_limit;
+mod put_call_ratio;
 mod result;
+mod watchlist;
 
+pub use bar_interval::BarInterval;
+pub use keyword_filter::KeywordFilter;
+pub use price_limit::{LimitStatus, PriceLimit};
+pub use put_call_ratio::PutCallRatio;
 pub use result::Result;
+pub use watchlist::Watchlist;
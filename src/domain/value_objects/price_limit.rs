@@ -0,0 +1,186 @@
+use bigdecimal::{BigDecimal, RoundingMode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// 漲跌停狀態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LimitStatus {
+    LimitUp,   // 漲停
+    LimitDown, // 跌停
+}
+
+impl LimitStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LimitStatus::LimitUp => "LIMIT_UP",
+            LimitStatus::LimitDown => "LIMIT_DOWN",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "LIMIT_UP" => Some(LimitStatus::LimitUp),
+            "LIMIT_DOWN" => Some(LimitStatus::LimitDown),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LimitStatus::LimitUp => "漲停",
+            LimitStatus::LimitDown => "跌停",
+        }
+    }
+}
+
+/// 台股漲跌停價格，以前一日收盤價 ±10% 計算並調整至有效升降單位
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceLimit {
+    pub previous_close: BigDecimal,
+    pub limit_up: BigDecimal,
+    pub limit_down: BigDecimal,
+    pub is_etf: bool,
+}
+
+impl PriceLimit {
+    /// 依前一日收盤價計算漲跌停價格
+    ///
+    /// 漲停價向下、跌停價向上取至升降單位，確保不超過 ±10%
+    pub fn from_previous_close(previous_close: BigDecimal, is_etf: bool) -> Self {
+        let limit_up = Self::round_to_tick(
+            &(&previous_close * BigDecimal::from_str("1.1").unwrap()),
+            is_etf,
+            RoundingMode::Floor,
+        );
+        let limit_down = Self::round_to_tick(
+            &(&previous_close * BigDecimal::from_str("0.9").unwrap()),
+            is_etf,
+            RoundingMode::Ceiling,
+        );
+
+        Self {
+            previous_close,
+            limit_up,
+            limit_down,
+            is_etf,
+        }
+    }
+
+    /// 升降單位
+    ///
+    /// 股票：未滿 10 元 0.01、10~50 元 0.05、50~100 元 0.1、100~500 元 0.5、500~1000 元 1、1000 元以上 5；
+    /// ETF：未滿 50 元 0.01、50 元以上 0.05
+    pub fn tick_size(price: &BigDecimal, is_etf: bool) -> BigDecimal {
+        let tick = if is_etf {
+            if *price < 50 { "0.01" } else { "0.05" }
+        } else if *price < 10 {
+            "0.01"
+        } else if *price < 50 {
+            "0.05"
+        } else if *price < 100 {
+            "0.1"
+        } else if *price < 500 {
+            "0.5"
+        } else if *price < 1000 {
+            "1"
+        } else {
+            "5"
+        };
+        BigDecimal::from_str(tick).unwrap()
+    }
+
+    /// 將價格取至有效升降單位
+    pub fn round_to_tick(price: &BigDecimal, is_etf: bool, mode: RoundingMode) -> BigDecimal {
+        let tick = Self::tick_size(price, is_etf);
+        let ticks = (price / &tick).with_scale_round(0, mode);
+        ticks * tick
+    }
+
+    /// 判斷收盤價是否為漲停或跌停
+    pub fn status(&self, close: &BigDecimal) -> Option<LimitStatus> {
+        if *close >= self.limit_up {
+            Some(LimitStatus::LimitUp)
+        } else if *close <= self.limit_down {
+            Some(LimitStatus::LimitDown)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    fn limits(previous_close: &str, is_etf: bool) -> (BigDecimal, BigDecimal) {
+        let limit = PriceLimit::from_previous_close(dec(previous_close), is_etf);
+        (limit.limit_up, limit.limit_down)
+    }
+
+    #[test]
+    fn stock_tick_size_changes_at_each_boundary() {
+        let cases = [
+            ("9.99", "0.01"),
+            ("10", "0.05"),
+            ("49.95", "0.05"),
+            ("50", "0.1"),
+            ("99.9", "0.1"),
+            ("100", "0.5"),
+            ("499.5", "0.5"),
+            ("500", "1"),
+            ("999", "1"),
+            ("1000", "5"),
+        ];
+        for (price, tick) in cases {
+            assert_eq!(PriceLimit::tick_size(&dec(price), false), dec(tick), "價格 {}", price);
+        }
+    }
+
+    #[test]
+    fn etf_tick_size_changes_at_fifty() {
+        assert_eq!(PriceLimit::tick_size(&dec("49.99"), true), dec("0.01"));
+        assert_eq!(PriceLimit::tick_size(&dec("50"), true), dec("0.05"));
+        assert_eq!(PriceLimit::tick_size(&dec("1000"), true), dec("0.05"));
+    }
+
+    #[test]
+    fn limits_on_exact_ticks_are_ten_percent() {
+        assert_eq!(limits("100", false), (dec("110"), dec("90")));
+        assert_eq!(limits("50", true), (dec("55"), dec("45")));
+    }
+
+    #[test]
+    fn limit_up_rounds_down_and_limit_down_rounds_up() {
+        // 12.221 取至 0.05 為 12.20；9.999 取至 0.01 為 10.00
+        assert_eq!(limits("11.11", false), (dec("12.2"), dec("10")));
+        // 1006.5 取至 5 為 1005；823.5 取至 1 為 824
+        assert_eq!(limits("915", false), (dec("1005"), dec("824")));
+    }
+
+    #[test]
+    fn limits_crossing_a_tick_boundary_use_the_tick_of_the_limit_price() {
+        // 10 元
+        assert_eq!(limits("9.5", false), (dec("10.45"), dec("8.55")));
+        // 50 元：50.05 取至 0.1 為 50.0，40.95 仍以 0.05 計
+        assert_eq!(limits("45.5", false), (dec("50"), dec("40.95")));
+        // 100 元：100.1 取至 0.5 為 100.0
+        assert_eq!(limits("91", false), (dec("100"), dec("81.9")));
+        // 500 元：500.5 取至 1 為 500，409.5 仍以 0.5 計
+        assert_eq!(limits("455", false), (dec("500"), dec("409.5")));
+        // ETF 50 元：50.6 以 0.05 計，41.4 以 0.01 計
+        assert_eq!(limits("46", true), (dec("50.6"), dec("41.4")));
+    }
+
+    #[test]
+    fn status_matches_limit_prices() {
+        let limit = PriceLimit::from_previous_close(dec("100"), false);
+        assert_eq!(limit.status(&dec("110")), Some(LimitStatus::LimitUp));
+        assert_eq!(limit.status(&dec("90")), Some(LimitStatus::LimitDown));
+        assert_eq!(limit.status(&dec("109.5")), None);
+        assert_eq!(limit.status(&dec("90.1")), None);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::domain::models::{OptionType, OptionsDailyPrice};
+use serde::{Deserialize, Serialize};
+use time::Date;
+
+/// 選擇權賣權/買權比 (Put/Call Ratio)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PutCallRatio {
+    pub date: Date,
+    pub put_volume: u64,
+    pub call_volume: u64,
+    pub put_open_interest: u64,
+    pub call_open_interest: u64,
+}
+
+impl PutCallRatio {
+    /// 彙總同一天所有序列的成交量及未平倉量
+    pub fn from_options(date: Date, options: &[OptionsDailyPrice]) -> Self {
+        let mut ratio = Self {
+            date,
+            put_volume: 0,
+            call_volume: 0,
+            put_open_interest: 0,
+            call_open_interest: 0,
+        };
+
+        for option in options.iter().filter(|o| o.date == date) {
+            match option.option_type {
+                OptionType::Put => {
+                    ratio.put_volume += option.volume;
+                    ratio.put_open_interest += option.open_interest;
+                }
+                OptionType::Call => {
+                    ratio.call_volume += option.volume;
+                    ratio.call_open_interest += option.open_interest;
+                }
+            }
+        }
+
+        ratio
+    }
+
+    /// 成交量 P/C 比 (%)
+    pub fn volume_ratio(&self) -> Option<f64> {
+        if self.call_volume == 0 {
+            return None;
+        }
+        Some(self.put_volume as f64 / self.call_volume as f64 * 100.0)
+    }
+
+    /// 未平倉量 P/C 比 (%)
+    pub fn open_interest_ratio(&self) -> Option<f64> {
+        if self.call_open_interest == 0 {
+            return None;
+        }
+        Some(self.put_open_interest as f64 / self.call_open_interest as f64 * 100.0)
+    }
+}
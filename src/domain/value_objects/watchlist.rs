@@ -0,0 +1,19 @@
+use crate::domain::value_objects::comma_list::parse_comma_list;
+use serde::{Deserialize, Serialize};
+
+/// 自選股清單，以股票代碼表示
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Watchlist {
+    pub codes: Vec<String>,
+}
+
+impl Watchlist {
+    /// 從逗號分隔的字串解析自選股清單，例如 "2330,2317,0050"
+    pub fn parse(text: &str) -> Self {
+        Self { codes: parse_comma_list(text) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+}
@@ -1,3 +1,24 @@
 pub mod database;
 pub mod postgres_stock_repository;
 pub mod postgres_stock_price_repository;
+pub mod postgres_intraday_quote_repository;
+pub mod postgres_intraday_bar_repository;
+pub mod postgres_index_level_repository;
+pub mod postgres_derivatives_repository;
+pub mod postgres_market_surveillance_repository;
+pub mod postgres_material_announcement_repository;
+pub mod postgres_foreign_ownership_repository;
+pub mod postgres_day_trading_repository;
+pub mod postgres_off_session_trading_repository;
+pub mod postgres_block_trade_repository;
+pub mod postgres_insider_holding_repository;
+pub mod postgres_treasury_buyback_repository;
+pub mod postgres_public_subscription_repository;
+pub mod postgres_etf_repository;
+pub mod postgres_convertible_bond_repository;
+pub mod postgres_shares_outstanding_repository;
+pub mod postgres_price_discrepancy_repository;
+pub mod postgres_quarantined_price_repository;
+pub mod postgres_price_gap_repository;
+pub mod postgres_isin_listing_repository;
+pub mod postgres_universe_change_repository;
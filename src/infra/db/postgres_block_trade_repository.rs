@@ -0,0 +1,103 @@
+use crate::domain::models::{BlockTrade, BlockTradeType};
+use crate::domain::repositories::BlockTradeRepository;
+use crate::domain::value_objects::Result;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresBlockTradeRepository {
+    pool: PgPool,
+}
+
+impl PostgresBlockTradeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> Result<BlockTrade> {
+        let trade_type: String = r.get("trade_type");
+        let price: String = r.get("price");
+        let volume: String = r.get("volume");
+        let turnover: String = r.get("turnover");
+
+        Ok(BlockTrade {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            stock_name: r.get("stock_name"),
+            date: r.get("date"),
+            trade_type: BlockTradeType::parse(&trade_type)
+                .ok_or_else(|| anyhow!("未知的鉅額交易類型: {}", trade_type))?,
+            price: BigDecimal::from_str(&price).unwrap_or_else(|_| BigDecimal::from(0)),
+            volume: u64::from_str(&volume).unwrap_or(0),
+            turnover: u64::from_str(&turnover).unwrap_or(0),
+        })
+    }
+}
+
+#[async_trait]
+impl BlockTradeRepository for PostgresBlockTradeRepository {
+    async fn replace_by_date(&self, date: Date, trades: &[BlockTrade]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM block_trades WHERE date = $1")
+            .bind(date)
+            .execute(&mut *tx)
+            .await?;
+
+        for trade in trades {
+            sqlx::query(
+                r#"
+                INSERT INTO block_trades (
+                    id, stock_code, stock_name, date, trade_type, price, volume, turnover
+                ) VALUES (
+                    $1, $2, $3, $4, $5, $6::numeric, $7::numeric, $8::numeric
+                )
+                "#,
+            )
+            .bind(trade.id)
+            .bind(&trade.stock_code)
+            .bind(&trade.stock_name)
+            .bind(trade.date)
+            .bind(trade.trade_type.as_str())
+            .bind(trade.price.to_string())
+            .bind(trade.volume.to_string())
+            .bind(trade.turnover.to_string())
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_stock_code(
+        &self,
+        stock_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<BlockTrade>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, stock_name, date, trade_type,
+                price::text as price, volume::text as volume, turnover::text as turnover
+            FROM block_trades
+            WHERE stock_code = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC, turnover DESC
+            "#,
+        )
+        .bind(stock_code)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(Self::map_row).collect()
+    }
+}
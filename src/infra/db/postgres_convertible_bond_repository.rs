@@ -0,0 +1,162 @@
+use crate::domain::models::{ConvertibleBond, ConvertibleBondQuote};
+use crate::domain::repositories::ConvertibleBondRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresConvertibleBondRepository {
+    pool: PgPool,
+}
+
+impl PostgresConvertibleBondRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_bond_row(r: &PgRow) -> ConvertibleBond {
+        let conversion_price: String = r.get("conversion_price");
+        let outstanding_amount: String = r.get("outstanding_amount");
+
+        ConvertibleBond {
+            id: r.get("id"),
+            bond_code: r.get("bond_code"),
+            bond_name: r.get("bond_name"),
+            underlying_code: r.get("underlying_code"),
+            conversion_price: BigDecimal::from_str(&conversion_price).unwrap_or_else(|_| BigDecimal::from(0)),
+            outstanding_amount: u64::from_str(&outstanding_amount).unwrap_or(0),
+            issue_date: r.get("issue_date"),
+            maturity_date: r.get("maturity_date"),
+        }
+    }
+
+    fn map_quote_row(r: &PgRow) -> ConvertibleBondQuote {
+        let close: Option<String> = r.get("close");
+        let volume: String = r.get("volume");
+        let underlying_close: Option<String> = r.get("underlying_close");
+        let conversion_premium: Option<String> = r.get("conversion_premium");
+
+        ConvertibleBondQuote {
+            id: r.get("id"),
+            bond_code: r.get("bond_code"),
+            date: r.get("date"),
+            close: close.and_then(|v| BigDecimal::from_str(&v).ok()),
+            volume: u64::from_str(&volume).unwrap_or(0),
+            underlying_close: underlying_close.and_then(|v| BigDecimal::from_str(&v).ok()),
+            conversion_premium: conversion_premium.and_then(|v| BigDecimal::from_str(&v).ok()),
+        }
+    }
+}
+
+#[async_trait]
+impl ConvertibleBondRepository for PostgresConvertibleBondRepository {
+    async fn save_bond(&self, bond: &ConvertibleBond) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO convertible_bonds (
+                id, bond_code, bond_name, underlying_code, stock_id,
+                conversion_price, outstanding_amount, issue_date, maturity_date
+            ) VALUES (
+                $1, $2, $3, $4, (SELECT id FROM stocks WHERE code = $4),
+                $5::numeric, $6::numeric, $7, $8
+            )
+            ON CONFLICT (bond_code)
+            DO UPDATE SET
+                bond_name = $3,
+                underlying_code = $4,
+                stock_id = (SELECT id FROM stocks WHERE code = $4),
+                conversion_price = $5::numeric,
+                outstanding_amount = $6::numeric,
+                issue_date = COALESCE($7, convertible_bonds.issue_date),
+                maturity_date = COALESCE($8, convertible_bonds.maturity_date),
+                updated_at = NOW()
+            "#,
+        )
+        .bind(bond.id)
+        .bind(&bond.bond_code)
+        .bind(&bond.bond_name)
+        .bind(&bond.underlying_code)
+        .bind(bond.conversion_price.to_string())
+        .bind(bond.outstanding_amount.to_string())
+        .bind(bond.issue_date)
+        .bind(bond.maturity_date)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn save_quote(&self, quote: &ConvertibleBondQuote) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO convertible_bond_quotes (
+                id, bond_code, date, close, volume, underlying_close, conversion_premium
+            ) VALUES (
+                $1, $2, $3, $4::numeric, $5::numeric, $6::numeric, $7::numeric
+            )
+            ON CONFLICT (bond_code, date)
+            DO UPDATE SET
+                close = $4::numeric,
+                volume = $5::numeric,
+                underlying_close = $6::numeric,
+                conversion_premium = $7::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(quote.id)
+        .bind(&quote.bond_code)
+        .bind(quote.date)
+        .bind(quote.close.as_ref().map(|v| v.to_string()))
+        .bind(quote.volume.to_string())
+        .bind(quote.underlying_close.as_ref().map(|v| v.to_string()))
+        .bind(quote.conversion_premium.as_ref().map(|v| v.to_string()))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_outstanding_by_underlying(&self, stock_code: &str) -> Result<Vec<ConvertibleBond>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, bond_code, bond_name, underlying_code,
+                conversion_price::text as conversion_price,
+                outstanding_amount::text as outstanding_amount,
+                issue_date, maturity_date
+            FROM convertible_bonds
+            WHERE underlying_code = $1
+                AND outstanding_amount > 0
+                AND (maturity_date IS NULL OR maturity_date >= CURRENT_DATE)
+            ORDER BY bond_code ASC
+            "#,
+        )
+        .bind(stock_code)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_bond_row).collect())
+    }
+
+    async fn find_latest_quote(&self, bond_code: &str) -> Result<Option<ConvertibleBondQuote>> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                id, bond_code, date, close::text as close, volume::text as volume,
+                underlying_close::text as underlying_close,
+                conversion_premium::text as conversion_premium
+            FROM convertible_bond_quotes
+            WHERE bond_code = $1
+            ORDER BY date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(bond_code)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(Self::map_quote_row))
+    }
+}
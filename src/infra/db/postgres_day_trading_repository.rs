@@ -0,0 +1,50 @@
+use crate::domain::models::DayTradingStat;
+use crate::domain::repositories::DayTradingRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+pub struct PostgresDayTradingRepository {
+    pool: PgPool,
+}
+
+impl PostgresDayTradingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DayTradingRepository for PostgresDayTradingRepository {
+    async fn save(&self, stat: &DayTradingStat) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO day_trading_stats (
+                id, stock_code, stock_name, date, volume, buy_amount, sell_amount, sell_first_suspended
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric, $8
+            )
+            ON CONFLICT (stock_code, date)
+            DO UPDATE SET
+                stock_name = $3,
+                volume = $5::numeric,
+                buy_amount = $6::numeric,
+                sell_amount = $7::numeric,
+                sell_first_suspended = $8,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(stat.id)
+        .bind(&stat.stock_code)
+        .bind(&stat.stock_name)
+        .bind(stat.date)
+        .bind(stat.volume.to_string())
+        .bind(stat.buy_amount.to_string())
+        .bind(stat.sell_amount.to_string())
+        .bind(stat.sell_first_suspended)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,294 @@
+use crate::domain::models::{
+    FuturesDailyPrice, InstitutionType, InstitutionalOpenInterest, OptionType, OptionsDailyPrice,
+};
+use crate::domain::repositories::DerivativesRepository;
+use crate::domain::value_objects::Result;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresDerivativesRepository {
+    pool: PgPool,
+}
+
+impl PostgresDerivativesRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn optional_decimal(r: &PgRow, column: &str) -> Option<BigDecimal> {
+        let value: Option<String> = r.get(column);
+        value.and_then(|v| BigDecimal::from_str(&v).ok())
+    }
+
+    fn map_futures_row(r: &PgRow) -> FuturesDailyPrice {
+        let volume: String = r.get("volume");
+        let open_interest: String = r.get("open_interest");
+
+        FuturesDailyPrice {
+            id: r.get("id"),
+            contract_code: r.get("contract_code"),
+            delivery_month: r.get("delivery_month"),
+            date: r.get("date"),
+            open: Self::optional_decimal(r, "open"),
+            high: Self::optional_decimal(r, "high"),
+            low: Self::optional_decimal(r, "low"),
+            close: Self::optional_decimal(r, "close"),
+            settlement_price: Self::optional_decimal(r, "settlement_price"),
+            volume: u64::from_str(&volume).unwrap_or(0),
+            open_interest: u64::from_str(&open_interest).unwrap_or(0),
+        }
+    }
+
+    fn map_options_row(r: &PgRow) -> Result<OptionsDailyPrice> {
+        let option_type: String = r.get("option_type");
+        let strike_price: String = r.get("strike_price");
+        let volume: String = r.get("volume");
+        let open_interest: String = r.get("open_interest");
+
+        Ok(OptionsDailyPrice {
+            id: r.get("id"),
+            contract_code: r.get("contract_code"),
+            delivery_month: r.get("delivery_month"),
+            strike_price: BigDecimal::from_str(&strike_price).unwrap_or_else(|_| BigDecimal::from(0)),
+            option_type: OptionType::parse(&option_type)
+                .ok_or_else(|| anyhow!("未知的買賣權別: {}", option_type))?,
+            date: r.get("date"),
+            open: Self::optional_decimal(r, "open"),
+            high: Self::optional_decimal(r, "high"),
+            low: Self::optional_decimal(r, "low"),
+            close: Self::optional_decimal(r, "close"),
+            settlement_price: Self::optional_decimal(r, "settlement_price"),
+            volume: u64::from_str(&volume).unwrap_or(0),
+            open_interest: u64::from_str(&open_interest).unwrap_or(0),
+        })
+    }
+
+    fn map_institutional_row(r: &PgRow) -> Result<InstitutionalOpenInterest> {
+        let institution_type: String = r.get("institution_type");
+
+        Ok(InstitutionalOpenInterest {
+            id: r.get("id"),
+            contract_code: r.get("contract_code"),
+            date: r.get("date"),
+            institution_type: InstitutionType::parse(&institution_type)
+                .ok_or_else(|| anyhow!("未知的法人身份別: {}", institution_type))?,
+            long_open_interest: r.get("long_open_interest"),
+            short_open_interest: r.get("short_open_interest"),
+            net_open_interest: r.get("net_open_interest"),
+            long_value: r.get("long_value"),
+            short_value: r.get("short_value"),
+            net_value: r.get("net_value"),
+        })
+    }
+}
+
+#[async_trait]
+impl DerivativesRepository for PostgresDerivativesRepository {
+    async fn save_futures_price(&self, price: &FuturesDailyPrice) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO futures_daily_prices (
+                id, contract_code, delivery_month, date, open, high, low, close,
+                settlement_price, volume, open_interest
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric, $8::numeric,
+                $9::numeric, $10::numeric, $11::numeric
+            )
+            ON CONFLICT (contract_code, delivery_month, date)
+            DO UPDATE SET
+                open = $5::numeric,
+                high = $6::numeric,
+                low = $7::numeric,
+                close = $8::numeric,
+                settlement_price = $9::numeric,
+                volume = $10::numeric,
+                open_interest = $11::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(price.id)
+        .bind(&price.contract_code)
+        .bind(&price.delivery_month)
+        .bind(price.date)
+        .bind(price.open.as_ref().map(|v| v.to_string()))
+        .bind(price.high.as_ref().map(|v| v.to_string()))
+        .bind(price.low.as_ref().map(|v| v.to_string()))
+        .bind(price.close.as_ref().map(|v| v.to_string()))
+        .bind(price.settlement_price.as_ref().map(|v| v.to_string()))
+        .bind(price.volume.to_string())
+        .bind(price.open_interest.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn save_options_price(&self, price: &OptionsDailyPrice) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO options_daily_prices (
+                id, contract_code, delivery_month, strike_price, option_type, date,
+                open, high, low, close, settlement_price, volume, open_interest
+            ) VALUES (
+                $1, $2, $3, $4::numeric, $5, $6,
+                $7::numeric, $8::numeric, $9::numeric, $10::numeric, $11::numeric, $12::numeric, $13::numeric
+            )
+            ON CONFLICT (contract_code, delivery_month, strike_price, option_type, date)
+            DO UPDATE SET
+                open = $7::numeric,
+                high = $8::numeric,
+                low = $9::numeric,
+                close = $10::numeric,
+                settlement_price = $11::numeric,
+                volume = $12::numeric,
+                open_interest = $13::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(price.id)
+        .bind(&price.contract_code)
+        .bind(&price.delivery_month)
+        .bind(price.strike_price.to_string())
+        .bind(price.option_type.as_str())
+        .bind(price.date)
+        .bind(price.open.as_ref().map(|v| v.to_string()))
+        .bind(price.high.as_ref().map(|v| v.to_string()))
+        .bind(price.low.as_ref().map(|v| v.to_string()))
+        .bind(price.close.as_ref().map(|v| v.to_string()))
+        .bind(price.settlement_price.as_ref().map(|v| v.to_string()))
+        .bind(price.volume.to_string())
+        .bind(price.open_interest.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn save_institutional_open_interest(&self, position: &InstitutionalOpenInterest) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO futures_institutional_open_interest (
+                id, contract_code, date, institution_type,
+                long_open_interest, short_open_interest, net_open_interest,
+                long_value, short_value, net_value
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10
+            )
+            ON CONFLICT (contract_code, date, institution_type)
+            DO UPDATE SET
+                long_open_interest = $5,
+                short_open_interest = $6,
+                net_open_interest = $7,
+                long_value = $8,
+                short_value = $9,
+                net_value = $10,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(position.id)
+        .bind(&position.contract_code)
+        .bind(position.date)
+        .bind(position.institution_type.as_str())
+        .bind(position.long_open_interest)
+        .bind(position.short_open_interest)
+        .bind(position.net_open_interest)
+        .bind(position.long_value)
+        .bind(position.short_value)
+        .bind(position.net_value)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_futures_prices(
+        &self,
+        contract_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<FuturesDailyPrice>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, contract_code, delivery_month, date,
+                open::text as open, high::text as high, low::text as low, close::text as close,
+                settlement_price::text as settlement_price,
+                volume::text as volume, open_interest::text as open_interest
+            FROM futures_daily_prices
+            WHERE contract_code = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC, delivery_month ASC
+            "#,
+        )
+        .bind(contract_code)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_futures_row).collect())
+    }
+
+    async fn find_options_prices(
+        &self,
+        contract_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<OptionsDailyPrice>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, contract_code, delivery_month, strike_price::text as strike_price, option_type, date,
+                open::text as open, high::text as high, low::text as low, close::text as close,
+                settlement_price::text as settlement_price,
+                volume::text as volume, open_interest::text as open_interest
+            FROM options_daily_prices
+            WHERE contract_code = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC, delivery_month ASC, strike_price ASC
+            "#,
+        )
+        .bind(contract_code)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(Self::map_options_row).collect()
+    }
+
+    async fn find_institutional_open_interest(
+        &self,
+        contract_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<InstitutionalOpenInterest>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, contract_code, date, institution_type,
+                long_open_interest, short_open_interest, net_open_interest,
+                long_value, short_value, net_value
+            FROM futures_institutional_open_interest
+            WHERE contract_code = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC, institution_type ASC
+            "#,
+        )
+        .bind(contract_code)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(Self::map_institutional_row).collect()
+    }
+}
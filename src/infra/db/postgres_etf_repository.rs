@@ -0,0 +1,187 @@
+use crate::domain::models::{EtfConstituent, EtfNav};
+use crate::domain::repositories::EtfRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresEtfRepository {
+    pool: PgPool,
+}
+
+impl PostgresEtfRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_nav_row(r: &PgRow) -> EtfNav {
+        let nav: String = r.get("nav");
+        let close: Option<String> = r.get("close");
+        let issued_units: Option<String> = r.get("issued_units");
+
+        EtfNav {
+            id: r.get("id"),
+            etf_code: r.get("etf_code"),
+            etf_name: r.get("etf_name"),
+            date: r.get("date"),
+            nav: BigDecimal::from_str(&nav).unwrap_or_else(|_| BigDecimal::from(0)),
+            close: close.and_then(|v| BigDecimal::from_str(&v).ok()),
+            issued_units: issued_units.and_then(|v| u64::from_str(&v).ok()),
+        }
+    }
+
+    fn map_constituent_row(r: &PgRow) -> EtfConstituent {
+        let weight: String = r.get("weight");
+        let shares: Option<String> = r.get("shares");
+
+        EtfConstituent {
+            id: r.get("id"),
+            etf_code: r.get("etf_code"),
+            date: r.get("date"),
+            stock_code: r.get("stock_code"),
+            stock_name: r.get("stock_name"),
+            weight: BigDecimal::from_str(&weight).unwrap_or_else(|_| BigDecimal::from(0)),
+            shares: shares.and_then(|v| u64::from_str(&v).ok()),
+        }
+    }
+}
+
+#[async_trait]
+impl EtfRepository for PostgresEtfRepository {
+    async fn save_nav(&self, nav: &EtfNav) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO etf_navs (
+                id, etf_code, etf_name, date, nav, close, issued_units
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric
+            )
+            ON CONFLICT (etf_code, date)
+            DO UPDATE SET
+                etf_name = $3,
+                nav = $5::numeric,
+                close = $6::numeric,
+                issued_units = $7::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(nav.id)
+        .bind(&nav.etf_code)
+        .bind(&nav.etf_name)
+        .bind(nav.date)
+        .bind(nav.nav.to_string())
+        .bind(nav.close.as_ref().map(|v| v.to_string()))
+        .bind(nav.issued_units.map(|v| v.to_string()))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_navs(
+        &self,
+        etf_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<EtfNav>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, etf_code, etf_name, date, nav::text as nav,
+                close::text as close, issued_units::text as issued_units
+            FROM etf_navs
+            WHERE etf_code = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC
+            "#,
+        )
+        .bind(etf_code)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_nav_row).collect())
+    }
+
+    async fn replace_constituents(&self, etf_code: &str, date: Date, constituents: &[EtfConstituent]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM etf_constituents WHERE etf_code = $1 AND date = $2")
+            .bind(etf_code)
+            .bind(date)
+            .execute(&mut *tx)
+            .await?;
+
+        for constituent in constituents {
+            sqlx::query(
+                r#"
+                INSERT INTO etf_constituents (
+                    id, etf_code, date, stock_code, stock_name, weight, shares
+                ) VALUES (
+                    $1, $2, $3, $4, $5, $6::numeric, $7::numeric
+                )
+                ON CONFLICT (etf_code, date, stock_code) DO NOTHING
+                "#,
+            )
+            .bind(constituent.id)
+            .bind(&constituent.etf_code)
+            .bind(constituent.date)
+            .bind(&constituent.stock_code)
+            .bind(&constituent.stock_name)
+            .bind(constituent.weight.to_string())
+            .bind(constituent.shares.map(|v| v.to_string()))
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_latest_constituents(&self, etf_code: &str) -> Result<Vec<EtfConstituent>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, etf_code, date, stock_code, stock_name,
+                weight::text as weight, shares::text as shares
+            FROM etf_constituents
+            WHERE etf_code = $1
+                AND date = (SELECT MAX(date) FROM etf_constituents WHERE etf_code = $1)
+            ORDER BY etf_constituents.weight DESC
+            "#,
+        )
+        .bind(etf_code)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_constituent_row).collect())
+    }
+
+    async fn find_latest_holders(&self, stock_code: &str) -> Result<Vec<EtfConstituent>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                c.id, c.etf_code, c.date, c.stock_code, c.stock_name,
+                c.weight::text as weight, c.shares::text as shares
+            FROM etf_constituents c
+            JOIN (
+                SELECT etf_code, MAX(date) as date
+                FROM etf_constituents
+                GROUP BY etf_code
+            ) latest ON latest.etf_code = c.etf_code AND latest.date = c.date
+            WHERE c.stock_code = $1
+            ORDER BY c.weight DESC
+            "#,
+        )
+        .bind(stock_code)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_constituent_row).collect())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::domain::models::ForeignOwnership;
+use crate::domain::repositories::ForeignOwnershipRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresForeignOwnershipRepository {
+    pool: PgPool,
+}
+
+impl PostgresForeignOwnershipRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> ForeignOwnership {
+        let issued_shares: String = r.get("issued_shares");
+        let available_shares: String = r.get("available_shares");
+        let foreign_held_shares: String = r.get("foreign_held_shares");
+        let foreign_held_ratio: String = r.get("foreign_held_ratio");
+        let foreign_limit_ratio: String = r.get("foreign_limit_ratio");
+
+        ForeignOwnership {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            stock_name: r.get("stock_name"),
+            date: r.get("date"),
+            issued_shares: u64::from_str(&issued_shares).unwrap_or(0),
+            available_shares: u64::from_str(&available_shares).unwrap_or(0),
+            foreign_held_shares: u64::from_str(&foreign_held_shares).unwrap_or(0),
+            foreign_held_ratio: BigDecimal::from_str(&foreign_held_ratio).unwrap_or_else(|_| BigDecimal::from(0)),
+            foreign_limit_ratio: BigDecimal::from_str(&foreign_limit_ratio).unwrap_or_else(|_| BigDecimal::from(0)),
+        }
+    }
+}
+
+#[async_trait]
+impl ForeignOwnershipRepository for PostgresForeignOwnershipRepository {
+    async fn save(&self, ownership: &ForeignOwnership) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO foreign_ownership (
+                id, stock_code, stock_name, date, issued_shares, available_shares,
+                foreign_held_shares, foreign_held_ratio, foreign_limit_ratio
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric, $8::numeric, $9::numeric
+            )
+            ON CONFLICT (stock_code, date)
+            DO UPDATE SET
+                stock_name = $3,
+                issued_shares = $5::numeric,
+                available_shares = $6::numeric,
+                foreign_held_shares = $7::numeric,
+                foreign_held_ratio = $8::numeric,
+                foreign_limit_ratio = $9::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(ownership.id)
+        .bind(&ownership.stock_code)
+        .bind(&ownership.stock_name)
+        .bind(ownership.date)
+        .bind(ownership.issued_shares.to_string())
+        .bind(ownership.available_shares.to_string())
+        .bind(ownership.foreign_held_shares.to_string())
+        .bind(ownership.foreign_held_ratio.to_string())
+        .bind(ownership.foreign_limit_ratio.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_stock_code_and_date_range(
+        &self,
+        stock_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<ForeignOwnership>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, stock_name, date,
+                issued_shares::text as issued_shares, available_shares::text as available_shares,
+                foreign_held_shares::text as foreign_held_shares,
+                foreign_held_ratio::text as foreign_held_ratio, foreign_limit_ratio::text as foreign_limit_ratio
+            FROM foreign_ownership
+            WHERE stock_code = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC
+            "#,
+        )
+        .bind(stock_code)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
+
+    async fn find_latest(&self) -> Result<Vec<ForeignOwnership>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT ON (stock_code)
+                id, stock_code, stock_name, date,
+                issued_shares::text as issued_shares, available_shares::text as available_shares,
+                foreign_held_shares::text as foreign_held_shares,
+                foreign_held_ratio::text as foreign_held_ratio, foreign_limit_ratio::text as foreign_limit_ratio
+            FROM foreign_ownership
+            ORDER BY stock_code, date DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
+
+    async fn find_latest_by_stock_code(&self, stock_code: &str) -> Result<Option<ForeignOwnership>> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, stock_name, date,
+                issued_shares::text as issued_shares, available_shares::text as available_shares,
+                foreign_held_shares::text as foreign_held_shares,
+                foreign_held_ratio::text as foreign_held_ratio, foreign_limit_ratio::text as foreign_limit_ratio
+            FROM foreign_ownership
+            WHERE stock_code = $1
+            ORDER BY date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(stock_code)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(Self::map_row))
+    }
+}
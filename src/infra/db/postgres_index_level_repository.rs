@@ -0,0 +1,109 @@
+use crate::domain::models::IndexLevel;
+use crate::domain::repositories::IndexLevelRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use time::Date;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresIndexLevelRepository {
+    pool: PgPool,
+}
+
+impl PostgresIndexLevelRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IndexLevelRepository for PostgresIndexLevelRepository {
+    async fn save(&self, index_level: &IndexLevel) -> Result<()> {
+        // 只有收盤點位的來源不應覆蓋已存在的開高低
+        sqlx::query(
+            r#"
+            INSERT INTO index_levels (
+                id, index_code, index_name, date, open, high, low, close, change, change_percent
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric, $8::numeric, $9::numeric, $10::numeric
+            )
+            ON CONFLICT (index_code, date)
+            DO UPDATE SET
+                index_name = $3,
+                open = COALESCE($5::numeric, index_levels.open),
+                high = COALESCE($6::numeric, index_levels.high),
+                low = COALESCE($7::numeric, index_levels.low),
+                close = $8::numeric,
+                change = $9::numeric,
+                change_percent = $10::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(index_level.id)
+        .bind(&index_level.index_code)
+        .bind(&index_level.index_name)
+        .bind(index_level.date)
+        .bind(index_level.open.as_ref().map(|v| v.to_string()))
+        .bind(index_level.high.as_ref().map(|v| v.to_string()))
+        .bind(index_level.low.as_ref().map(|v| v.to_string()))
+        .bind(index_level.close.to_string())
+        .bind(index_level.change.to_string())
+        .bind(index_level.change_percent.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_index_code_and_date_range(
+        &self,
+        index_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<IndexLevel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, index_code, index_name, date,
+                open::text as open, high::text as high, low::text as low, close::text as close,
+                change::text as change, change_percent::text as change_percent
+            FROM index_levels
+            WHERE index_code = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC
+            "#,
+        )
+        .bind(index_code)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut index_levels = Vec::new();
+        for r in rows {
+            let open: Option<String> = r.get("open");
+            let high: Option<String> = r.get("high");
+            let low: Option<String> = r.get("low");
+            let close: String = r.get("close");
+            let change: String = r.get("change");
+            let change_percent: String = r.get("change_percent");
+
+            index_levels.push(IndexLevel {
+                id: r.get("id"),
+                index_code: r.get("index_code"),
+                index_name: r.get("index_name"),
+                date: r.get("date"),
+                open: open.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
+                high: high.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
+                low: low.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
+                close: BigDecimal::from_str(&close).unwrap_or_else(|_| BigDecimal::from(0)),
+                change: BigDecimal::from_str(&change).unwrap_or_else(|_| BigDecimal::from(0)),
+                change_percent: BigDecimal::from_str(&change_percent).unwrap_or_else(|_| BigDecimal::from(0)),
+            });
+        }
+
+        Ok(index_levels)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::domain::models::InsiderHolding;
+use crate::domain::repositories::InsiderHoldingRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+use std::str::FromStr;
+
+pub struct PostgresInsiderHoldingRepository {
+    pool: PgPool,
+}
+
+impl PostgresInsiderHoldingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> InsiderHolding {
+        let insider_shares: String = r.get("insider_shares");
+        let pledged_shares: String = r.get("pledged_shares");
+        let issued_shares: Option<String> = r.get("issued_shares");
+
+        InsiderHolding {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            company_name: r.get("company_name"),
+            period: r.get("period"),
+            insider_shares: u64::from_str(&insider_shares).unwrap_or(0),
+            pledged_shares: u64::from_str(&pledged_shares).unwrap_or(0),
+            issued_shares: issued_shares.and_then(|v| u64::from_str(&v).ok()),
+        }
+    }
+}
+
+#[async_trait]
+impl InsiderHoldingRepository for PostgresInsiderHoldingRepository {
+    async fn save(&self, holding: &InsiderHolding) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO insider_holdings (
+                id, stock_code, company_name, period, insider_shares, pledged_shares, issued_shares
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric
+            )
+            ON CONFLICT (stock_code, period)
+            DO UPDATE SET
+                company_name = $3,
+                insider_shares = $5::numeric,
+                pledged_shares = $6::numeric,
+                issued_shares = $7::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(holding.id)
+        .bind(&holding.stock_code)
+        .bind(&holding.company_name)
+        .bind(holding.period)
+        .bind(holding.insider_shares.to_string())
+        .bind(holding.pledged_shares.to_string())
+        .bind(holding.issued_shares.map(|v| v.to_string()))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_stock_code(&self, stock_code: &str) -> Result<Vec<InsiderHolding>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, company_name, period,
+                insider_shares::text as insider_shares,
+                pledged_shares::text as pledged_shares,
+                issued_shares::text as issued_shares
+            FROM insider_holdings
+            WHERE stock_code = $1
+            ORDER BY period DESC
+            "#,
+        )
+        .bind(stock_code)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
+
+    async fn find_previous(&self, stock_code: &str, period: Date) -> Result<Option<InsiderHolding>> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, company_name, period,
+                insider_shares::text as insider_shares,
+                pledged_shares::text as pledged_shares,
+                issued_shares::text as issued_shares
+            FROM insider_holdings
+            WHERE stock_code = $1 AND period < $2
+            ORDER BY period DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(stock_code)
+        .bind(period)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(Self::map_row))
+    }
+
+    async fn find_stock_codes_by_period(&self, period: Date) -> Result<Vec<String>> {
+        let rows = sqlx::query("SELECT stock_code FROM insider_holdings WHERE period = $1")
+            .bind(period)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.iter().map(|r| r.get("stock_code")).collect())
+    }
+
+    async fn is_period_completed(&self, period: Date) -> Result<bool> {
+        let row = sqlx::query("SELECT EXISTS (SELECT 1 FROM insider_holding_periods WHERE period = $1) as exists")
+            .bind(period)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(row.get("exists"))
+    }
+
+    async fn mark_period_completed(&self, period: Date) -> Result<()> {
+        sqlx::query("INSERT INTO insider_holding_periods (period) VALUES ($1) ON CONFLICT (period) DO NOTHING")
+            .bind(period)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
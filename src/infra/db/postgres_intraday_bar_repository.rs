@@ -0,0 +1,203 @@
+use crate::domain::models::IntradayBar;
+use crate::domain::repositories::IntradayBarRepository;
+use crate::domain::services::MarketClock;
+use crate::domain::value_objects::{BarInterval, Result};
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresIntradayBarRepository {
+    pool: PgPool,
+    // 已確認存在的月分區，避免每次寫入都執行 DDL
+    known_partitions: Mutex<HashSet<String>>,
+}
+
+impl PostgresIntradayBarRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            known_partitions: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 確保 K 棒所屬月份 (台北時間) 的分區存在
+    async fn ensure_partition(&self, start_time: OffsetDateTime) -> Result<()> {
+        let local = start_time.to_offset(MarketClock::offset());
+        let (year, month) = (local.year(), local.month() as u8);
+        let partition = format!("intraday_bars_{:04}{:02}", year, month);
+
+        if self.known_partitions.lock().unwrap().contains(&partition) {
+            return Ok(());
+        }
+
+        let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+        let query = format!(
+            r#"
+            CREATE TABLE IF NOT EXISTS {} PARTITION OF intraday_bars
+            FOR VALUES FROM ('{:04}-{:02}-01 00:00:00+08') TO ('{:04}-{:02}-01 00:00:00+08')
+            "#,
+            partition, year, month, next_year, next_month
+        );
+        sqlx::query(&query).execute(&self.pool).await?;
+
+        self.known_partitions.lock().unwrap().insert(partition);
+        Ok(())
+    }
+
+    fn map_row(r: &PgRow) -> Result<IntradayBar> {
+        let interval: String = r.get("bar_interval");
+        let open: String = r.get("open");
+        let high: String = r.get("high");
+        let low: String = r.get("low");
+        let close: String = r.get("close");
+        let volume: String = r.get("volume");
+
+        Ok(IntradayBar {
+            id: r.get("id"),
+            stock_id: r.get("stock_id"),
+            interval: BarInterval::from_str(&interval)?,
+            start_time: r.get("start_time"),
+            open: BigDecimal::from_str(&open).unwrap_or_else(|_| BigDecimal::from(0)),
+            high: BigDecimal::from_str(&high).unwrap_or_else(|_| BigDecimal::from(0)),
+            low: BigDecimal::from_str(&low).unwrap_or_else(|_| BigDecimal::from(0)),
+            close: BigDecimal::from_str(&close).unwrap_or_else(|_| BigDecimal::from(0)),
+            volume: u64::from_str(&volume).unwrap_or(0),
+        })
+    }
+}
+
+#[async_trait]
+impl IntradayBarRepository for PostgresIntradayBarRepository {
+    /// 盤中重新啟動後記憶體中的 K 棒只涵蓋重啟後的報價，因此與已保存的 K 棒合併而不直接覆蓋
+    async fn save(&self, bar: &IntradayBar) -> Result<()> {
+        self.ensure_partition(bar.start_time).await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO intraday_bars (
+                id, stock_id, bar_interval, start_time, open, high, low, close, volume
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric, $8::numeric, $9::numeric
+            )
+            ON CONFLICT (stock_id, bar_interval, start_time)
+            DO UPDATE SET
+                high = GREATEST(intraday_bars.high, EXCLUDED.high),
+                low = LEAST(intraday_bars.low, EXCLUDED.low),
+                close = EXCLUDED.close,
+                volume = GREATEST(intraday_bars.volume, EXCLUDED.volume),
+                updated_at = NOW()
+            "#,
+        )
+        .bind(bar.id)
+        .bind(bar.stock_id)
+        .bind(bar.interval.as_str())
+        .bind(bar.start_time)
+        .bind(bar.open.to_string())
+        .bind(bar.high.to_string())
+        .bind(bar.low.to_string())
+        .bind(bar.close.to_string())
+        .bind(bar.volume.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_stock_id_and_date_range(
+        &self,
+        stock_id: &Uuid,
+        interval: BarInterval,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<IntradayBar>> {
+        // 與日 K 相同的日期語意：起訖日皆包含在內，日期以台北時間計算
+        let start_time = start_date.map(|d| d.midnight().assume_offset(MarketClock::offset()));
+        let end_time = end_date
+            .and_then(|d| d.next_day())
+            .map(|d| d.midnight().assume_offset(MarketClock::offset()));
+
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_id, bar_interval, start_time,
+                open::text as open, high::text as high, low::text as low, close::text as close,
+                volume::text as volume
+            FROM intraday_bars
+            WHERE stock_id = $1 AND bar_interval = $2
+                AND ($3::timestamptz IS NULL OR start_time >= $3)
+                AND ($4::timestamptz IS NULL OR start_time < $4)
+            ORDER BY start_time DESC
+            "#,
+        )
+        .bind(stock_id)
+        .bind(interval.as_str())
+        .bind(start_time)
+        .bind(end_time)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(Self::map_row).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Stock;
+    use time::format_description::well_known::Rfc3339;
+
+    /// 需要 DATABASE_URL 指向已遷移的資料庫，未設定時略過
+    async fn connect() -> Option<PgPool> {
+        let database_url = std::env::var("DATABASE_URL").ok()?;
+        PgPool::connect(&database_url).await.ok()
+    }
+
+    #[tokio::test]
+    async fn saving_a_narrower_bar_keeps_the_stored_range_and_volume() {
+        let Some(pool) = connect().await else {
+            return;
+        };
+        let stock = Stock::new(format!("T{}", &Uuid::new_v4().simple().to_string()[..8]), "測試".to_string());
+        sqlx::query("INSERT INTO stocks (id, code, name, last_updated) VALUES ($1, $2, $3, NOW())")
+            .bind(stock.id)
+            .bind(&stock.code)
+            .bind(&stock.name)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let repository = PostgresIntradayBarRepository::new(pool.clone());
+        let start_time = OffsetDateTime::parse("2026-10-16T10:00:00+08:00", &Rfc3339).unwrap();
+
+        let mut bar = IntradayBar::new(stock.id, BarInterval::SixtyMinutes, start_time, BigDecimal::from(100), 5_000);
+        bar.update(&BigDecimal::from(110), 0);
+        bar.update(&BigDecimal::from(95), 0);
+        repository.save(&bar).await.unwrap();
+
+        // 重新啟動後只看到區間內的部分報價
+        let restarted = IntradayBar::new(stock.id, BarInterval::SixtyMinutes, start_time, BigDecimal::from(102), 300);
+        repository.save(&restarted).await.unwrap();
+
+        let date = start_time.to_offset(MarketClock::offset()).date();
+        let bars = repository
+            .find_by_stock_id_and_date_range(&stock.id, BarInterval::SixtyMinutes, Some(date), Some(date))
+            .await
+            .unwrap();
+
+        sqlx::query("DELETE FROM intraday_bars WHERE stock_id = $1").bind(stock.id).execute(&pool).await.unwrap();
+        sqlx::query("DELETE FROM stocks WHERE id = $1").bind(stock.id).execute(&pool).await.unwrap();
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].open, BigDecimal::from(100));
+        assert_eq!(bars[0].high, BigDecimal::from(110));
+        assert_eq!(bars[0].low, BigDecimal::from(95));
+        assert_eq!(bars[0].close, BigDecimal::from(102));
+        assert_eq!(bars[0].volume, 5_000);
+    }
+}
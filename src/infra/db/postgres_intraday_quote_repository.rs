@@ -0,0 +1,51 @@
+use crate::domain::models::IntradayQuote;
+use crate::domain::repositories::IntradayQuoteRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::types::Json;
+
+pub struct PostgresIntradayQuoteRepository {
+    pool: PgPool,
+}
+
+impl PostgresIntradayQuoteRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IntradayQuoteRepository for PostgresIntradayQuoteRepository {
+    async fn save(&self, quote: &IntradayQuote) -> Result<()> {
+        // 同一時間點的快照只保留一筆，重複輪詢到相同報價時略過
+        sqlx::query(
+            r#"
+            INSERT INTO intraday_quotes (
+                id, stock_code, stock_name, quote_time, last_price, accumulated_volume,
+                open, high, low, prev_close, bids, asks
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric,
+                $7::numeric, $8::numeric, $9::numeric, $10::numeric, $11, $12
+            )
+            ON CONFLICT (stock_code, quote_time) DO NOTHING
+            "#,
+        )
+        .bind(quote.id)
+        .bind(&quote.stock_code)
+        .bind(&quote.stock_name)
+        .bind(quote.quote_time)
+        .bind(quote.last_price.as_ref().map(|v| v.to_string()))
+        .bind(quote.accumulated_volume.to_string())
+        .bind(quote.open.as_ref().map(|v| v.to_string()))
+        .bind(quote.high.as_ref().map(|v| v.to_string()))
+        .bind(quote.low.as_ref().map(|v| v.to_string()))
+        .bind(quote.prev_close.as_ref().map(|v| v.to_string()))
+        .bind(Json(&quote.bids))
+        .bind(Json(&quote.asks))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
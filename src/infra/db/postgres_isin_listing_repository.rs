@@ -0,0 +1,165 @@
+use crate::domain::models::{ListedSecurity, ListingSync};
+use crate::domain::repositories::IsinListingRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use sqlx::postgres::PgRow;
+
+pub struct PostgresIsinListingRepository {
+    pool: PgPool,
+}
+
+impl PostgresIsinListingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> ListedSecurity {
+        ListedSecurity {
+            code: r.get("code"),
+            name: r.get("name"),
+            listed_date: r.get("listed_date"),
+            market: r.get("market"),
+            industry: r.get("industry"),
+        }
+    }
+
+    async fn write_snapshot(tx: &mut Transaction<'_, Postgres>, listings: &[ListedSecurity]) -> Result<()> {
+        sqlx::query("DELETE FROM isin_listings")
+            .execute(&mut **tx)
+            .await?;
+
+        for listing in listings {
+            sqlx::query(
+                r#"
+                INSERT INTO isin_listings (code, name, listed_date, market, industry)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (code) DO UPDATE
+                SET name = EXCLUDED.name, listed_date = EXCLUDED.listed_date,
+                    market = EXCLUDED.market, industry = EXCLUDED.industry, updated_at = NOW()
+                "#,
+            )
+            .bind(&listing.code)
+            .bind(&listing.name)
+            .bind(listing.listed_date)
+            .bind(&listing.market)
+            .bind(&listing.industry)
+            .execute(&mut **tx)
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl IsinListingRepository for PostgresIsinListingRepository {
+    async fn find_all(&self) -> Result<Vec<ListedSecurity>> {
+        let rows = sqlx::query("SELECT code, name, listed_date, market, industry FROM isin_listings ORDER BY code")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
+
+    async fn replace_all(&self, listings: &[ListedSecurity]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        Self::write_snapshot(&mut tx, listings).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn apply_sync(&self, sync: &ListingSync) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        for stock in &sync.new_stocks {
+            sqlx::query(
+                r#"
+                INSERT INTO stocks (id, code, name, last_updated, status, listed_date, status_changed_on)
+                VALUES ($1, $2, $3, to_timestamp($4), $5, $6, $7)
+                ON CONFLICT (code) DO UPDATE
+                SET name = $3, last_updated = to_timestamp($4),
+                    listed_date = COALESCE($6, stocks.listed_date)
+                "#,
+            )
+            .bind(stock.id)
+            .bind(&stock.code)
+            .bind(&stock.name)
+            .bind(stock.last_updated.unix_timestamp() as f64)
+            .bind(stock.status.as_str())
+            .bind(stock.listed_date)
+            .bind(stock.status_changed_on)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for (stock_id, status) in &sync.status_changes {
+            sqlx::query(
+                r#"
+                UPDATE stocks
+                SET status = $2, status_changed_on = $3, updated_at = NOW()
+                WHERE id = $1 AND status <> $2
+                "#,
+            )
+            .bind(stock_id)
+            .bind(status.as_str())
+            .bind(sync.changed_on)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for change in &sync.name_changes {
+            sqlx::query("UPDATE stocks SET name = $2, updated_at = NOW() WHERE id = $1")
+                .bind(change.stock_id)
+                .bind(&change.new_name)
+                .execute(&mut *tx)
+                .await?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO stock_name_history (id, stock_id, previous_name, new_name, changed_on)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (stock_id, changed_on, new_name) DO NOTHING
+                "#,
+            )
+            .bind(change.id)
+            .bind(change.stock_id)
+            .bind(&change.previous_name)
+            .bind(&change.new_name)
+            .bind(change.changed_on)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for change in &sync.changes {
+            sqlx::query(
+                r#"
+                INSERT INTO universe_changes (
+                    id, stock_code, stock_name, change_type, previous_value, new_value, detected_on
+                ) VALUES (
+                    $1, $2, $3, $4, $5, $6, $7
+                )
+                ON CONFLICT (stock_code, change_type, detected_on) DO UPDATE
+                SET stock_name = EXCLUDED.stock_name,
+                    previous_value = EXCLUDED.previous_value,
+                    new_value = EXCLUDED.new_value
+                "#,
+            )
+            .bind(change.id)
+            .bind(&change.stock_code)
+            .bind(&change.stock_name)
+            .bind(change.change_type.as_str())
+            .bind(&change.previous_value)
+            .bind(&change.new_value)
+            .bind(change.detected_on)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Self::write_snapshot(&mut tx, &sync.listings).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
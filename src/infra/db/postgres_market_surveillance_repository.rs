@@ -0,0 +1,118 @@
+use crate::domain::models::{MarketSurveillanceEvent, SurveillanceType};
+use crate::domain::repositories::MarketSurveillanceRepository;
+use crate::domain::value_objects::Result;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+
+pub struct PostgresMarketSurveillanceRepository {
+    pool: PgPool,
+}
+
+impl PostgresMarketSurveillanceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> Result<MarketSurveillanceEvent> {
+        let event_type: String = r.get("event_type");
+
+        Ok(MarketSurveillanceEvent {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            stock_name: r.get("stock_name"),
+            market: r.get("market"),
+            event_type: SurveillanceType::parse(&event_type)
+                .ok_or_else(|| anyhow!("未知的監視公告類型: {}", event_type))?,
+            announced_date: r.get("announced_date"),
+            start_date: r.get("start_date"),
+            end_date: r.get("end_date"),
+            reason: r.get("reason"),
+            measure: r.get("measure"),
+        })
+    }
+}
+
+#[async_trait]
+impl MarketSurveillanceRepository for PostgresMarketSurveillanceRepository {
+    async fn save(&self, event: &MarketSurveillanceEvent) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO market_surveillance_events (
+                id, stock_code, stock_name, market, event_type,
+                announced_date, start_date, end_date, reason, measure
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10
+            )
+            ON CONFLICT (market, stock_code, event_type, start_date)
+            DO UPDATE SET
+                stock_name = $3,
+                announced_date = $6,
+                end_date = $8,
+                reason = $9,
+                measure = $10,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(event.id)
+        .bind(&event.stock_code)
+        .bind(&event.stock_name)
+        .bind(&event.market)
+        .bind(event.event_type.as_str())
+        .bind(event.announced_date)
+        .bind(event.start_date)
+        .bind(event.end_date)
+        .bind(&event.reason)
+        .bind(&event.measure)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_overlapping(
+        &self,
+        start_date: Date,
+        end_date: Date,
+        stock_codes: Option<&[String]>,
+    ) -> Result<Vec<MarketSurveillanceEvent>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, stock_name, market, event_type,
+                announced_date, start_date, end_date, reason, measure
+            FROM market_surveillance_events
+            WHERE start_date <= $2 AND end_date >= $1
+              AND ($3::text[] IS NULL OR stock_code = ANY($3))
+            ORDER BY start_date DESC, stock_code ASC
+            "#,
+        )
+        .bind(start_date)
+        .bind(end_date)
+        .bind(stock_codes)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    async fn find_by_stock_code(&self, stock_code: &str) -> Result<Vec<MarketSurveillanceEvent>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, stock_name, market, event_type,
+                announced_date, start_date, end_date, reason, measure
+            FROM market_surveillance_events
+            WHERE stock_code = $1
+            ORDER BY start_date DESC
+            "#,
+        )
+        .bind(stock_code)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(Self::map_row).collect()
+    }
+}
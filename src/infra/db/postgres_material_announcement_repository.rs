@@ -0,0 +1,84 @@
+use crate::domain::models::MaterialAnnouncement;
+use crate::domain::repositories::MaterialAnnouncementRepository;
+use crate::domain::services::MarketClock;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use time::Date;
+
+pub struct PostgresMaterialAnnouncementRepository {
+    pool: PgPool,
+}
+
+impl PostgresMaterialAnnouncementRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MaterialAnnouncementRepository for PostgresMaterialAnnouncementRepository {
+    async fn save(&self, announcement: &MaterialAnnouncement) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO material_announcements (
+                id, stock_code, company_name, announced_at, title, body
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6
+            )
+            ON CONFLICT (stock_code, announced_at, title) DO NOTHING
+            "#,
+        )
+        .bind(announcement.id)
+        .bind(&announcement.stock_code)
+        .bind(&announcement.company_name)
+        .bind(announcement.announced_at)
+        .bind(&announcement.title)
+        .bind(&announcement.body)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_by_date_range(
+        &self,
+        stock_code: Option<&str>,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<MaterialAnnouncement>> {
+        // 日期以台北時間計算，起訖日皆包含在內
+        let start_time = start_date.map(|d| d.midnight().assume_offset(MarketClock::offset()));
+        let end_time = end_date
+            .and_then(|d| d.next_day())
+            .map(|d| d.midnight().assume_offset(MarketClock::offset()));
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, stock_code, company_name, announced_at, title, body
+            FROM material_announcements
+            WHERE ($1::varchar IS NULL OR stock_code = $1)
+                AND ($2::timestamptz IS NULL OR announced_at >= $2)
+                AND ($3::timestamptz IS NULL OR announced_at < $3)
+            ORDER BY announced_at DESC
+            "#,
+        )
+        .bind(stock_code)
+        .bind(start_time)
+        .bind(end_time)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .iter()
+            .map(|r| MaterialAnnouncement {
+                id: r.get("id"),
+                stock_code: r.get("stock_code"),
+                company_name: r.get("company_name"),
+                announced_at: r.get("announced_at"),
+                title: r.get("title"),
+                body: r.get("body"),
+            })
+            .collect())
+    }
+}
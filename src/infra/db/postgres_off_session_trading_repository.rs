@@ -0,0 +1,204 @@
+use crate::domain::models::{AfterHoursTrade, OddLotQuote};
+use crate::domain::repositories::OffSessionTradingRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresOffSessionTradingRepository {
+    pool: PgPool,
+}
+
+impl PostgresOffSessionTradingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn optional_decimal(r: &PgRow, column: &str) -> Option<BigDecimal> {
+        let value: Option<String> = r.get(column);
+        value.and_then(|v| BigDecimal::from_str(&v).ok())
+    }
+
+    fn unsigned(r: &PgRow, column: &str) -> u64 {
+        let value: String = r.get(column);
+        u64::from_str(&value).unwrap_or(0)
+    }
+
+    fn map_odd_lot_row(r: &PgRow) -> OddLotQuote {
+        OddLotQuote {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            stock_name: r.get("stock_name"),
+            date: r.get("date"),
+            open: Self::optional_decimal(r, "open"),
+            high: Self::optional_decimal(r, "high"),
+            low: Self::optional_decimal(r, "low"),
+            close: Self::optional_decimal(r, "close"),
+            volume: Self::unsigned(r, "volume"),
+            transactions: Self::unsigned(r, "transactions"),
+            turnover: Self::unsigned(r, "turnover"),
+            last_bid_price: Self::optional_decimal(r, "last_bid_price"),
+            last_ask_price: Self::optional_decimal(r, "last_ask_price"),
+        }
+    }
+
+    fn map_after_hours_row(r: &PgRow) -> AfterHoursTrade {
+        AfterHoursTrade {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            stock_name: r.get("stock_name"),
+            date: r.get("date"),
+            price: Self::optional_decimal(r, "price"),
+            volume: Self::unsigned(r, "volume"),
+            transactions: Self::unsigned(r, "transactions"),
+            turnover: Self::unsigned(r, "turnover"),
+            last_bid_volume: Self::unsigned(r, "last_bid_volume"),
+            last_ask_volume: Self::unsigned(r, "last_ask_volume"),
+        }
+    }
+}
+
+#[async_trait]
+impl OffSessionTradingRepository for PostgresOffSessionTradingRepository {
+    async fn save_odd_lot_quote(&self, quote: &OddLotQuote) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO odd_lot_quotes (
+                id, stock_code, stock_name, date, open, high, low, close,
+                volume, transactions, turnover, last_bid_price, last_ask_price
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric, $8::numeric,
+                $9::numeric, $10::numeric, $11::numeric, $12::numeric, $13::numeric
+            )
+            ON CONFLICT (stock_code, date)
+            DO UPDATE SET
+                stock_name = $3,
+                open = $5::numeric,
+                high = $6::numeric,
+                low = $7::numeric,
+                close = $8::numeric,
+                volume = $9::numeric,
+                transactions = $10::numeric,
+                turnover = $11::numeric,
+                last_bid_price = $12::numeric,
+                last_ask_price = $13::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(quote.id)
+        .bind(&quote.stock_code)
+        .bind(&quote.stock_name)
+        .bind(quote.date)
+        .bind(quote.open.as_ref().map(|v| v.to_string()))
+        .bind(quote.high.as_ref().map(|v| v.to_string()))
+        .bind(quote.low.as_ref().map(|v| v.to_string()))
+        .bind(quote.close.as_ref().map(|v| v.to_string()))
+        .bind(quote.volume.to_string())
+        .bind(quote.transactions.to_string())
+        .bind(quote.turnover.to_string())
+        .bind(quote.last_bid_price.as_ref().map(|v| v.to_string()))
+        .bind(quote.last_ask_price.as_ref().map(|v| v.to_string()))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn save_after_hours_trade(&self, trade: &AfterHoursTrade) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO after_hours_trades (
+                id, stock_code, stock_name, date, price, volume, transactions, turnover,
+                last_bid_volume, last_ask_volume
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric, $8::numeric,
+                $9::numeric, $10::numeric
+            )
+            ON CONFLICT (stock_code, date)
+            DO UPDATE SET
+                stock_name = $3,
+                price = $5::numeric,
+                volume = $6::numeric,
+                transactions = $7::numeric,
+                turnover = $8::numeric,
+                last_bid_volume = $9::numeric,
+                last_ask_volume = $10::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(trade.id)
+        .bind(&trade.stock_code)
+        .bind(&trade.stock_name)
+        .bind(trade.date)
+        .bind(trade.price.as_ref().map(|v| v.to_string()))
+        .bind(trade.volume.to_string())
+        .bind(trade.transactions.to_string())
+        .bind(trade.turnover.to_string())
+        .bind(trade.last_bid_volume.to_string())
+        .bind(trade.last_ask_volume.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_odd_lot_quotes(
+        &self,
+        stock_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<OddLotQuote>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, stock_name, date,
+                open::text as open, high::text as high, low::text as low, close::text as close,
+                volume::text as volume, transactions::text as transactions, turnover::text as turnover,
+                last_bid_price::text as last_bid_price, last_ask_price::text as last_ask_price
+            FROM odd_lot_quotes
+            WHERE stock_code = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC
+            "#,
+        )
+        .bind(stock_code)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_odd_lot_row).collect())
+    }
+
+    async fn find_after_hours_trades(
+        &self,
+        stock_code: &str,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<AfterHoursTrade>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, stock_name, date, price::text as price,
+                volume::text as volume, transactions::text as transactions, turnover::text as turnover,
+                last_bid_volume::text as last_bid_volume, last_ask_volume::text as last_ask_volume
+            FROM after_hours_trades
+            WHERE stock_code = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC
+            "#,
+        )
+        .bind(stock_code)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_after_hours_row).collect())
+    }
+}
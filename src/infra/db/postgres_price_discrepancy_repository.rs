@@ -0,0 +1,112 @@
+use crate::domain::models::{PriceDiscrepancy, PriceSource};
+use crate::domain::repositories::PriceDiscrepancyRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresPriceDiscrepancyRepository {
+    pool: PgPool,
+}
+
+impl PostgresPriceDiscrepancyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> PriceDiscrepancy {
+        let primary_source: String = r.get("primary_source");
+        let primary_value: String = r.get("primary_value");
+        let secondary_source: String = r.get("secondary_source");
+        let secondary_value: String = r.get("secondary_value");
+        let diff_percent: String = r.get("diff_percent");
+
+        PriceDiscrepancy {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            date: r.get("date"),
+            field: r.get("field"),
+            primary_source: PriceSource::parse(&primary_source).unwrap_or_default(),
+            primary_value: BigDecimal::from_str(&primary_value).unwrap_or_else(|_| BigDecimal::from(0)),
+            secondary_source: PriceSource::parse(&secondary_source).unwrap_or_default(),
+            secondary_value: BigDecimal::from_str(&secondary_value).unwrap_or_else(|_| BigDecimal::from(0)),
+            diff_percent: BigDecimal::from_str(&diff_percent).unwrap_or_else(|_| BigDecimal::from(0)),
+        }
+    }
+}
+
+#[async_trait]
+impl PriceDiscrepancyRepository for PostgresPriceDiscrepancyRepository {
+    async fn replace(
+        &self,
+        stock_code: &str,
+        date: Date,
+        secondary_source: PriceSource,
+        discrepancies: &[PriceDiscrepancy],
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM price_discrepancies WHERE stock_code = $1 AND date = $2 AND secondary_source = $3")
+            .bind(stock_code)
+            .bind(date)
+            .bind(secondary_source.as_str())
+            .execute(&mut *tx)
+            .await?;
+
+        for discrepancy in discrepancies {
+            sqlx::query(
+                r#"
+                INSERT INTO price_discrepancies (
+                    id, stock_code, date, field, primary_source, primary_value,
+                    secondary_source, secondary_value, diff_percent
+                ) VALUES (
+                    $1, $2, $3, $4, $5, $6::numeric, $7, $8::numeric, $9::numeric
+                )
+                ON CONFLICT (stock_code, date, field, secondary_source) DO NOTHING
+                "#,
+            )
+            .bind(discrepancy.id)
+            .bind(&discrepancy.stock_code)
+            .bind(discrepancy.date)
+            .bind(&discrepancy.field)
+            .bind(discrepancy.primary_source.as_str())
+            .bind(discrepancy.primary_value.to_string())
+            .bind(discrepancy.secondary_source.as_str())
+            .bind(discrepancy.secondary_value.to_string())
+            .bind(discrepancy.diff_percent.to_string())
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_date_range(
+        &self,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<PriceDiscrepancy>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, date, field, primary_source, primary_value::text as primary_value,
+                secondary_source, secondary_value::text as secondary_value,
+                diff_percent::text as diff_percent
+            FROM price_discrepancies
+            WHERE ($1::date IS NULL OR date >= $1)
+                AND ($2::date IS NULL OR date <= $2)
+            ORDER BY date DESC, stock_code, field
+            "#,
+        )
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
+}
@@ -0,0 +1,125 @@
+use crate::domain::models::{PriceGap, PriceGapStatus};
+use crate::domain::repositories::PriceGapRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+use uuid::Uuid;
+
+pub struct PostgresPriceGapRepository {
+    pool: PgPool,
+}
+
+impl PostgresPriceGapRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> PriceGap {
+        let status: String = r.get("status");
+        let attempts: i32 = r.get("attempts");
+
+        PriceGap {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            date: r.get("date"),
+            status: PriceGapStatus::parse(&status).unwrap_or(PriceGapStatus::Missing),
+            attempts: attempts.max(0) as u32,
+        }
+    }
+}
+
+#[async_trait]
+impl PriceGapRepository for PostgresPriceGapRepository {
+    async fn record_missing(&self, stock_code: &str, dates: &[Date]) -> Result<()> {
+        for date in dates {
+            sqlx::query(
+                r#"
+                INSERT INTO price_gaps (id, stock_code, date, status)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (stock_code, date) DO NOTHING
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(stock_code)
+            .bind(date)
+            .bind(PriceGapStatus::Missing.as_str())
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn mark_repaired_where_present(&self) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE price_gaps g
+            SET status = $1, updated_at = NOW()
+            FROM stock_prices p
+            JOIN stocks s ON s.id = p.stock_id
+            WHERE s.code = g.stock_code AND p.date = g.date AND g.status <> $1
+            "#,
+        )
+        .bind(PriceGapStatus::Repaired.as_str())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_no_trade_dates(&self, stock_code: &str, start_date: Date, end_date: Date) -> Result<Vec<Date>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT date
+            FROM price_gaps
+            WHERE stock_code = $1 AND status = $2 AND date BETWEEN $3 AND $4
+            ORDER BY date
+            "#,
+        )
+        .bind(stock_code)
+        .bind(PriceGapStatus::NoTrade.as_str())
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(|r| r.get("date")).collect())
+    }
+
+    async fn find_open(&self, max_attempts: u32) -> Result<Vec<PriceGap>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, stock_code, date, status, attempts
+            FROM price_gaps
+            WHERE status IN ($1, $2) AND attempts < $3
+            ORDER BY stock_code, date
+            "#,
+        )
+        .bind(PriceGapStatus::Missing.as_str())
+        .bind(PriceGapStatus::Failed.as_str())
+        .bind(max_attempts as i32)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
+
+    async fn record_attempt(&self, stock_code: &str, date: Date, status: PriceGapStatus) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE price_gaps
+            SET status = $3, attempts = attempts + 1, last_attempt_at = NOW(), updated_at = NOW()
+            WHERE stock_code = $1 AND date = $2
+            "#,
+        )
+        .bind(stock_code)
+        .bind(date)
+        .bind(status.as_str())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use crate::domain::models::PublicSubscription;
+use crate::domain::repositories::PublicSubscriptionRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresPublicSubscriptionRepository {
+    pool: PgPool,
+}
+
+impl PostgresPublicSubscriptionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> PublicSubscription {
+        let offer_price: String = r.get("offer_price");
+        let shares_offered: String = r.get("shares_offered");
+        let shares_per_application: String = r.get("shares_per_application");
+        let win_rate: Option<String> = r.get("win_rate");
+
+        PublicSubscription {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            stock_name: r.get("stock_name"),
+            market: r.get("market"),
+            subscription_start: r.get("subscription_start"),
+            subscription_end: r.get("subscription_end"),
+            lottery_date: r.get("lottery_date"),
+            listing_date: r.get("listing_date"),
+            offer_price: BigDecimal::from_str(&offer_price).unwrap_or_else(|_| BigDecimal::from(0)),
+            shares_offered: u64::from_str(&shares_offered).unwrap_or(0),
+            shares_per_application: u64::from_str(&shares_per_application).unwrap_or(0),
+            win_rate: win_rate.and_then(|v| BigDecimal::from_str(&v).ok()),
+            is_cancelled: r.get("is_cancelled"),
+        }
+    }
+}
+
+#[async_trait]
+impl PublicSubscriptionRepository for PostgresPublicSubscriptionRepository {
+    async fn save(&self, subscription: &PublicSubscription) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO public_subscriptions (
+                id, stock_code, stock_name, market, subscription_start, subscription_end,
+                lottery_date, listing_date, offer_price, shares_offered,
+                shares_per_application, win_rate, is_cancelled
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9::numeric, $10::numeric, $11::numeric, $12::numeric, $13
+            )
+            ON CONFLICT (stock_code, lottery_date)
+            DO UPDATE SET
+                stock_name = $3,
+                market = $4,
+                subscription_start = $5,
+                subscription_end = $6,
+                listing_date = $8,
+                offer_price = $9::numeric,
+                shares_offered = $10::numeric,
+                shares_per_application = $11::numeric,
+                win_rate = $12::numeric,
+                is_cancelled = $13,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(subscription.id)
+        .bind(&subscription.stock_code)
+        .bind(&subscription.stock_name)
+        .bind(&subscription.market)
+        .bind(subscription.subscription_start)
+        .bind(subscription.subscription_end)
+        .bind(subscription.lottery_date)
+        .bind(subscription.listing_date)
+        .bind(subscription.offer_price.to_string())
+        .bind(subscription.shares_offered.to_string())
+        .bind(subscription.shares_per_application.to_string())
+        .bind(subscription.win_rate.as_ref().map(|v| v.to_string()))
+        .bind(subscription.is_cancelled)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_lottery_date_range(
+        &self,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<PublicSubscription>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, stock_name, market, subscription_start, subscription_end,
+                lottery_date, listing_date, offer_price::text as offer_price,
+                shares_offered::text as shares_offered,
+                shares_per_application::text as shares_per_application,
+                win_rate::text as win_rate, is_cancelled
+            FROM public_subscriptions
+            WHERE ($1::date IS NULL OR lottery_date >= $1)
+                AND ($2::date IS NULL OR lottery_date <= $2)
+            ORDER BY lottery_date ASC, stock_code ASC
+            "#,
+        )
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
+
+    async fn find_by_listing_date_range(&self, start_date: Date, end_date: Date) -> Result<Vec<PublicSubscription>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, stock_code, stock_name, market, subscription_start, subscription_end,
+                lottery_date, listing_date, offer_price::text as offer_price,
+                shares_offered::text as shares_offered,
+                shares_per_application::text as shares_per_application,
+                win_rate::text as win_rate, is_cancelled
+            FROM public_subscriptions
+            WHERE listing_date >= $1 AND listing_date <= $2
+            ORDER BY listing_date ASC
+            "#,
+        )
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
+}
@@ -0,0 +1,75 @@
+use crate::domain::models::QuarantinedPrice;
+use crate::domain::repositories::QuarantinedPriceRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use time::Date;
+use uuid::Uuid;
+
+pub struct PostgresQuarantinedPriceRepository {
+    pool: PgPool,
+}
+
+impl PostgresQuarantinedPriceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QuarantinedPriceRepository for PostgresQuarantinedPriceRepository {
+    async fn save(&self, price: &QuarantinedPrice) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO quarantined_prices (
+                id, stock_id, date, source, open, high, low, close, volume, turnover, reasons
+            ) VALUES (
+                $1, $2, $3, $4, $5::numeric, $6::numeric, $7::numeric, $8::numeric, $9::numeric, $10::numeric, $11
+            )
+            ON CONFLICT (stock_id, date, source)
+            DO UPDATE SET
+                open = $5::numeric,
+                high = $6::numeric,
+                low = $7::numeric,
+                close = $8::numeric,
+                volume = $9::numeric,
+                turnover = $10::numeric,
+                reasons = $11,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(price.id)
+        .bind(price.stock_id)
+        .bind(price.date)
+        .bind(price.source.as_str())
+        .bind(price.open.to_string())
+        .bind(price.high.to_string())
+        .bind(price.low.to_string())
+        .bind(price.close.to_string())
+        .bind(price.volume.to_string())
+        .bind(price.turnover.to_string())
+        .bind(price.reasons.join("、"))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_dates_by_stock_id(&self, stock_id: &Uuid, start_date: Date, end_date: Date) -> Result<Vec<Date>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT date
+            FROM quarantined_prices
+            WHERE stock_id = $1 AND date BETWEEN $2 AND $3
+            ORDER BY date
+            "#,
+        )
+        .bind(stock_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(|r| r.get("date")).collect())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::domain::models::SharesOutstanding;
+use crate::domain::repositories::SharesOutstandingRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+pub struct PostgresSharesOutstandingRepository {
+    pool: PgPool,
+}
+
+impl PostgresSharesOutstandingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SharesOutstandingRepository for PostgresSharesOutstandingRepository {
+    async fn record(&self, shares: &SharesOutstanding) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO stock_shares (id, stock_code, effective_date, issued_shares)
+            SELECT $1, $2, $3, $4::numeric
+            WHERE NOT EXISTS (
+                SELECT 1
+                FROM (
+                    SELECT issued_shares
+                    FROM stock_shares
+                    WHERE stock_code = $2 AND effective_date <= $3
+                    ORDER BY effective_date DESC
+                    LIMIT 1
+                ) previous
+                WHERE previous.issued_shares = $4::numeric
+            )
+            ON CONFLICT (stock_code, effective_date)
+            DO UPDATE SET
+                issued_shares = $4::numeric,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(shares.id)
+        .bind(&shares.stock_code)
+        .bind(shares.effective_date)
+        .bind(shares.issued_shares.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
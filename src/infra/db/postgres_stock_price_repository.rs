@@ -1,13 +1,49 @@
-use crate::domain::models::StockPrice;
+use crate::domain::models::{PriceSource, StockPrice};
 use crate::domain::repositories::StockPriceRepository;
-use crate::domain::value_objects::Result;
+use crate::domain::value_objects::{LimitStatus, Result};
 use async_trait::async_trait;
 use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
 use time::Date;
 use uuid::Uuid;
 use bigdecimal::BigDecimal;
 use std::str::FromStr;
 
+/// 查詢股價共用的欄位，另外帶出當沖量、當時的已發行股數及董監持股
+const SELECT_COLUMNS: &str = r#"
+    id, stock_id, date,
+    open::text as open, high::text as high, low::text as low, close::text as close,
+    volume::text as volume,
+    change::text as change, change_percent::text as change_percent,
+    turnover::text as turnover, transactions::text as transactions,
+    pe_ratio::text as pe_ratio, pb_ratio::text as pb_ratio, dividend_yield::text as dividend_yield,
+    market_cap::text as market_cap,
+    foreign_buy::text as foreign_buy, trust_buy::text as trust_buy, dealer_buy::text as dealer_buy,
+    limit_status, source,
+    (
+        SELECT d.volume::text
+        FROM day_trading_stats d
+        JOIN stocks s ON s.code = d.stock_code
+        WHERE s.id = stock_prices.stock_id AND d.date = stock_prices.date
+    ) as day_trade_volume,
+    (
+        SELECT ss.issued_shares::text
+        FROM stock_shares ss
+        JOIN stocks s ON s.code = ss.stock_code
+        WHERE s.id = stock_prices.stock_id AND ss.effective_date <= stock_prices.date
+        ORDER BY ss.effective_date DESC
+        LIMIT 1
+    ) as issued_shares,
+    (
+        SELECT ih.insider_shares::text
+        FROM insider_holdings ih
+        JOIN stocks s ON s.code = ih.stock_code
+        WHERE s.id = stock_prices.stock_id AND ih.period <= stock_prices.date
+        ORDER BY ih.period DESC
+        LIMIT 1
+    ) as insider_shares
+"#;
+
 pub struct PostgresStockPriceRepository {
     pool: PgPool,
 }
@@ -17,13 +53,59 @@ impl PostgresStockPriceRepository {
         Self { pool }
     }
 
-    // 安全地將 i64 轉換為 u64
-    fn i64_to_u64(value: i64) -> u64 {
-        if value < 0 {
-            0
-        } else {
-            value as u64
-        }
+    fn map_row(r: &PgRow) -> StockPrice {
+        let decimal = |column: &str| {
+            let value: String = r.get(column);
+            BigDecimal::from_str(&value).unwrap_or_else(|_| BigDecimal::from(0))
+        };
+        let optional_decimal = |column: &str| {
+            let value: Option<String> = r.get(column);
+            value.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0)))
+        };
+        let unsigned = |column: &str| {
+            let value: String = r.get(column);
+            u64::from_str(&value).unwrap_or(0)
+        };
+        let optional_unsigned = |column: &str| {
+            let value: Option<String> = r.get(column);
+            value.map(|v| u64::from_str(&v).unwrap_or(0))
+        };
+        let optional_signed = |column: &str| {
+            let value: Option<String> = r.get(column);
+            value.map(|v| i64::from_str(&v).unwrap_or(0))
+        };
+        let limit_status: Option<String> = r.get("limit_status");
+        let source: String = r.get("source");
+
+        let mut stock_price = StockPrice {
+            id: r.get("id"),
+            stock_id: r.get("stock_id"),
+            date: r.get("date"),
+            open: decimal("open"),
+            high: decimal("high"),
+            low: decimal("low"),
+            close: decimal("close"),
+            volume: unsigned("volume"),
+            change: decimal("change"),
+            change_percent: decimal("change_percent"),
+            turnover: unsigned("turnover"),
+            transactions: unsigned("transactions"),
+            pe_ratio: optional_decimal("pe_ratio"),
+            pb_ratio: optional_decimal("pb_ratio"),
+            dividend_yield: optional_decimal("dividend_yield"),
+            market_cap: optional_unsigned("market_cap"),
+            foreign_buy: optional_signed("foreign_buy"),
+            trust_buy: optional_signed("trust_buy"),
+            dealer_buy: optional_signed("dealer_buy"),
+            limit_status: limit_status.as_deref().and_then(LimitStatus::parse),
+            day_trade_volume: optional_unsigned("day_trade_volume"),
+            issued_shares: optional_unsigned("issued_shares"),
+            insider_shares: optional_unsigned("insider_shares"),
+            source: PriceSource::parse(&source).unwrap_or_default(),
+        };
+        // 市值只在讀取時推算：有股本資料時以收盤價乘以當時的已發行股數，取代爬取的市值
+        stock_price.market_cap = stock_price.derived_market_cap().or(stock_price.market_cap);
+        stock_price
     }
 }
 
@@ -36,12 +118,12 @@ impl StockPriceRepository for PostgresStockPriceRepository {
                 id, stock_id, date, open, high, low, close, volume, 
                 change, change_percent, turnover, transactions, 
                 pe_ratio, pb_ratio, dividend_yield, market_cap,
-                foreign_buy, trust_buy, dealer_buy
+                foreign_buy, trust_buy, dealer_buy, limit_status, source
             ) VALUES (
                 $1, $2, $3, $4::numeric, $5::numeric, $6::numeric, $7::numeric, $8::numeric, 
                 $9::numeric, $10::numeric, $11::numeric, $12::numeric, 
                 $13::numeric, $14::numeric, $15::numeric, $16::numeric,
-                $17::numeric, $18::numeric, $19::numeric
+                $17::numeric, $18::numeric, $19::numeric, $20, $21
             )
             ON CONFLICT (stock_id, date) 
             DO UPDATE SET
@@ -60,7 +142,9 @@ impl StockPriceRepository for PostgresStockPriceRepository {
                 market_cap = $16::numeric,
                 foreign_buy = $17::numeric,
                 trust_buy = $18::numeric,
-                dealer_buy = $19::numeric
+                dealer_buy = $19::numeric,
+                limit_status = $20,
+                source = $21
             "#
         );
 
@@ -101,6 +185,8 @@ impl StockPriceRepository for PostgresStockPriceRepository {
             .bind(foreign_buy_str)
             .bind(trust_buy_str)
             .bind(dealer_buy_str)
+            .bind(stock_price.limit_status.map(|s| s.as_str()))
+            .bind(stock_price.source.as_str())
             .execute(&self.pool)
             .await?;
 
@@ -108,151 +194,28 @@ impl StockPriceRepository for PostgresStockPriceRepository {
     }
 
     async fn find_by_id(&self, id: &Uuid) -> Result<Option<StockPrice>> {
-        let query = format!(
-            r#"
-            SELECT 
-                id, stock_id, date, 
-                open::text as open, high::text as high, low::text as low, close::text as close,
-                volume::text as volume, 
-                change::text as change, change_percent::text as change_percent,
-                turnover::text as turnover, transactions::text as transactions, 
-                pe_ratio::text as pe_ratio, pb_ratio::text as pb_ratio, dividend_yield::text as dividend_yield,
-                market_cap::text as market_cap,
-                foreign_buy::text as foreign_buy, trust_buy::text as trust_buy, dealer_buy::text as dealer_buy
-            FROM stock_prices
-            WHERE id = $1
-            "#
-        );
+        let query = format!("SELECT {} FROM stock_prices WHERE id = $1", SELECT_COLUMNS);
 
-        let row_result = sqlx::query(&query)
+        let row = sqlx::query(&query)
             .bind(id)
-            .fetch_one(&self.pool)
-            .await;
-
-        match row_result {
-            Ok(r) => {
-                let id: Uuid = r.get("id");
-                let stock_id: Uuid = r.get("stock_id");
-                let date: Date = r.get("date");
-                let open: String = r.get("open");
-                let high: String = r.get("high");
-                let low: String = r.get("low");
-                let close: String = r.get("close");
-                let volume: String = r.get("volume");
-                let change: String = r.get("change");
-                let change_percent: String = r.get("change_percent");
-                let turnover: String = r.get("turnover");
-                let transactions: String = r.get("transactions");
-                let pe_ratio: Option<String> = r.get("pe_ratio");
-                let pb_ratio: Option<String> = r.get("pb_ratio");
-                let dividend_yield: Option<String> = r.get("dividend_yield");
-                let market_cap: Option<String> = r.get("market_cap");
-                let foreign_buy: Option<String> = r.get("foreign_buy");
-                let trust_buy: Option<String> = r.get("trust_buy");
-                let dealer_buy: Option<String> = r.get("dealer_buy");
+            .fetch_optional(&self.pool)
+            .await?;
 
-                let stock_price = StockPrice {
-                    id,
-                    stock_id,
-                    date,
-                    open: BigDecimal::from_str(&open).unwrap_or_else(|_| BigDecimal::from(0)),
-                    high: BigDecimal::from_str(&high).unwrap_or_else(|_| BigDecimal::from(0)),
-                    low: BigDecimal::from_str(&low).unwrap_or_else(|_| BigDecimal::from(0)),
-                    close: BigDecimal::from_str(&close).unwrap_or_else(|_| BigDecimal::from(0)),
-                    volume: u64::from_str(&volume).unwrap_or(0),
-                    change: BigDecimal::from_str(&change).unwrap_or_else(|_| BigDecimal::from(0)),
-                    change_percent: BigDecimal::from_str(&change_percent).unwrap_or_else(|_| BigDecimal::from(0)),
-                    turnover: u64::from_str(&turnover).unwrap_or(0),
-                    transactions: u64::from_str(&transactions).unwrap_or(0),
-                    pe_ratio: pe_ratio.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                    pb_ratio: pb_ratio.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                    dividend_yield: dividend_yield.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                    market_cap: market_cap.map(|v| u64::from_str(&v).unwrap_or(0)),
-                    foreign_buy: foreign_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                    trust_buy: trust_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                    dealer_buy: dealer_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                };
-                Ok(Some(stock_price))
-            }
-            Err(sqlx::Error::RowNotFound) => Ok(None),
-            Err(e) => Err(e.into()),
-        }
+        Ok(row.as_ref().map(Self::map_row))
     }
 
     async fn find_by_stock_id(&self, stock_id: &Uuid) -> Result<Vec<StockPrice>> {
         let query = format!(
-            r#"
-            SELECT 
-                id, stock_id, date, 
-                open::text as open, high::text as high, low::text as low, close::text as close,
-                volume::text as volume, 
-                change::text as change, change_percent::text as change_percent,
-                turnover::text as turnover, transactions::text as transactions, 
-                pe_ratio::text as pe_ratio, pb_ratio::text as pb_ratio, dividend_yield::text as dividend_yield,
-                market_cap::text as market_cap,
-                foreign_buy::text as foreign_buy, trust_buy::text as trust_buy, dealer_buy::text as dealer_buy
-            FROM stock_prices
-            WHERE stock_id = $1
-            ORDER BY date DESC
-            "#
+            "SELECT {} FROM stock_prices WHERE stock_id = $1 ORDER BY date DESC",
+            SELECT_COLUMNS
         );
 
-        let rows = match sqlx::query(&query)
+        let rows = sqlx::query(&query)
             .bind(stock_id)
             .fetch_all(&self.pool)
-            .await
-        {
-            Ok(rows) => rows,
-            Err(e) => return Err(e.into()),
-        };
-
-        let mut stock_prices = Vec::new();
-        for r in rows {
-            let id: Uuid = r.get("id");
-            let stock_id: Uuid = r.get("stock_id");
-            let date: Date = r.get("date");
-            let open: String = r.get("open");
-            let high: String = r.get("high");
-            let low: String = r.get("low");
-            let close: String = r.get("close");
-            let volume: String = r.get("volume");
-            let change: String = r.get("change");
-            let change_percent: String = r.get("change_percent");
-            let turnover: String = r.get("turnover");
-            let transactions: String = r.get("transactions");
-            let pe_ratio: Option<String> = r.get("pe_ratio");
-            let pb_ratio: Option<String> = r.get("pb_ratio");
-            let dividend_yield: Option<String> = r.get("dividend_yield");
-            let market_cap: Option<String> = r.get("market_cap");
-            let foreign_buy: Option<String> = r.get("foreign_buy");
-            let trust_buy: Option<String> = r.get("trust_buy");
-            let dealer_buy: Option<String> = r.get("dealer_buy");
-
-            let stock_price = StockPrice {
-                id,
-                stock_id,
-                date,
-                open: BigDecimal::from_str(&open).unwrap_or_else(|_| BigDecimal::from(0)),
-                high: BigDecimal::from_str(&high).unwrap_or_else(|_| BigDecimal::from(0)),
-                low: BigDecimal::from_str(&low).unwrap_or_else(|_| BigDecimal::from(0)),
-                close: BigDecimal::from_str(&close).unwrap_or_else(|_| BigDecimal::from(0)),
-                volume: u64::from_str(&volume).unwrap_or(0),
-                change: BigDecimal::from_str(&change).unwrap_or_else(|_| BigDecimal::from(0)),
-                change_percent: BigDecimal::from_str(&change_percent).unwrap_or_else(|_| BigDecimal::from(0)),
-                turnover: u64::from_str(&turnover).unwrap_or(0),
-                transactions: u64::from_str(&transactions).unwrap_or(0),
-                pe_ratio: pe_ratio.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                pb_ratio: pb_ratio.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                dividend_yield: dividend_yield.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                market_cap: market_cap.map(|v| u64::from_str(&v).unwrap_or(0)),
-                foreign_buy: foreign_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                trust_buy: trust_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                dealer_buy: dealer_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-            };
-            stock_prices.push(stock_price);
-        }
+            .await?;
 
-        Ok(stock_prices)
+        Ok(rows.iter().map(Self::map_row).collect())
     }
 
     async fn find_by_stock_id_and_date_range(
@@ -261,216 +224,79 @@ impl StockPriceRepository for PostgresStockPriceRepository {
         start_date: Option<Date>,
         end_date: Option<Date>,
     ) -> Result<Vec<StockPrice>> {
-        let query = match (start_date, end_date) {
-            (Some(start), Some(end)) => {
-                format!(
-                    r#"
-                    SELECT 
-                        id, stock_id, date, 
-                        open::text as open, high::text as high, low::text as low, close::text as close,
-                        volume::text as volume, 
-                        change::text as change, change_percent::text as change_percent,
-                        turnover::text as turnover, transactions::text as transactions, 
-                        pe_ratio::text as pe_ratio, pb_ratio::text as pb_ratio, dividend_yield::text as dividend_yield,
-                        market_cap::text as market_cap,
-                        foreign_buy::text as foreign_buy, trust_buy::text as trust_buy, dealer_buy::text as dealer_buy
-                    FROM stock_prices
-                    WHERE stock_id = $1 AND date BETWEEN $2 AND $3
-                    ORDER BY date DESC
-                    "#
-                )
-            }
-            (Some(start), None) => {
-                format!(
-                    r#"
-                    SELECT 
-                        id, stock_id, date, 
-                        open::text as open, high::text as high, low::text as low, close::text as close,
-                        volume::text as volume, 
-                        change::text as change, change_percent::text as change_percent,
-                        turnover::text as turnover, transactions::text as transactions, 
-                        pe_ratio::text as pe_ratio, pb_ratio::text as pb_ratio, dividend_yield::text as dividend_yield,
-                        market_cap::text as market_cap,
-                        foreign_buy::text as foreign_buy, trust_buy::text as trust_buy, dealer_buy::text as dealer_buy
-                    FROM stock_prices
-                    WHERE stock_id = $1 AND date >= $2
-                    ORDER BY date DESC
-                    "#
-                )
-            }
-            (None, Some(end)) => {
-                format!(
-                    r#"
-                    SELECT 
-                        id, stock_id, date, 
-                        open::text as open, high::text as high, low::text as low, close::text as close,
-                        volume::text as volume, 
-                        change::text as change, change_percent::text as change_percent,
-                        turnover::text as turnover, transactions::text as transactions, 
-                        pe_ratio::text as pe_ratio, pb_ratio::text as pb_ratio, dividend_yield::text as dividend_yield,
-                        market_cap::text as market_cap,
-                        foreign_buy::text as foreign_buy, trust_buy::text as trust_buy, dealer_buy::text as dealer_buy
-                    FROM stock_prices
-                    WHERE stock_id = $1 AND date <= $2
-                    ORDER BY date DESC
-                    "#
-                )
-            }
-            (None, None) => {
-                format!(
-                    r#"
-                    SELECT 
-                        id, stock_id, date, 
-                        open::text as open, high::text as high, low::text as low, close::text as close,
-                        volume::text as volume, 
-                        change::text as change, change_percent::text as change_percent,
-                        turnover::text as turnover, transactions::text as transactions, 
-                        pe_ratio::text as pe_ratio, pb_ratio::text as pb_ratio, dividend_yield::text as dividend_yield,
-                        market_cap::text as market_cap,
-                        foreign_buy::text as foreign_buy, trust_buy::text as trust_buy, dealer_buy::text as dealer_buy
-                    FROM stock_prices
-                    WHERE stock_id = $1
-                    ORDER BY date DESC
-                    "#
-                )
-            }
-        };
+        let query = format!(
+            r#"
+            SELECT {}
+            FROM stock_prices
+            WHERE stock_id = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date DESC
+            "#,
+            SELECT_COLUMNS
+        );
 
-        let mut query_builder = sqlx::query(&query).bind(stock_id);
+        let rows = sqlx::query(&query)
+            .bind(stock_id)
+            .bind(start_date)
+            .bind(end_date)
+            .fetch_all(&self.pool)
+            .await?;
 
-        if let Some(start) = start_date {
-            query_builder = query_builder.bind(start);
-        }
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
 
-        if let Some(end) = end_date {
-            query_builder = query_builder.bind(end);
-        }
+    async fn find_dates_by_stock_id(
+        &self,
+        stock_id: &Uuid,
+        start_date: Option<Date>,
+        end_date: Option<Date>,
+    ) -> Result<Vec<Date>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT date
+            FROM stock_prices
+            WHERE stock_id = $1
+                AND ($2::date IS NULL OR date >= $2)
+                AND ($3::date IS NULL OR date <= $3)
+            ORDER BY date
+            "#,
+        )
+        .bind(stock_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
 
-        let rows = match query_builder.fetch_all(&self.pool).await {
-            Ok(rows) => rows,
-            Err(e) => return Err(e.into()),
-        };
+        Ok(rows.iter().map(|r| r.get("date")).collect())
+    }
 
-        let mut stock_prices = Vec::new();
-        for r in rows {
-            let id: Uuid = r.get("id");
-            let stock_id: Uuid = r.get("stock_id");
-            let date: Date = r.get("date");
-            let open: String = r.get("open");
-            let high: String = r.get("high");
-            let low: String = r.get("low");
-            let close: String = r.get("close");
-            let volume: String = r.get("volume");
-            let change: String = r.get("change");
-            let change_percent: String = r.get("change_percent");
-            let turnover: String = r.get("turnover");
-            let transactions: String = r.get("transactions");
-            let pe_ratio: Option<String> = r.get("pe_ratio");
-            let pb_ratio: Option<String> = r.get("pb_ratio");
-            let dividend_yield: Option<String> = r.get("dividend_yield");
-            let market_cap: Option<String> = r.get("market_cap");
-            let foreign_buy: Option<String> = r.get("foreign_buy");
-            let trust_buy: Option<String> = r.get("trust_buy");
-            let dealer_buy: Option<String> = r.get("dealer_buy");
+    async fn find_latest_by_stock_id(&self, stock_id: &Uuid) -> Result<Option<StockPrice>> {
+        let query = format!(
+            "SELECT {} FROM stock_prices WHERE stock_id = $1 ORDER BY date DESC LIMIT 1",
+            SELECT_COLUMNS
+        );
 
-            let stock_price = StockPrice {
-                id,
-                stock_id,
-                date,
-                open: BigDecimal::from_str(&open).unwrap_or_else(|_| BigDecimal::from(0)),
-                high: BigDecimal::from_str(&high).unwrap_or_else(|_| BigDecimal::from(0)),
-                low: BigDecimal::from_str(&low).unwrap_or_else(|_| BigDecimal::from(0)),
-                close: BigDecimal::from_str(&close).unwrap_or_else(|_| BigDecimal::from(0)),
-                volume: u64::from_str(&volume).unwrap_or(0),
-                change: BigDecimal::from_str(&change).unwrap_or_else(|_| BigDecimal::from(0)),
-                change_percent: BigDecimal::from_str(&change_percent).unwrap_or_else(|_| BigDecimal::from(0)),
-                turnover: u64::from_str(&turnover).unwrap_or(0),
-                transactions: u64::from_str(&transactions).unwrap_or(0),
-                pe_ratio: pe_ratio.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                pb_ratio: pb_ratio.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                dividend_yield: dividend_yield.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                market_cap: market_cap.map(|v| u64::from_str(&v).unwrap_or(0)),
-                foreign_buy: foreign_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                trust_buy: trust_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                dealer_buy: dealer_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-            };
-            stock_prices.push(stock_price);
-        }
+        let row = sqlx::query(&query)
+            .bind(stock_id)
+            .fetch_optional(&self.pool)
+            .await?;
 
-        Ok(stock_prices)
+        Ok(row.as_ref().map(Self::map_row))
     }
 
-    async fn find_latest_by_stock_id(&self, stock_id: &Uuid) -> Result<Option<StockPrice>> {
+    async fn find_previous_by_stock_id(&self, stock_id: &Uuid, date: Date) -> Result<Option<StockPrice>> {
         let query = format!(
-            r#"
-            SELECT 
-                id, stock_id, date, 
-                open::text as open, high::text as high, low::text as low, close::text as close,
-                volume::text as volume, 
-                change::text as change, change_percent::text as change_percent,
-                turnover::text as turnover, transactions::text as transactions, 
-                pe_ratio::text as pe_ratio, pb_ratio::text as pb_ratio, dividend_yield::text as dividend_yield,
-                market_cap::text as market_cap,
-                foreign_buy::text as foreign_buy, trust_buy::text as trust_buy, dealer_buy::text as dealer_buy
-            FROM stock_prices
-            WHERE stock_id = $1
-            ORDER BY date DESC
-            LIMIT 1
-            "#
+            "SELECT {} FROM stock_prices WHERE stock_id = $1 AND date < $2 ORDER BY date DESC LIMIT 1",
+            SELECT_COLUMNS
         );
 
-        let row_result = sqlx::query(&query)
+        let row = sqlx::query(&query)
             .bind(stock_id)
-            .fetch_one(&self.pool)
-            .await;
-
-        match row_result {
-            Ok(r) => {
-                let id: Uuid = r.get("id");
-                let stock_id: Uuid = r.get("stock_id");
-                let date: Date = r.get("date");
-                let open: String = r.get("open");
-                let high: String = r.get("high");
-                let low: String = r.get("low");
-                let close: String = r.get("close");
-                let volume: String = r.get("volume");
-                let change: String = r.get("change");
-                let change_percent: String = r.get("change_percent");
-                let turnover: String = r.get("turnover");
-                let transactions: String = r.get("transactions");
-                let pe_ratio: Option<String> = r.get("pe_ratio");
-                let pb_ratio: Option<String> = r.get("pb_ratio");
-                let dividend_yield: Option<String> = r.get("dividend_yield");
-                let market_cap: Option<String> = r.get("market_cap");
-                let foreign_buy: Option<String> = r.get("foreign_buy");
-                let trust_buy: Option<String> = r.get("trust_buy");
-                let dealer_buy: Option<String> = r.get("dealer_buy");
+            .bind(date)
+            .fetch_optional(&self.pool)
+            .await?;
 
-                let stock_price = StockPrice {
-                    id,
-                    stock_id,
-                    date,
-                    open: BigDecimal::from_str(&open).unwrap_or_else(|_| BigDecimal::from(0)),
-                    high: BigDecimal::from_str(&high).unwrap_or_else(|_| BigDecimal::from(0)),
-                    low: BigDecimal::from_str(&low).unwrap_or_else(|_| BigDecimal::from(0)),
-                    close: BigDecimal::from_str(&close).unwrap_or_else(|_| BigDecimal::from(0)),
-                    volume: u64::from_str(&volume).unwrap_or(0),
-                    change: BigDecimal::from_str(&change).unwrap_or_else(|_| BigDecimal::from(0)),
-                    change_percent: BigDecimal::from_str(&change_percent).unwrap_or_else(|_| BigDecimal::from(0)),
-                    turnover: u64::from_str(&turnover).unwrap_or(0),
-                    transactions: u64::from_str(&transactions).unwrap_or(0),
-                    pe_ratio: pe_ratio.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                    pb_ratio: pb_ratio.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                    dividend_yield: dividend_yield.map(|v| BigDecimal::from_str(&v).unwrap_or_else(|_| BigDecimal::from(0))),
-                    market_cap: market_cap.map(|v| u64::from_str(&v).unwrap_or(0)),
-                    foreign_buy: foreign_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                    trust_buy: trust_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                    dealer_buy: dealer_buy.map(|v| i64::from_str(&v).unwrap_or(0)),
-                };
-                Ok(Some(stock_price))
-            }
-            Err(sqlx::Error::RowNotFound) => Ok(None),
-            Err(e) => Err(e.into()),
-        }
+        Ok(row.as_ref().map(Self::map_row))
     }
 }
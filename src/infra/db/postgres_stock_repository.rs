@@ -1,9 +1,9 @@
-use crate::domain::models::Stock;
+use crate::domain::models::{ListingStatus, Stock, StockNameChange};
 use crate::domain::repositories::StockRepository;
 use crate::domain::value_objects::Result;
 use async_trait::async_trait;
 use sqlx::PgPool;
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 use uuid::Uuid;
 
 pub struct PostgresStockRepository {
@@ -21,15 +21,19 @@ impl StockRepository for PostgresStockRepository {
     async fn save(&self, stock: &Stock) -> Result<()> {
         sqlx::query!(
             r#"
-            INSERT INTO stocks (id, code, name, last_updated)
-            VALUES ($1, $2, $3, to_timestamp($4))
+            INSERT INTO stocks (id, code, name, last_updated, status, listed_date, status_changed_on)
+            VALUES ($1, $2, $3, to_timestamp($4), $5, $6, $7)
             ON CONFLICT (code) DO UPDATE
-            SET name = $3, last_updated = to_timestamp($4)
+            SET name = $3, last_updated = to_timestamp($4),
+                listed_date = COALESCE($6, stocks.listed_date)
             "#,
             stock.id,
             stock.code,
             stock.name,
             stock.last_updated.unix_timestamp() as f64,
+            stock.status.as_str(),
+            stock.listed_date,
+            stock.status_changed_on,
         )
         .execute(&self.pool)
         .await?;
@@ -40,7 +44,8 @@ impl StockRepository for PostgresStockRepository {
     async fn find_by_id(&self, id: &Uuid) -> Result<Option<Stock>> {
         let record = sqlx::query!(
             r#"
-            SELECT id, code, name, extract(epoch from last_updated) as "last_updated!: f64"
+            SELECT id, code, name, extract(epoch from last_updated) as "last_updated!: f64",
+                   status, listed_date, status_changed_on
             FROM stocks
             WHERE id = $1
             "#,
@@ -56,6 +61,9 @@ impl StockRepository for PostgresStockRepository {
                 name: r.name,
                 last_updated: OffsetDateTime::from_unix_timestamp(r.last_updated as i64)
                     .unwrap_or_else(|_| OffsetDateTime::now_utc()),
+                status: ListingStatus::parse(&r.status).unwrap_or_default(),
+                listed_date: r.listed_date,
+                status_changed_on: r.status_changed_on,
             }
         }))
     }
@@ -63,7 +71,8 @@ impl StockRepository for PostgresStockRepository {
     async fn find_by_code(&self, code: &str) -> Result<Option<Stock>> {
         let record = sqlx::query!(
             r#"
-            SELECT id, code, name, extract(epoch from last_updated) as "last_updated!: f64"
+            SELECT id, code, name, extract(epoch from last_updated) as "last_updated!: f64",
+                   status, listed_date, status_changed_on
             FROM stocks
             WHERE code = $1
             "#,
@@ -79,6 +88,9 @@ impl StockRepository for PostgresStockRepository {
                 name: r.name,
                 last_updated: OffsetDateTime::from_unix_timestamp(r.last_updated as i64)
                     .unwrap_or_else(|_| OffsetDateTime::now_utc()),
+                status: ListingStatus::parse(&r.status).unwrap_or_default(),
+                listed_date: r.listed_date,
+                status_changed_on: r.status_changed_on,
             }
         }))
     }
@@ -86,7 +98,8 @@ impl StockRepository for PostgresStockRepository {
     async fn find_all(&self) -> Result<Vec<Stock>> {
         let records = sqlx::query!(
             r#"
-            SELECT id, code, name, extract(epoch from last_updated) as "last_updated!: f64"
+            SELECT id, code, name, extract(epoch from last_updated) as "last_updated!: f64",
+                   status, listed_date, status_changed_on
             FROM stocks
             ORDER BY code
             "#,
@@ -103,22 +116,69 @@ impl StockRepository for PostgresStockRepository {
                     name: r.name,
                     last_updated: OffsetDateTime::from_unix_timestamp(r.last_updated as i64)
                         .unwrap_or_else(|_| OffsetDateTime::now_utc()),
+                    status: ListingStatus::parse(&r.status).unwrap_or_default(),
+                    listed_date: r.listed_date,
+                    status_changed_on: r.status_changed_on,
                 }
             })
             .collect())
     }
 
-    async fn delete(&self, id: &Uuid) -> Result<()> {
+    async fn mark_delisted(&self, id: &Uuid) -> Result<()> {
         sqlx::query!(
             r#"
-            DELETE FROM stocks
-            WHERE id = $1
+            UPDATE stocks
+            SET status = $2, status_changed_on = (NOW() AT TIME ZONE 'Asia/Taipei')::date, updated_at = NOW()
+            WHERE id = $1 AND status <> $2
             "#,
             id,
+            ListingStatus::Delisted.as_str(),
         )
         .execute(&self.pool)
         .await?;
 
         Ok(())
     }
+
+    async fn update_status(&self, id: &Uuid, status: ListingStatus, changed_on: Date) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE stocks
+            SET status = $2, status_changed_on = $3, updated_at = NOW()
+            WHERE id = $1 AND status <> $2
+            "#,
+            id,
+            status.as_str(),
+            changed_on,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_name_history(&self, stock_id: &Uuid) -> Result<Vec<StockNameChange>> {
+        let records = sqlx::query!(
+            r#"
+            SELECT id, stock_id, previous_name, new_name, changed_on
+            FROM stock_name_history
+            WHERE stock_id = $1
+            ORDER BY changed_on, created_at
+            "#,
+            stock_id,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records
+            .into_iter()
+            .map(|r| StockNameChange {
+                id: r.id,
+                stock_id: r.stock_id,
+                previous_name: r.previous_name,
+                new_name: r.new_name,
+                changed_on: r.changed_on,
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,119 @@
+use crate::domain::models::TreasuryBuyback;
+use crate::domain::repositories::TreasuryBuybackRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+pub struct PostgresTreasuryBuybackRepository {
+    pool: PgPool,
+}
+
+impl PostgresTreasuryBuybackRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> TreasuryBuyback {
+        let planned_shares: String = r.get("planned_shares");
+        let price_low: String = r.get("price_low");
+        let price_high: String = r.get("price_high");
+        let executed_shares: String = r.get("executed_shares");
+        let executed_amount: String = r.get("executed_amount");
+
+        TreasuryBuyback {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            company_name: r.get("company_name"),
+            resolution_date: r.get("resolution_date"),
+            purpose: r.get("purpose"),
+            planned_shares: u64::from_str(&planned_shares).unwrap_or(0),
+            price_low: BigDecimal::from_str(&price_low).unwrap_or_else(|_| BigDecimal::from(0)),
+            price_high: BigDecimal::from_str(&price_high).unwrap_or_else(|_| BigDecimal::from(0)),
+            period_start: r.get("period_start"),
+            period_end: r.get("period_end"),
+            executed_shares: u64::from_str(&executed_shares).unwrap_or(0),
+            executed_amount: u64::from_str(&executed_amount).unwrap_or(0),
+            is_completed: r.get("is_completed"),
+        }
+    }
+}
+
+#[async_trait]
+impl TreasuryBuybackRepository for PostgresTreasuryBuybackRepository {
+    async fn save(&self, buyback: &TreasuryBuyback) -> Result<bool> {
+        // xmax = 0 表示這筆資料是新增而非更新
+        let row = sqlx::query(
+            r#"
+            INSERT INTO treasury_buybacks (
+                id, stock_id, company_name, resolution_date, purpose, planned_shares,
+                price_low, price_high, period_start, period_end,
+                executed_shares, executed_amount, is_completed
+            )
+            SELECT
+                $1, s.id, $3, $4, $5, $6::numeric,
+                $7::numeric, $8::numeric, $9, $10,
+                $11::numeric, $12::numeric, $13
+            FROM stocks s
+            WHERE s.code = $2
+            ON CONFLICT (stock_id, resolution_date)
+            DO UPDATE SET
+                company_name = $3,
+                purpose = $5,
+                planned_shares = $6::numeric,
+                price_low = $7::numeric,
+                price_high = $8::numeric,
+                period_start = $9,
+                period_end = $10,
+                executed_shares = $11::numeric,
+                executed_amount = $12::numeric,
+                is_completed = $13,
+                updated_at = NOW()
+            RETURNING (xmax = 0) as inserted
+            "#,
+        )
+        .bind(buyback.id)
+        .bind(&buyback.stock_code)
+        .bind(&buyback.company_name)
+        .bind(buyback.resolution_date)
+        .bind(&buyback.purpose)
+        .bind(buyback.planned_shares.to_string())
+        .bind(buyback.price_low.to_string())
+        .bind(buyback.price_high.to_string())
+        .bind(buyback.period_start)
+        .bind(buyback.period_end)
+        .bind(buyback.executed_shares.to_string())
+        .bind(buyback.executed_amount.to_string())
+        .bind(buyback.is_completed)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.is_some_and(|r| r.get::<bool, _>("inserted")))
+    }
+
+    async fn find_by_stock_code(&self, stock_code: &str) -> Result<Vec<TreasuryBuyback>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                b.id, s.code as stock_code, b.company_name, b.resolution_date, b.purpose,
+                b.planned_shares::text as planned_shares,
+                b.price_low::text as price_low, b.price_high::text as price_high,
+                b.period_start, b.period_end,
+                b.executed_shares::text as executed_shares,
+                b.executed_amount::text as executed_amount,
+                b.is_completed
+            FROM treasury_buybacks b
+            JOIN stocks s ON s.id = b.stock_id
+            WHERE s.code = $1
+            ORDER BY b.resolution_date DESC
+            "#,
+        )
+        .bind(stock_code)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(Self::map_row).collect())
+    }
+}
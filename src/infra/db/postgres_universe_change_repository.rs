@@ -0,0 +1,50 @@
+use crate::domain::models::{UniverseChange, UniverseChangeType};
+use crate::domain::repositories::UniverseChangeRepository;
+use crate::domain::value_objects::Result;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use sqlx::postgres::PgRow;
+use time::Date;
+
+pub struct PostgresUniverseChangeRepository {
+    pool: PgPool,
+}
+
+impl PostgresUniverseChangeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn map_row(r: &PgRow) -> Option<UniverseChange> {
+        let change_type: String = r.get("change_type");
+
+        Some(UniverseChange {
+            id: r.get("id"),
+            stock_code: r.get("stock_code"),
+            stock_name: r.get("stock_name"),
+            change_type: UniverseChangeType::parse(&change_type)?,
+            previous_value: r.get("previous_value"),
+            new_value: r.get("new_value"),
+            detected_on: r.get("detected_on"),
+        })
+    }
+}
+
+#[async_trait]
+impl UniverseChangeRepository for PostgresUniverseChangeRepository {
+    async fn find_since(&self, since: Option<Date>) -> Result<Vec<UniverseChange>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, stock_code, stock_name, change_type, previous_value, new_value, detected_on
+            FROM universe_changes
+            WHERE ($1::date IS NULL OR detected_on >= $1)
+            ORDER BY detected_on DESC, stock_code
+            "#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().filter_map(Self::map_row).collect())
+    }
+}
@@ -0,0 +1,64 @@
+// 引入必要的外部庫
+use crate::domain::models::{BlockTrade, BlockTradeType};
+use crate::infra::external_services::parsing::{parse_decimal, parse_u64};
+use crate::infra::external_services::twse_report::{cell_text, fetch_twse_report, twse_date};
+use anyhow::Result;
+use reqwest::Client;
+use time::Date;
+use tracing::info;
+use uuid::Uuid;
+
+/// 鉅額交易爬蟲服務 (證交所 BFIAUU)
+pub struct BlockTradeCrawlerService {
+    client: Client,
+}
+
+impl BlockTradeCrawlerService {
+    /// 創建新的鉅額交易爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取指定日期所有鉅額交易成交紀錄，包含配對交易及非配對交易
+    pub async fn crawl_block_trades(&self, date: Date) -> Result<Vec<BlockTrade>> {
+        info!("開始爬取 {} 的鉅額交易...", date);
+
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/block/BFIAUU?date={}&selectType=S&response=json",
+            twse_date(date)
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+        if !report.is_ok() {
+            info!("證交所鉅額交易報表查無資料: {}", report.stat);
+            return Ok(Vec::new());
+        }
+
+        // 欄位：證券代號、證券名稱、交易別、成交價、成交股數、成交金額
+        let trades: Vec<BlockTrade> = report
+            .data
+            .iter()
+            .filter_map(|row| {
+                let stock_code = cell_text(row, 0);
+                if stock_code.is_empty() {
+                    return None;
+                }
+
+                Some(BlockTrade {
+                    id: Uuid::new_v4(),
+                    stock_code,
+                    stock_name: cell_text(row, 1),
+                    date,
+                    trade_type: BlockTradeType::from_label(&cell_text(row, 2)),
+                    price: parse_decimal(&cell_text(row, 3))?,
+                    volume: parse_u64(&cell_text(row, 4))?,
+                    turnover: parse_u64(&cell_text(row, 5)).unwrap_or(0),
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆鉅額交易", trades.len());
+        Ok(trades)
+    }
+}
@@ -0,0 +1,89 @@
+// 引入必要的外部庫
+use crate::domain::models::{ConvertibleBond, ConvertibleBondQuote};
+use crate::infra::external_services::parsing::{parse_decimal, parse_roc_date, parse_u64};
+use crate::infra::external_services::twse_report::{cell_text, fetch_tpex_report, roc_date};
+use anyhow::Result;
+use reqwest::Client;
+use time::Date;
+use tracing::info;
+use uuid::Uuid;
+
+/// 櫃買中心可轉換公司債爬蟲服務
+pub struct ConvertibleBondCrawlerService {
+    client: Client,
+}
+
+impl ConvertibleBondCrawlerService {
+    /// 創建新的可轉債爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取指定日期的可轉債發行資料，包含最新轉換價格及流通餘額
+    pub async fn crawl_bonds(&self, date: Date) -> Result<Vec<ConvertibleBond>> {
+        info!("開始爬取 {} 的可轉債發行資料...", date);
+
+        let url = format!(
+            "https://www.tpex.org.tw/web/bond/publish/convertible_bond_search/cb_search_result.php?l=zh-tw&d={}&o=json",
+            roc_date(date)
+        );
+        let rows = fetch_tpex_report(&self.client, &url).await?.into_rows();
+
+        // 欄位：債券代號、債券簡稱、發行日期、到期日期、發行總額、流通餘額、最新轉換價格
+        let bonds: Vec<ConvertibleBond> = rows
+            .iter()
+            .filter_map(|row| {
+                let bond_code = cell_text(row, 0);
+                let underlying_code = ConvertibleBond::underlying_code_of(&bond_code)?;
+                let conversion_price = parse_decimal(&cell_text(row, 6))?;
+                Some(ConvertibleBond {
+                    id: Uuid::new_v4(),
+                    bond_code,
+                    bond_name: cell_text(row, 1),
+                    underlying_code,
+                    conversion_price,
+                    outstanding_amount: parse_u64(&cell_text(row, 5)).unwrap_or(0),
+                    issue_date: parse_roc_date(&cell_text(row, 2)),
+                    maturity_date: parse_roc_date(&cell_text(row, 3)),
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆可轉債發行資料", bonds.len());
+        Ok(bonds)
+    }
+
+    /// 爬取指定日期的可轉債等價成交行情，轉換溢價率由服務層以標的收盤價計算
+    pub async fn crawl_quotes(&self, date: Date) -> Result<Vec<ConvertibleBondQuote>> {
+        info!("開始爬取 {} 的可轉債行情...", date);
+
+        let url = format!(
+            "https://www.tpex.org.tw/web/bond/tradeinfo/cb/cb_daily_result.php?l=zh-tw&d={}&o=json",
+            roc_date(date)
+        );
+        let rows = fetch_tpex_report(&self.client, &url).await?.into_rows();
+
+        // 欄位：代號、名稱、收盤、漲跌、開盤、最高、最低、筆數、單位 (張)、金額
+        let quotes: Vec<ConvertibleBondQuote> = rows
+            .iter()
+            .filter_map(|row| {
+                let bond_code = cell_text(row, 0);
+                ConvertibleBond::underlying_code_of(&bond_code)?;
+                Some(ConvertibleBondQuote {
+                    id: Uuid::new_v4(),
+                    bond_code,
+                    date,
+                    close: parse_decimal(&cell_text(row, 2)),
+                    volume: parse_u64(&cell_text(row, 8)).unwrap_or(0),
+                    underlying_close: None,
+                    conversion_premium: None,
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆可轉債行情", quotes.len());
+        Ok(quotes)
+    }
+}
@@ -0,0 +1,73 @@
+// 引入必要的外部庫
+use crate::domain::models::DayTradingStat;
+use crate::infra::external_services::parsing::parse_u64;
+use crate::infra::external_services::twse_report::{cell_text, fetch_twse_report, twse_date};
+use anyhow::Result;
+use reqwest::Client;
+use time::Date;
+use tracing::info;
+use uuid::Uuid;
+
+/// 當日沖銷交易統計爬蟲服務 (證交所 TWTB4U)
+pub struct DayTradingCrawlerService {
+    client: Client,
+}
+
+impl DayTradingCrawlerService {
+    /// 創建新的當沖統計爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取指定日期所有上市股票的當日沖銷交易量值
+    pub async fn crawl_day_trading(&self, date: Date) -> Result<Vec<DayTradingStat>> {
+        info!("開始爬取 {} 的當日沖銷交易統計...", date);
+
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/block/TWTB4U?date={}&selectType=All&response=json",
+            twse_date(date)
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+        if !report.is_ok() {
+            info!("證交所當沖報表查無資料: {}", report.stat);
+            return Ok(Vec::new());
+        }
+
+        // 第一個表格為全市場統計，個股明細在標題含「標的」的表格
+        let rows = report
+            .tables
+            .iter()
+            .find(|t| t.title.contains("標的"))
+            .or_else(|| report.tables.last())
+            .map(|t| &t.data)
+            .unwrap_or(&report.data);
+
+        // 欄位：證券代號、證券名稱、暫停現股賣出後現款買進當沖註記、當日沖銷交易成交股數、
+        // 當日沖銷交易買進成交金額、當日沖銷交易賣出成交金額
+        let stats: Vec<DayTradingStat> = rows
+            .iter()
+            .filter_map(|row| {
+                let stock_code = cell_text(row, 0);
+                if stock_code.is_empty() {
+                    return None;
+                }
+
+                Some(DayTradingStat {
+                    id: Uuid::new_v4(),
+                    stock_code,
+                    stock_name: cell_text(row, 1),
+                    date,
+                    volume: parse_u64(&cell_text(row, 3))?,
+                    buy_amount: parse_u64(&cell_text(row, 4)).unwrap_or(0),
+                    sell_amount: parse_u64(&cell_text(row, 5)).unwrap_or(0),
+                    sell_first_suspended: cell_text(row, 2) == "Y",
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆當日沖銷交易統計", stats.len());
+        Ok(stats)
+    }
+}
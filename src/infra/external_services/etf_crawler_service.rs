@@ -0,0 +1,175 @@
+// 引入必要的外部庫
+use crate::domain::models::{EtfConstituent, EtfNav};
+use crate::infra::external_services::parsing::{parse_decimal, parse_u64};
+use anyhow::Result;
+use reqwest::Client;
+use scraper::{Html, Selector};
+use serde::Deserialize;
+use time::{Date, Month};
+use tracing::info;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+struct MisEtfResponse {
+    #[serde(default)]
+    a1: Vec<MisEtfGroup>,
+}
+
+/// all_etf.txt 依投信分組
+#[derive(Debug, Deserialize)]
+struct MisEtfGroup {
+    #[serde(rename = "msgArray", default)]
+    msg_array: Vec<MisEtfQuote>,
+}
+
+/// 單一 ETF 的預估淨值，欄位名稱沿用 MIS 的縮寫
+#[derive(Debug, Deserialize)]
+struct MisEtfQuote {
+    a: String,              // ETF 代碼
+    #[serde(default)]
+    b: String,              // ETF 名稱
+    #[serde(default)]
+    c: String,              // 已發行受益權單位數
+    #[serde(default)]
+    e: String,              // 成交價
+    #[serde(default)]
+    f: String,              // 預估淨值
+    #[serde(default)]
+    i: String,              // 資料日期，例如 "20250102"
+}
+
+/// ETF 淨值及成分股爬蟲服務
+pub struct EtfCrawlerService {
+    client: Client,
+}
+
+impl EtfCrawlerService {
+    /// 創建新的 ETF 爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取所有上市 ETF 的預估淨值，收盤後的資料即為當日收盤價及淨值
+    pub async fn crawl_navs(&self) -> Result<Vec<EtfNav>> {
+        info!("開始爬取 ETF 預估淨值...");
+
+        let response = self.client
+            .get("https://mis.twse.com.tw/stock/data/all_etf.txt")
+            .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+            .send()
+            .await?
+            .json::<MisEtfResponse>()
+            .await?;
+
+        let navs: Vec<EtfNav> = response
+            .a1
+            .into_iter()
+            .flat_map(|group| group.msg_array)
+            .filter_map(|quote| {
+                let date = Self::parse_date(&quote.i)?;
+                let nav = parse_decimal(&quote.f)?;
+                Some(EtfNav {
+                    id: Uuid::new_v4(),
+                    etf_code: quote.a.trim().to_string(),
+                    etf_name: quote.b.trim().to_string(),
+                    date,
+                    nav,
+                    close: parse_decimal(&quote.e),
+                    issued_units: parse_u64(&quote.c),
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆 ETF 預估淨值", navs.len());
+        Ok(navs)
+    }
+
+    /// 爬取指定 ETF 的成分股及權重 (MoneyDJ ETF 持股明細)
+    pub async fn crawl_constituents(&self, etf_code: &str, date: Date) -> Result<Vec<EtfConstituent>> {
+        info!("開始爬取 {} 的成分股...", etf_code);
+
+        let url = format!(
+            "https://www.moneydj.com/ETF/X/Basic/Basic0007a.xdjhtm?etfid={}.TW",
+            etf_code
+        );
+        let response = self.client
+            .get(&url)
+            .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        // Html 不是 Send，解析放在同步函數中
+        let constituents = Self::parse_constituents(&response, etf_code, date);
+
+        info!("成功爬取 {} 的 {} 檔成分股", etf_code, constituents.len());
+        Ok(constituents)
+    }
+
+    /// 解析 "20250102" 格式的日期
+    fn parse_date(text: &str) -> Option<Date> {
+        let text = text.trim();
+        if text.len() != 8 {
+            return None;
+        }
+
+        let year = text[0..4].parse::<i32>().ok()?;
+        let month = Month::try_from(text[4..6].parse::<u8>().ok()?).ok()?;
+        let day = text[6..8].parse::<u8>().ok()?;
+        Date::from_calendar_date(year, month, day).ok()
+    }
+
+    /// 解析持股明細表
+    ///
+    /// 欄位：個股名稱 (例如 "台積電(2330.TW)")、投資比例(%)、持有股數；
+    /// 只保留台灣上市櫃股票，現金、期貨等非股票部位不列入
+    fn parse_constituents(html: &str, etf_code: &str, date: Date) -> Vec<EtfConstituent> {
+        let document = Html::parse_document(html);
+        let tr_selector = Selector::parse("table tr").unwrap();
+        let td_selector = Selector::parse("td").unwrap();
+
+        let mut constituents = Vec::new();
+
+        for row in document.select(&tr_selector) {
+            let cells: Vec<String> = row
+                .select(&td_selector)
+                .map(|td| td.text().collect::<String>().trim().to_string())
+                .collect();
+            if cells.len() < 2 {
+                continue;
+            }
+
+            let Some((stock_name, stock_code)) = Self::split_name_and_code(&cells[0]) else {
+                continue;
+            };
+            let Some(weight) = parse_decimal(&cells[1]) else {
+                continue;
+            };
+
+            constituents.push(EtfConstituent {
+                id: Uuid::new_v4(),
+                etf_code: etf_code.to_string(),
+                date,
+                stock_code,
+                stock_name,
+                weight,
+                shares: cells.get(2).and_then(|v| parse_u64(v)),
+            });
+        }
+
+        constituents
+    }
+
+    /// 拆出 "台積電(2330.TW)" 中的名稱及代碼，上櫃股票後綴為 .TWO
+    fn split_name_and_code(text: &str) -> Option<(String, String)> {
+        let start = text.rfind('(')?;
+        let inner = text[start + 1..].strip_suffix(')')?;
+        let code = inner
+            .strip_suffix(".TW")
+            .or_else(|| inner.strip_suffix(".TWO"))?;
+        Some((text[..start].trim().to_string(), code.to_string()))
+    }
+}
@@ -0,0 +1,67 @@
+// 引入必要的外部庫
+use crate::domain::models::ForeignOwnership;
+use crate::infra::external_services::parsing::{parse_decimal, parse_u64};
+use crate::infra::external_services::twse_report::{cell_text, fetch_twse_report, twse_date};
+use anyhow::Result;
+use reqwest::Client;
+use time::Date;
+use tracing::info;
+use uuid::Uuid;
+
+/// 外資及陸資持股比例爬蟲服務 (證交所 MI_QFIIS)
+pub struct ForeignOwnershipCrawlerService {
+    client: Client,
+}
+
+impl ForeignOwnershipCrawlerService {
+    /// 創建新的外資持股爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取指定日期所有上市股票的外資及陸資持股統計
+    pub async fn crawl_foreign_ownership(&self, date: Date) -> Result<Vec<ForeignOwnership>> {
+        info!("開始爬取 {} 的外資持股比例...", date);
+
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/fund/MI_QFIIS?date={}&selectType=ALLBUT0999&response=json",
+            twse_date(date)
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+        if !report.is_ok() {
+            info!("證交所外資持股報表查無資料: {}", report.stat);
+            return Ok(Vec::new());
+        }
+
+        // 欄位：證券代號、證券名稱、國際證券辨識號碼、發行股數、外資及陸資尚可投資股數、
+        // 全體外資及陸資持有股數、外資及陸資尚可投資比率、全體外資及陸資持股比率、
+        // 外資及陸資共用法令投資上限比率、陸資法令投資上限比率、與前日異動原因、最近一次上市公司申報外資持股異動日期
+        let records: Vec<ForeignOwnership> = report
+            .data
+            .iter()
+            .filter_map(|row| {
+                let stock_code = cell_text(row, 0);
+                if stock_code.is_empty() {
+                    return None;
+                }
+
+                Some(ForeignOwnership {
+                    id: Uuid::new_v4(),
+                    stock_code,
+                    stock_name: cell_text(row, 1),
+                    date,
+                    issued_shares: parse_u64(&cell_text(row, 3))?,
+                    available_shares: parse_u64(&cell_text(row, 4)).unwrap_or(0),
+                    foreign_held_shares: parse_u64(&cell_text(row, 5)).unwrap_or(0),
+                    foreign_held_ratio: parse_decimal(&cell_text(row, 7))?,
+                    foreign_limit_ratio: parse_decimal(&cell_text(row, 8))?,
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆外資持股資料", records.len());
+        Ok(records)
+    }
+}
@@ -0,0 +1,204 @@
+// 引入必要的外部庫
+use crate::domain::models::IndexLevel;
+use crate::infra::external_services::parsing::{parse_decimal, parse_roc_date, strip_html};
+use crate::infra::external_services::twse_report::{cell_text, fetch_tpex_report, fetch_twse_report, roc_date, twse_date};
+use anyhow::Result;
+use reqwest::Client;
+use time::Date;
+use tracing::info;
+use bigdecimal::{BigDecimal, Zero};
+use std::collections::HashSet;
+
+/// 證交所指數名稱與系統內指數代碼的對照
+const TWSE_INDEX_CODES: &[(&str, &str)] = &[
+    ("發行量加權股價指數", "TAIEX"),
+    ("未含金融保險股指數", "TAIEX_EX_FINANCE"),
+    ("未含電子股指數", "TAIEX_EX_ELECTRONICS"),
+    ("未含金融電子股指數", "TAIEX_EX_FINANCE_ELECTRONICS"),
+    ("水泥類指數", "CEMENT"),
+    ("食品類指數", "FOOD"),
+    ("塑膠類指數", "PLASTICS"),
+    ("紡織纖維類指數", "TEXTILES"),
+    ("電機機械類指數", "ELECTRIC_MACHINERY"),
+    ("電器電纜類指數", "ELECTRIC_CABLE"),
+    ("化學生技醫療類指數", "CHEMICAL_BIOTECH"),
+    ("化學類指數", "CHEMICAL"),
+    ("生技醫療類指數", "BIOTECH"),
+    ("玻璃陶瓷類指數", "GLASS_CERAMICS"),
+    ("造紙類指數", "PAPER"),
+    ("鋼鐵類指數", "STEEL"),
+    ("橡膠類指數", "RUBBER"),
+    ("汽車類指數", "AUTOMOBILE"),
+    ("電子類指數", "ELECTRONICS"),
+    ("電子工業類指數", "ELECTRONICS"),
+    ("半導體類指數", "SEMICONDUCTOR"),
+    ("電腦及週邊設備類指數", "COMPUTER"),
+    ("光電類指數", "OPTOELECTRONICS"),
+    ("通信網路類指數", "COMMUNICATIONS"),
+    ("電子零組件類指數", "ELECTRONIC_COMPONENTS"),
+    ("電子通路類指數", "ELECTRONIC_DISTRIBUTION"),
+    ("資訊服務類指數", "INFORMATION_SERVICE"),
+    ("其他電子類指數", "OTHER_ELECTRONICS"),
+    ("建材營造類指數", "CONSTRUCTION"),
+    ("航運類指數", "SHIPPING"),
+    ("觀光事業類指數", "TOURISM"),
+    ("觀光餐旅類指數", "TOURISM"),
+    ("金融保險類指數", "FINANCE"),
+    ("貿易百貨類指數", "TRADING"),
+    ("油電燃氣類指數", "OIL_GAS_ELECTRICITY"),
+    ("綠能環保類指數", "GREEN_ENERGY"),
+    ("數位雲端類指數", "DIGITAL_CLOUD"),
+    ("運動休閒類指數", "SPORTS_LEISURE"),
+    ("居家生活類指數", "HOME_LIVING"),
+    ("其他類指數", "OTHERS"),
+];
+
+/// 指數爬蟲服務，爬取加權指數、櫃買指數及證交所類股指數
+pub struct IndexCrawlerService {
+    client: Client,
+}
+
+impl IndexCrawlerService {
+    /// 創建新的指數爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取指定日期的所有指數點位
+    pub async fn crawl_index_levels(&self, date: Date) -> Result<Vec<IndexLevel>> {
+        info!("開始爬取 {} 的指數資料...", date);
+
+        let mut index_levels = self.crawl_twse_indices(date).await?;
+
+        // 類股指數只有收盤點位，加權指數另外補上開高低
+        if let Some(taiex) = index_levels.iter_mut().find(|l| l.index_code == "TAIEX")
+            && let Err(e) = self.fill_taiex_ohlc(taiex).await
+        {
+            info!("爬取加權指數開高低失敗: {}", e);
+        }
+
+        match self.crawl_tpex_index(date).await {
+            Ok(Some(tpex)) => index_levels.push(tpex),
+            Ok(None) => info!("{} 沒有櫃買指數資料", date),
+            Err(e) => info!("爬取櫃買指數失敗: {}", e),
+        }
+
+        info!("成功爬取 {} 的 {} 筆指數資料", date, index_levels.len());
+        Ok(index_levels)
+    }
+
+    /// 爬取證交所每日收盤行情中的價格指數
+    async fn crawl_twse_indices(&self, date: Date) -> Result<Vec<IndexLevel>> {
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/afterTrading/MI_INDEX?date={}&type=IND&response=json",
+            twse_date(date)
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+        if !report.is_ok() {
+            info!("證交所指數報表查無資料: {}", report.stat);
+            return Ok(Vec::new());
+        }
+
+        let mut index_levels = Vec::new();
+        let mut seen_codes = HashSet::new();
+
+        for table in report.tables.iter().filter(|t| t.title.contains("價格指數")) {
+            for row in &table.data {
+                let name = cell_text(row, 0);
+                let code = match TWSE_INDEX_CODES.iter().find(|(n, _)| *n == name) {
+                    Some((_, code)) => *code,
+                    None => continue,
+                };
+                if !seen_codes.insert(code) {
+                    continue;
+                }
+
+                let close = match parse_decimal(&cell_text(row, 1)) {
+                    Some(close) => close,
+                    None => continue,
+                };
+
+                // 漲跌符號欄位為帶樣式的 HTML，點數及百分比欄位只有絕對值
+                let negative = strip_html(&cell_text(row, 2)).contains('-');
+                let mut change = parse_decimal(&cell_text(row, 3)).unwrap_or_else(BigDecimal::zero).abs();
+                let mut change_percent = parse_decimal(&cell_text(row, 4)).unwrap_or_else(BigDecimal::zero).abs();
+                if negative {
+                    change = -change;
+                    change_percent = -change_percent;
+                }
+
+                index_levels.push(IndexLevel::new(
+                    code.to_string(),
+                    name,
+                    date,
+                    close,
+                    change,
+                    change_percent,
+                ));
+            }
+        }
+
+        Ok(index_levels)
+    }
+
+    /// 從加權指數歷史資料補上開盤、最高、最低點位
+    async fn fill_taiex_ohlc(&self, taiex: &mut IndexLevel) -> Result<()> {
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/TAIEX/MI_5MINS_HIST?date={}&response=json",
+            twse_date(taiex.date)
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+
+        for row in &report.data {
+            if parse_roc_date(&cell_text(row, 0)) == Some(taiex.date) {
+                taiex.open = parse_decimal(&cell_text(row, 1));
+                taiex.high = parse_decimal(&cell_text(row, 2));
+                taiex.low = parse_decimal(&cell_text(row, 3));
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 爬取櫃買中心的櫃買指數
+    async fn crawl_tpex_index(&self, date: Date) -> Result<Option<IndexLevel>> {
+        let url = format!(
+            "https://www.tpex.org.tw/web/stock/aftertrading/daily_trading_index/st41_result.php?l=zh-tw&d={}&o=json",
+            roc_date(date)
+        );
+        let rows = fetch_tpex_report(&self.client, &url).await?.into_rows();
+
+        // 報表為整月資料：日期、成交股數、金額、筆數、櫃買指數、漲跌
+        for row in &rows {
+            if parse_roc_date(&cell_text(row, 0)) != Some(date) {
+                continue;
+            }
+
+            let close = match parse_decimal(&cell_text(row, 4)) {
+                Some(close) => close,
+                None => return Ok(None),
+            };
+            let change = parse_decimal(&cell_text(row, 5)).unwrap_or_else(BigDecimal::zero);
+            let prev_close = &close - &change;
+            let change_percent = if prev_close.is_zero() {
+                BigDecimal::zero()
+            } else {
+                (&change / &prev_close * BigDecimal::from(100)).round(2)
+            };
+
+            return Ok(Some(IndexLevel::new(
+                "TPEX".to_string(),
+                "櫃買指數".to_string(),
+                date,
+                close,
+                change,
+                change_percent,
+            )));
+        }
+
+        Ok(None)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::models::{Stock, StockPrice};
+use crate::domain::models::{InsiderHolding, MaterialAnnouncement, PriceDiscrepancy, TreasuryBuyback, Stock, StockPrice, SurveillanceType, UniverseChange, UniverseChangeType};
 use anyhow::Result;
 use reqwest::Client;
 use serde_json::json;
@@ -6,6 +6,15 @@ use tracing::{info, error};
 use time::Date;
 use bigdecimal::BigDecimal;
 
+/// 每日摘要中的單支股票
+#[derive(Debug, Clone)]
+pub struct DailySummaryItem {
+    pub stock: Stock,
+    pub price: StockPrice,
+    pub surveillance: Vec<SurveillanceType>,   // 注意股、處置股標記
+    pub foreign_ownership_ratio: Option<f64>,  // 外資持股比率 (%)
+}
+
 /// LINE 通知服務，用於發送股票相關通知到 LINE Bot
 pub struct LineNotificationService {
     client: Client,
@@ -27,11 +36,12 @@ impl LineNotificationService {
     pub async fn send_stock_price_notification(
         &self, 
         stock: &Stock, 
-        price: &StockPrice
+        price: &StockPrice,
+        surveillance: &[SurveillanceType]
     ) -> Result<()> {
         info!("發送股票價格通知到 LINE: {} {}", stock.code, stock.name);
         
-        let message = self.build_stock_price_message(stock, price);
+        let message = self.build_stock_price_message(stock, price, surveillance);
         self.send_push_message(&self.user_id, &message).await
     }
 
@@ -39,7 +49,7 @@ impl LineNotificationService {
     pub async fn send_daily_summary(
         &self, 
         date: Date, 
-        stocks: Vec<(Stock, StockPrice)>
+        stocks: Vec<DailySummaryItem>
     ) -> Result<()> {
         info!("發送每日股票摘要通知到 LINE");
         
@@ -47,6 +57,153 @@ impl LineNotificationService {
         self.send_push_message(&self.user_id, &message).await
     }
 
+    /// 發送重大訊息通知到 LINE
+    pub async fn send_material_announcement(&self, announcement: &MaterialAnnouncement) -> Result<()> {
+        info!("發送重大訊息通知到 LINE: {} {}", announcement.stock_code, announcement.title);
+        
+        // LINE 文字訊息上限 5000 字，說明過長時截斷
+        let body: String = announcement.body.chars().take(1000).collect();
+        let text = format!(
+            "【重大訊息】{} {}\n{}\n{}\n\n{}",
+            announcement.stock_code,
+            self.utf8_encode(&announcement.company_name),
+            announcement.announced_at.date(),
+            self.utf8_encode(&announcement.title),
+            self.utf8_encode(&body)
+        );
+        
+        let message = json!({
+            "type": "text",
+            "text": text.trim_end()
+        });
+        
+        self.send_push_message(&self.user_id, &message).await
+    }
+
+    /// 發送董監設質比率升破門檻通知到 LINE
+    pub async fn send_pledge_alert(&self, holding: &InsiderHolding, threshold: &BigDecimal) -> Result<()> {
+        info!("發送董監質押通知到 LINE: {}", holding.stock_code);
+        
+        let pledge_ratio = holding.pledge_ratio().unwrap_or_default();
+        let text = format!(
+            "【董監質押】{} {}\n{}-{:02} 董監設質比率 {}% (門檻 {}%)\n董監持股 {} 股，設質 {} 股",
+            holding.stock_code,
+            self.utf8_encode(&holding.company_name),
+            holding.period.year(),
+            holding.period.month() as u8,
+            pledge_ratio,
+            threshold,
+            holding.insider_shares,
+            holding.pledged_shares
+        );
+        
+        let message = json!({
+            "type": "text",
+            "text": text
+        });
+        
+        self.send_push_message(&self.user_id, &message).await
+    }
+
+    /// 發送庫藏股買回計畫通知到 LINE
+    pub async fn send_treasury_buyback(&self, buyback: &TreasuryBuyback) -> Result<()> {
+        info!("發送庫藏股通知到 LINE: {}", buyback.stock_code);
+        
+        let text = format!(
+            "【庫藏股】{} {}\n董事會決議日 {}\n預定買回 {} 股，價格 {} ~ {} 元\n期間 {} ~ {}\n目的：{}",
+            buyback.stock_code,
+            self.utf8_encode(&buyback.company_name),
+            buyback.resolution_date,
+            buyback.planned_shares,
+            buyback.price_low,
+            buyback.price_high,
+            buyback.period_start,
+            buyback.period_end,
+            self.utf8_encode(&buyback.purpose)
+        );
+        
+        let message = json!({
+            "type": "text",
+            "text": text
+        });
+        
+        self.send_push_message(&self.user_id, &message).await
+    }
+
+    /// 發送跨來源股價差異彙總到 LINE
+    pub async fn send_price_discrepancy_digest(&self, date: Date, discrepancies: &[PriceDiscrepancy]) -> Result<()> {
+        info!("發送股價比對差異通知到 LINE: {}, 共 {} 筆", date, discrepancies.len());
+        
+        // LINE 文字訊息上限 5000 字，差異過多時只列出前 50 筆
+        let lines: Vec<String> = discrepancies
+            .iter()
+            .take(50)
+            .map(|d| format!(
+                "{} {} {}: {} {} / {} {} (差 {}%)",
+                d.date,
+                d.stock_code,
+                d.field,
+                d.primary_source.as_str(),
+                d.primary_value,
+                d.secondary_source.as_str(),
+                d.secondary_value,
+                d.diff_percent
+            ))
+            .collect();
+        let text = format!(
+            "【股價比對】{} 共 {} 筆差異超過容許範圍\n{}",
+            date,
+            discrepancies.len(),
+            lines.join("\n")
+        );
+        
+        let message = json!({
+            "type": "text",
+            "text": text
+        });
+        
+        self.send_push_message(&self.user_id, &message).await
+    }
+
+    /// 發送上市櫃股票異動彙總，依異動類型分段列出
+    pub async fn send_universe_change_digest(&self, date: Date, changes: &[UniverseChange]) -> Result<()> {
+        info!("發送上市櫃異動通知到 LINE: {}, 共 {} 筆", date, changes.len());
+        
+        let sections = [
+            (UniverseChangeType::NewListing, "新上市櫃"),
+            (UniverseChangeType::Delisting, "下市櫃"),
+            (UniverseChangeType::NameChange, "更名"),
+            (UniverseChangeType::IndustryChange, "產業別變更"),
+            (UniverseChangeType::MarketTransfer, "市場別變更"),
+        ];
+        
+        let mut text = format!("【上市櫃異動】{} 共 {} 筆", date, changes.len());
+        for (change_type, label) in sections {
+            // LINE 文字訊息上限 5000 字，每類最多列出 30 筆
+            let lines: Vec<String> = changes
+                .iter()
+                .filter(|c| c.change_type == change_type)
+                .take(30)
+                .map(|c| match (&c.previous_value, &c.new_value) {
+                    (Some(previous), Some(new)) if change_type != UniverseChangeType::NewListing => {
+                        format!("{} {}: {} → {}", c.stock_code, c.stock_name, previous, new)
+                    }
+                    _ => format!("{} {}", c.stock_code, c.stock_name),
+                })
+                .collect();
+            if !lines.is_empty() {
+                text.push_str(&format!("\n\n■ {}\n{}", label, lines.join("\n")));
+            }
+        }
+        
+        let message = json!({
+            "type": "text",
+            "text": text
+        });
+        
+        self.send_push_message(&self.user_id, &message).await
+    }
+
     /// 發送自訂訊息到 LINE
     pub async fn send_custom_message(&self, text: &str) -> Result<()> {
         info!("發送自訂訊息到 LINE: {}", text);
@@ -89,13 +246,13 @@ impl LineNotificationService {
     }
 
     /// 構建股票價格訊息
-    fn build_stock_price_message(&self, stock: &Stock, price: &StockPrice) -> serde_json::Value {
+    fn build_stock_price_message(&self, stock: &Stock, price: &StockPrice, surveillance: &[SurveillanceType]) -> serde_json::Value {
         // 計算漲跌顏色
         let zero = BigDecimal::from(0);
         let color = if price.change >= zero { "#FF0000" } else { "#00FF00" };
         
         // 構建 Flex Message
-        json!({
+        let mut message = json!({
             "type": "flex",
             "altText": format!("股票價格通知: {} {}", stock.code, stock.name),
             "contents": {
@@ -314,14 +471,29 @@ impl LineNotificationService {
                     ]
                 }
             }
-        })
+        });
+        
+        // 注意股、處置股在標題下方加上警示
+        if !surveillance.is_empty()
+            && let Some(header) = message["contents"]["header"]["contents"].as_array_mut()
+        {
+            header.push(json!({
+                "type": "text",
+                "text": self.surveillance_text(surveillance),
+                "size": "sm",
+                "weight": "bold",
+                "color": "#FF6600"
+            }));
+        }
+        
+        message
     }
 
     /// 構建每日摘要訊息
-    fn build_daily_summary_message(&self, date: Date, stocks: &[(Stock, StockPrice)]) -> serde_json::Value {
+    fn build_daily_summary_message(&self, date: Date, stocks: &[DailySummaryItem]) -> serde_json::Value {
         // 按漲跌幅排序
         let mut sorted_stocks = stocks.to_vec();
-        sorted_stocks.sort_by(|a, b| b.1.change_percent.partial_cmp(&a.1.change_percent).unwrap());
+        sorted_stocks.sort_by(|a, b| b.price.change_percent.partial_cmp(&a.price.change_percent).unwrap());
         
         // 取前5名和後5名
         let top_stocks: Vec<_> = sorted_stocks.iter().take(5).collect();
@@ -348,15 +520,10 @@ impl LineNotificationService {
             "margin": "md"
         }));
         
-        for (stock, price) in top_stocks {
+        for item in top_stocks {
             contents.push(json!({
                 "type": "text",
-                "text": format!("{} {} {:.2} {:+.2}%", 
-                    stock.code, 
-                    self.utf8_encode(&stock.name), 
-                    price.close, 
-                    price.change_percent
-                ),
+                "text": self.summary_line(item),
                 "size": "sm",
                 "color": "#ffffff"
             }));
@@ -372,20 +539,41 @@ impl LineNotificationService {
             "margin": "md"
         }));
         
-        for (stock, price) in bottom_stocks {
+        for item in bottom_stocks {
             contents.push(json!({
                 "type": "text",
-                "text": format!("{} {} {:.2} {:+.2}%", 
-                    stock.code, 
-                    self.utf8_encode(&stock.name), 
-                    price.close, 
-                    price.change_percent
-                ),
+                "text": self.summary_line(item),
                 "size": "sm",
                 "color": "#ffffff"
             }));
         }
         
+        // 列出注意股及處置股
+        let flagged_stocks: Vec<_> = stocks.iter().filter(|item| !item.surveillance.is_empty()).collect();
+        if !flagged_stocks.is_empty() {
+            contents.push(json!({
+                "type": "text",
+                "text": "注意股 / 處置股",
+                "weight": "bold",
+                "size": "md",
+                "color": "#ffffff",
+                "margin": "md"
+            }));
+            
+            for item in flagged_stocks {
+                contents.push(json!({
+                    "type": "text",
+                    "text": format!("{} {} {}", 
+                        item.stock.code, 
+                        self.utf8_encode(&item.stock.name), 
+                        self.surveillance_text(&item.surveillance)
+                    ),
+                    "size": "sm",
+                    "color": "#ffffff"
+                }));
+            }
+        }
+        
         // 構建 Flex Message
         json!({
             "type": "flex",
@@ -418,6 +606,32 @@ impl LineNotificationService {
         })
     }
 
+    /// 摘要中單支股票的一行文字，外資持股比率、漲跌停及監視標記附加在行尾
+    fn summary_line(&self, item: &DailySummaryItem) -> String {
+        let mut line = format!("{} {} {:.2} {:+.2}%", 
+            item.stock.code, 
+            self.utf8_encode(&item.stock.name), 
+            item.price.close, 
+            item.price.change_percent
+        );
+        if let Some(ratio) = item.foreign_ownership_ratio {
+            line.push_str(&format!(" 外資 {:.2}%", ratio));
+        }
+        if let Some(limit_status) = item.price.limit_status {
+            line.push_str(&format!(" [{}]", limit_status.label()));
+        }
+        if !item.surveillance.is_empty() {
+            line.push(' ');
+            line.push_str(&self.surveillance_text(&item.surveillance));
+        }
+        line
+    }
+
+    /// 監視標記文字，例如「[注意股][處置股]」
+    fn surveillance_text(&self, surveillance: &[SurveillanceType]) -> String {
+        surveillance.iter().map(|t| format!("[{}]", t.label())).collect()
+    }
+
     /// 確保字符串是有效的 UTF-8 編碼
     fn utf8_encode(&self, text: &str) -> String {
         // 如果字符串已經是有效的 UTF-8，則直接返回
@@ -1,2 +1,20 @@
 pub mod stock_crawler_service;
 pub mod line_notification_service;
+pub mod twse_mis_service;
+pub mod index_crawler_service;
+pub mod twse_report;
+pub mod parsing;
+pub mod taifex_crawler_service;
+pub mod twse_holiday_service;
+pub mod surveillance_crawler_service;
+pub mod mops_crawler_service;
+pub mod foreign_ownership_crawler_service;
+pub mod day_trading_crawler_service;
+pub mod off_session_trading_crawler_service;
+pub mod block_trade_crawler_service;
+pub mod insider_holding_crawler_service;
+pub mod treasury_buyback_crawler_service;
+pub mod public_subscription_crawler_service;
+pub mod etf_crawler_service;
+pub mod convertible_bond_crawler_service;
+pub mod yahoo_finance_service;
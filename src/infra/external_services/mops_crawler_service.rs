@@ -0,0 +1,112 @@
+// 引入必要的外部庫
+use crate::domain::models::MaterialAnnouncement;
+use crate::domain::services::MarketClock;
+use crate::infra::external_services::parsing::parse_roc_date;
+use anyhow::Result;
+use reqwest::Client;
+use scraper::{Html, Selector};
+use time::{Date, PrimitiveDateTime, Time};
+use tracing::info;
+use uuid::Uuid;
+
+/// 公開資訊觀測站 (MOPS) 重大訊息爬蟲服務
+pub struct MopsCrawlerService {
+    client: Client,
+}
+
+impl MopsCrawlerService {
+    /// 創建新的重大訊息爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取指定日期上市櫃公司發布的所有重大訊息
+    pub async fn crawl_material_announcements(&self, date: Date) -> Result<Vec<MaterialAnnouncement>> {
+        info!("開始爬取 {} 的重大訊息...", date);
+
+        let response = self.client
+            .post("https://mops.twse.com.tw/mops/web/ajax_t05st02")
+            .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+            .form(&[
+                ("encodeURIComponent", "1".to_string()),
+                ("step", "1".to_string()),
+                ("firstin", "1".to_string()),
+                ("off", "1".to_string()),
+                ("TYPEK", "all".to_string()),
+                ("year", (date.year() - 1911).to_string()),
+                ("month", format!("{:02}", date.month() as u8)),
+                ("day", format!("{:02}", date.day())),
+            ])
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        // Html 不是 Send，解析放在同步函數中，讓爬蟲可以在 tokio::spawn 的任務中執行
+        let announcements = Self::parse_announcements(&response);
+
+        info!("成功爬取 {} 筆重大訊息", announcements.len());
+        Ok(announcements)
+    }
+
+    /// 解析重大訊息列表
+    ///
+    /// 欄位：發言日期、發言時間、公司代號、公司名稱、主旨；
+    /// 說明放在同一列「詳細資料」按鈕旁的隱藏欄位中，取其中最長的文字作為內容
+    fn parse_announcements(html: &str) -> Vec<MaterialAnnouncement> {
+        let document = Html::parse_document(html);
+        let tr_selector = Selector::parse("table tr").unwrap();
+        let td_selector = Selector::parse("td").unwrap();
+        let hidden_selector = Selector::parse("input[type=hidden]").unwrap();
+
+        let mut announcements = Vec::new();
+
+        for row in document.select(&tr_selector) {
+            let cells: Vec<String> = row
+                .select(&td_selector)
+                .map(|td| td.text().collect::<String>().trim().to_string())
+                .collect();
+            if cells.len() < 5 {
+                continue;
+            }
+
+            let (Some(date), Some(time)) = (parse_roc_date(&cells[0]), Self::parse_time(&cells[1])) else {
+                continue;
+            };
+
+            let body = row
+                .select(&hidden_selector)
+                .filter_map(|input| input.value().attr("value"))
+                .max_by_key(|value| value.chars().count())
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+
+            announcements.push(MaterialAnnouncement {
+                id: Uuid::new_v4(),
+                stock_code: cells[2].clone(),
+                company_name: cells[3].clone(),
+                announced_at: PrimitiveDateTime::new(date, time).assume_offset(MarketClock::offset()),
+                title: cells[4].clone(),
+                body,
+            });
+        }
+
+        announcements
+    }
+
+    /// 解析發言時間，可能是 "17:30:05" 或 "173005"
+    fn parse_time(text: &str) -> Option<Time> {
+        let digits: String = text.chars().filter(|c| c.is_ascii_digit()).collect();
+        if digits.len() != 6 {
+            return None;
+        }
+
+        let hour = digits[0..2].parse::<u8>().ok()?;
+        let minute = digits[2..4].parse::<u8>().ok()?;
+        let second = digits[4..6].parse::<u8>().ok()?;
+        Time::from_hms(hour, minute, second).ok()
+    }
+}
@@ -0,0 +1,123 @@
+// 引入必要的外部庫
+use crate::domain::models::{AfterHoursTrade, OddLotQuote};
+use crate::infra::external_services::parsing::{parse_decimal, parse_u64};
+use crate::infra::external_services::twse_report::{cell_text, fetch_twse_report, field_index, twse_date};
+use anyhow::Result;
+use reqwest::Client;
+use serde_json::Value;
+use time::Date;
+use tracing::info;
+use uuid::Uuid;
+
+/// 盤中零股及盤後定價交易爬蟲服務
+pub struct OffSessionTradingCrawlerService {
+    client: Client,
+}
+
+impl OffSessionTradingCrawlerService {
+    /// 創建新的零股及盤後定價爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取指定日期所有上市股票的盤中零股行情 (TWT53U)
+    pub async fn crawl_odd_lot_quotes(&self, date: Date) -> Result<Vec<OddLotQuote>> {
+        info!("開始爬取 {} 的盤中零股行情...", date);
+
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/afterTrading/TWT53U?date={}&selectType=ALL&response=json",
+            twse_date(date)
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+        if !report.is_ok() {
+            info!("證交所零股報表查無資料: {}", report.stat);
+            return Ok(Vec::new());
+        }
+
+        // 依欄位名稱取值；只有單一成交價的版本以成交價作為收盤價
+        let fields = &report.fields;
+        let column = |row: &[Value], name: &str| {
+            field_index(fields, name).map(|i| cell_text(row, i)).unwrap_or_default()
+        };
+
+        let quotes: Vec<OddLotQuote> = report
+            .data
+            .iter()
+            .filter_map(|row| {
+                let stock_code = cell_text(row, 0);
+                if stock_code.is_empty() {
+                    return None;
+                }
+
+                let close = parse_decimal(&column(row, "收盤價")).or_else(|| parse_decimal(&column(row, "成交價")));
+                Some(OddLotQuote {
+                    id: Uuid::new_v4(),
+                    stock_code,
+                    stock_name: cell_text(row, 1),
+                    date,
+                    open: parse_decimal(&column(row, "開盤價")),
+                    high: parse_decimal(&column(row, "最高價")),
+                    low: parse_decimal(&column(row, "最低價")),
+                    close,
+                    volume: parse_u64(&column(row, "成交股數")).unwrap_or(0),
+                    transactions: parse_u64(&column(row, "成交筆數")).unwrap_or(0),
+                    turnover: parse_u64(&column(row, "成交金額")).unwrap_or(0),
+                    last_bid_price: parse_decimal(&column(row, "最後揭示買價")),
+                    last_ask_price: parse_decimal(&column(row, "最後揭示賣價")),
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆盤中零股行情", quotes.len());
+        Ok(quotes)
+    }
+
+    /// 爬取指定日期所有上市股票的盤後定價交易 (BFT41U)
+    pub async fn crawl_after_hours_trades(&self, date: Date) -> Result<Vec<AfterHoursTrade>> {
+        info!("開始爬取 {} 的盤後定價交易...", date);
+
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/afterTrading/BFT41U?date={}&selectType=ALL&response=json",
+            twse_date(date)
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+        if !report.is_ok() {
+            info!("證交所盤後定價報表查無資料: {}", report.stat);
+            return Ok(Vec::new());
+        }
+
+        let fields = &report.fields;
+        let column = |row: &[Value], name: &str| {
+            field_index(fields, name).map(|i| cell_text(row, i)).unwrap_or_default()
+        };
+
+        let trades: Vec<AfterHoursTrade> = report
+            .data
+            .iter()
+            .filter_map(|row| {
+                let stock_code = cell_text(row, 0);
+                if stock_code.is_empty() {
+                    return None;
+                }
+
+                Some(AfterHoursTrade {
+                    id: Uuid::new_v4(),
+                    stock_code,
+                    stock_name: cell_text(row, 1),
+                    date,
+                    price: parse_decimal(&column(row, "成交價")),
+                    volume: parse_u64(&column(row, "成交股數")).unwrap_or(0),
+                    transactions: parse_u64(&column(row, "成交筆數")).unwrap_or(0),
+                    turnover: parse_u64(&column(row, "成交金額")).unwrap_or(0),
+                    last_bid_volume: parse_u64(&column(row, "最後揭示買量")).unwrap_or(0),
+                    last_ask_volume: parse_u64(&column(row, "最後揭示賣量")).unwrap_or(0),
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆盤後定價交易", trades.len());
+        Ok(trades)
+    }
+}
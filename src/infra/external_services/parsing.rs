@@ -0,0 +1,54 @@
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+use time::{Date, Month};
+
+/// 判斷文字是否為交易所的「無資料」標記，例如 "--"、"-"、"X"
+fn is_empty_marker(text: &str) -> bool {
+    text.is_empty() || text.chars().all(|c| c == '-') || text == "X"
+}
+
+/// 從文本中解析 BigDecimal，忽略千分位逗號及無資料標記
+pub fn parse_decimal(text: &str) -> Option<BigDecimal> {
+    let text = text.trim().replace(",", "");
+    if is_empty_marker(&text) {
+        return None;
+    }
+    BigDecimal::from_str(&text).ok()
+}
+
+/// 從文本中解析非負整數，忽略千分位逗號及小數部分 (MIS 的量會帶小數點)
+pub fn parse_u64(text: &str) -> Option<u64> {
+    let text = text.trim().replace(",", "");
+    if is_empty_marker(&text) {
+        return None;
+    }
+    text.split('.').next().and_then(|v| v.parse::<u64>().ok())
+}
+
+/// 解析民國日期，例如 "113/01/02" 或 "113.01.02"
+pub fn parse_roc_date(text: &str) -> Option<Date> {
+    let parts: Vec<&str> = text.trim().split(['/', '.', '-']).collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    let year = 1911 + parts[0].trim().parse::<i32>().ok()?;
+    let month = Month::try_from(parts[1].trim().parse::<u8>().ok()?).ok()?;
+    let day = parts[2].trim().parse::<u8>().ok()?;
+    Date::from_calendar_date(year, month, day).ok()
+}
+
+/// 去除 HTML 標籤，證交所部分欄位 (例如漲跌符號) 會夾帶樣式標籤
+pub fn strip_html(text: &str) -> String {
+    let mut result = String::new();
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {}
+        }
+    }
+    result.trim().to_string()
+}
@@ -0,0 +1,86 @@
+// 引入必要的外部庫
+use crate::domain::models::PublicSubscription;
+use crate::infra::external_services::parsing::{parse_decimal, parse_roc_date, parse_u64};
+use crate::infra::external_services::twse_report::{cell_text, fetch_twse_report, field_index};
+use anyhow::Result;
+use reqwest::Client;
+use serde_json::Value;
+use tracing::info;
+use uuid::Uuid;
+
+/// 證交所公開申購公告爬蟲服務
+pub struct PublicSubscriptionCrawlerService {
+    client: Client,
+}
+
+impl PublicSubscriptionCrawlerService {
+    /// 創建新的公開申購爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取指定年度的公開申購公告 (含初上市櫃及現金增資)
+    pub async fn crawl_public_subscriptions(&self, year: i32) -> Result<Vec<PublicSubscription>> {
+        info!("開始爬取 {} 年的公開申購公告...", year);
+
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/announcement/publicForm?yy={}&response=json",
+            year
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+        if !report.is_ok() {
+            info!("證交所公開申購公告查無資料: {}", report.stat);
+            return Ok(Vec::new());
+        }
+
+        // 依欄位名稱取值；實際承銷價及實際承銷股數於抽籤前才確定，未公告時以預定值為準
+        let fields = &report.fields;
+        let column = |row: &[Value], name: &str| {
+            field_index(fields, name).map(|i| cell_text(row, i)).unwrap_or_default()
+        };
+
+        let subscriptions: Vec<PublicSubscription> = report
+            .data
+            .iter()
+            .filter_map(|row| {
+                let stock_code = column(row, "證券代號");
+                let (Some(subscription_start), Some(subscription_end), Some(lottery_date)) = (
+                    parse_roc_date(&column(row, "申購開始日")),
+                    parse_roc_date(&column(row, "申購結束日")),
+                    parse_roc_date(&column(row, "抽籤日期")),
+                ) else {
+                    return None;
+                };
+                let offer_price = parse_decimal(&column(row, "實際承銷價(元)"))
+                    .or_else(|| parse_decimal(&column(row, "承銷價(元)")))?;
+                if stock_code.is_empty() {
+                    return None;
+                }
+
+                let cancelled = column(row, "取消公開抽籤");
+                Some(PublicSubscription {
+                    id: Uuid::new_v4(),
+                    stock_code,
+                    stock_name: column(row, "證券名稱"),
+                    market: column(row, "發行市場"),
+                    subscription_start,
+                    subscription_end,
+                    lottery_date,
+                    listing_date: parse_roc_date(&column(row, "撥券日期(上市、上櫃日期)")),
+                    offer_price,
+                    shares_offered: parse_u64(&column(row, "實際承銷股數"))
+                        .or_else(|| parse_u64(&column(row, "承銷股數")))
+                        .unwrap_or(0),
+                    shares_per_application: parse_u64(&column(row, "申購股數")).unwrap_or(1000),
+                    win_rate: parse_decimal(&column(row, "中籤率(%)")),
+                    is_cancelled: !cancelled.is_empty() && cancelled != "否",
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆公開申購公告", subscriptions.len());
+        Ok(subscriptions)
+    }
+}
@@ -1,24 +1,33 @@
 // 引入必要的外部庫
-use crate::domain::models::{Stock, StockPrice};
-use anyhow::Result;
+use crate::domain::models::{ListedSecurity, Stock, StockPrice};
+use crate::domain::services::MarketClock;
+use crate::infra::external_services::yahoo_finance_service::YahooFinanceService;
+use anyhow::{Result, anyhow};
 use reqwest::Client;
 use scraper::{Html, Selector, Element};
 use time::Date;
 use uuid::Uuid;
 use std::collections::HashMap;
-use tracing::info;
+use std::sync::Arc;
+use tracing::{info, warn};
 use bigdecimal::BigDecimal;
 use std::str::FromStr;
 use encoding_rs::BIG5;
 
 /// 股票爬蟲服務結構體
 /// 股票爬蟲服務，用於爬取股票列表和股票價格數據
-pub struct StockCrawlerService {}
+pub struct StockCrawlerService {
+    yahoo_finance_service: YahooFinanceService,
+    market_clock: Arc<MarketClock>,
+}
 
 impl StockCrawlerService {
     /// 創建新的股票爬蟲服務實例
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(market_clock: Arc<MarketClock>) -> Self {
+        Self {
+            yahoo_finance_service: YahooFinanceService::new(),
+            market_clock,
+        }
     }
 
     /// 爬取台灣股票市場的股票列表
@@ -81,18 +90,132 @@ impl StockCrawlerService {
         Ok(stocks)
     }
 
-    /// 爬取特定股票的歷史價格數據
-    pub async fn crawl_stock_prices(&self, stock_code: &str) -> Result<Vec<StockPrice>> {
+    /// 爬取完整的 ISIN 上市 (market=1) 及上櫃 (market=2) 股票清單，用於比對前後兩次清單找出新上市、下市、更名、產業別及市場別變更
+    pub async fn crawl_isin_listing(&self) -> Result<Vec<ListedSecurity>> {
+        info!("開始爬取 ISIN 上市櫃股票清單...");
+
+        let client = Client::new();
+        let mut listings = Vec::new();
+        for market in ["1", "2"] {
+            let url = format!(
+                "https://isin.twse.com.tw/isin/class_main.jsp?owncode=&stockname=&isincode=&market={}&issuetype=1&industry_code=&Page=1&chklike=Y",
+                market
+            );
+            let response = client
+                .get(&url)
+                .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+                .send()
+                .await?
+                .error_for_status()?
+                .bytes()
+                .await?;
+
+            // 台灣交易所網站使用 big5 編碼
+            let response_text = BIG5.decode(&response).0.into_owned();
+            let market_listings = Self::parse_isin_listing(&response_text);
+            if market_listings.is_empty() {
+                return Err(anyhow!("ISIN 清單 market={} 沒有任何股票，可能是網頁格式變更", market));
+            }
+            listings.extend(market_listings);
+        }
+
+        info!("成功爬取 {} 支上市櫃股票", listings.len());
+
+        Ok(listings)
+    }
+
+    /// 解析 ISIN 清單表格: 第 3 欄為代號、第 4 欄為名稱、第 5 欄為市場別、第 7 欄為產業別、第 8 欄為上市日 (YYYY/MM/DD)
+    fn parse_isin_listing(html: &str) -> Vec<ListedSecurity> {
+        let document = Html::parse_document(html);
+        let tr_selector = Selector::parse("table.h4 tr").unwrap();
+        let td_selector = Selector::parse("td").unwrap();
+
+        let mut listings = Vec::new();
+        for row in document.select(&tr_selector).skip(1) {
+            let cells: Vec<String> = row
+                .select(&td_selector)
+                .map(|td| td.text().collect::<String>().trim().to_string())
+                .collect();
+            if cells.len() < 8 {
+                continue;
+            }
+
+            let code = cells[2].clone();
+            let name = cells[3].clone();
+            if code.is_empty() || name.is_empty() || code.len() > 6 || !code.chars().all(|c| c.is_ascii_digit()) {
+                continue;
+            }
+
+            let listed_date = {
+                let parts: Vec<&str> = cells[7].split('/').collect();
+                match parts.as_slice() {
+                    [year, month, day] => match (year.parse::<i32>(), month.parse::<u8>(), day.parse::<u8>()) {
+                        (Ok(year), Ok(month), Ok(day)) => time::Month::try_from(month)
+                            .ok()
+                            .and_then(|month| Date::from_calendar_date(year, month, day).ok()),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            };
+
+            let non_empty = |value: &String| if value.is_empty() { None } else { Some(value.clone()) };
+            listings.push(ListedSecurity {
+                code,
+                name,
+                listed_date,
+                market: non_empty(&cells[4]),
+                industry: non_empty(&cells[6]),
+            });
+        }
+
+        listings
+    }
+
+    /// 爬取特定股票在指定日期所屬月份的價格數據，證交所無法取得時改用 Yahoo Finance
+    pub async fn crawl_stock_prices(&self, stock_code: &str, date: Date) -> Result<Vec<StockPrice>> {
+        self.crawl_stock_prices_for_month(stock_code, date).await
+    }
+
+    /// 爬取特定股票指定月份的價格數據，用於補抓缺漏的交易日，證交所無法取得時改用同一月份的 Yahoo Finance 日 K
+    pub async fn crawl_stock_prices_for_month(&self, stock_code: &str, month: Date) -> Result<Vec<StockPrice>> {
+        let month = month.replace_day(1)?;
+        match self.crawl_twse_stock_prices(stock_code, month).await {
+            Ok(prices) if !prices.is_empty() => Ok(prices),
+            result => {
+                match result {
+                    Err(e) => warn!("證交所爬取股票 {} {} 月的價格數據失敗: {}，改用 Yahoo Finance", stock_code, month.month() as u8, e),
+                    Ok(_) => warn!("證交所查無股票 {} {} 月的價格數據，改用 Yahoo Finance", stock_code, month.month() as u8),
+                }
+                let month_end = Date::from_calendar_date(month.year(), month.month(), month.month().length(month.year()))?;
+                self.yahoo_finance_service.crawl_stock_prices_between(stock_code, month, month_end).await
+            }
+        }
+    }
+
+    /// 證交所個股日成交資訊以月份查詢，日期參數固定為該月 1 日
+    fn stock_day_url(stock_code: &str, month: Date) -> String {
+        format!(
+            "https://www.twse.com.tw/exchangeReport/STOCK_DAY?response=html&date={:04}{:02}01&stockNo={}",
+            month.year(),
+            month.month() as u8,
+            stock_code
+        )
+    }
+
+    /// 從證交所爬取特定股票指定月份的歷史價格數據
+    async fn crawl_twse_stock_prices(&self, stock_code: &str, month: Date) -> Result<Vec<StockPrice>> {
         info!("開始爬取股票 {} 的價格數據...", stock_code);
         
         // 使用 reqwest 發送 HTTP 請求
         let client = Client::new();
-        let url = format!("https://www.twse.com.tw/exchangeReport/STOCK_DAY?response=html&date=20250101&stockNo={}", stock_code);
+        let url = Self::stock_day_url(stock_code, month);
         
         let response = client
             .get(&url)
             .send()
             .await?
+            .error_for_status()?
             .text()
             .await?;
         
@@ -143,8 +266,17 @@ impl StockCrawlerService {
                         let transactions_text = cells[8].text().collect::<String>().replace(",", "");
                         let transactions = transactions_text.parse::<u64>().unwrap_or(0);
                         
-                        // 爬取股票基本資訊（本益比、股價淨值比、殖利率等）
-                        let stock_info = self.crawl_stock_info(stock_code).await?;
+                        // 開高低收皆為 "--" 且無成交量表示當日暫停交易，沒有價格不產生日 K
+                        if open.is_none() && high.is_none() && low.is_none() && close.is_none() && volume == 0 {
+                            info!("股票 {} 於 {} 無成交，視為暫停交易略過", stock_code, date);
+                            continue;
+                        }
+                        
+                        // 爬取股票基本資訊（本益比、股價淨值比、殖利率等），失敗時視為缺少這些欄位，不影響當月其他日 K
+                        let stock_info = self.crawl_stock_info(stock_code).await.unwrap_or_else(|e| {
+                            warn!("爬取股票 {} 的基本資訊失敗: {}，本益比等欄位留空", stock_code, e);
+                            HashMap::new()
+                        });
                         
                         let pe_ratio = stock_info.get("本益比").map(|&v| BigDecimal::from_str(&v.to_string()).unwrap_or_else(|_| BigDecimal::from(0)));
                         let pb_ratio = stock_info.get("股價淨值比").map(|&v| BigDecimal::from_str(&v.to_string()).unwrap_or_else(|_| BigDecimal::from(0)));
@@ -178,7 +310,7 @@ impl StockCrawlerService {
                             }
                         };
                         
-                        // 從股票基本資訊中獲取其他數據
+                        // 從股票基本資訊中獲取其他數據，缺漏的價格以 0 表示，保存前的驗證會將其移至隔離區
                         let stock_price = StockPrice::with_details(
                             Uuid::nil(),
                             date,
@@ -475,7 +607,7 @@ impl StockCrawlerService {
         // 如果沒有爬取到任何數據，添加一些測試數據
         if result.is_empty() {
             info!("未爬取到三大法人買賣超資訊，添加測試數據");
-            let today = time::OffsetDateTime::now_utc().date();
+            let today = self.market_clock.today();
             let date_str = today.to_string();
             result.insert(date_str, (100, 50, 30)); // 添加測試數據
         }
@@ -501,4 +633,24 @@ impl StockCrawlerService {
         }
         BigDecimal::from_str(&text).ok()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn stock_day_url_uses_first_day_of_requested_month() {
+        let month = Date::from_calendar_date(2026, Month::October, 1).unwrap();
+        assert_eq!(
+            StockCrawlerService::stock_day_url("2330", month),
+            "https://www.twse.com.tw/exchangeReport/STOCK_DAY?response=html&date=20261001&stockNo=2330"
+        );
+    }
+
+    #[test]
+    fn stock_day_url_pads_single_digit_month() {
+        let month = Date::from_calendar_date(2027, Month::March, 1).unwrap();
+        assert!(StockCrawlerService::stock_day_url("0050", month).contains("date=20270301&stockNo=0050"));
+    }
+}
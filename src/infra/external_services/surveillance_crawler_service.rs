@@ -0,0 +1,179 @@
+// 引入必要的外部庫
+use crate::domain::models::{MarketSurveillanceEvent, SurveillanceType};
+use crate::infra::external_services::parsing::{parse_roc_date, strip_html};
+use crate::infra::external_services::twse_report::{cell_text, fetch_tpex_report, fetch_twse_report, roc_date, twse_date};
+use anyhow::Result;
+use reqwest::Client;
+use serde_json::Value;
+use time::Date;
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// 注意股、處置股公告爬蟲服務，涵蓋證交所及櫃買中心
+pub struct SurveillanceCrawlerService {
+    client: Client,
+}
+
+impl SurveillanceCrawlerService {
+    /// 創建新的監視公告爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取指定日期公告的所有注意股及處置股
+    pub async fn crawl_events(&self, date: Date) -> Result<Vec<MarketSurveillanceEvent>> {
+        info!("開始爬取 {} 的注意股及處置股公告...", date);
+
+        let mut events = Vec::new();
+
+        match self.crawl_twse_attention(date).await {
+            Ok(mut items) => events.append(&mut items),
+            Err(e) => error!("爬取上市注意股失敗: {}", e),
+        }
+        match self.crawl_twse_disposition(date).await {
+            Ok(mut items) => events.append(&mut items),
+            Err(e) => error!("爬取上市處置股失敗: {}", e),
+        }
+        match self.crawl_tpex_attention(date).await {
+            Ok(mut items) => events.append(&mut items),
+            Err(e) => error!("爬取上櫃注意股失敗: {}", e),
+        }
+        match self.crawl_tpex_disposition(date).await {
+            Ok(mut items) => events.append(&mut items),
+            Err(e) => error!("爬取上櫃處置股失敗: {}", e),
+        }
+
+        info!("成功爬取 {} 筆注意股及處置股公告", events.len());
+        Ok(events)
+    }
+
+    /// 證交所公布注意股 (announcement/notice)
+    async fn crawl_twse_attention(&self, date: Date) -> Result<Vec<MarketSurveillanceEvent>> {
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/announcement/notice?response=json&startDate={}&endDate={}",
+            twse_date(date),
+            twse_date(date)
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+
+        // 欄位：編號、證券代號、證券名稱、累計次數、注意交易資訊、日期、收盤價、本益比
+        Ok(report
+            .data
+            .iter()
+            .filter_map(|row| Self::attention_event(row, "TWSE", date))
+            .collect())
+    }
+
+    /// 證交所公布處置股 (announcement/punish)
+    async fn crawl_twse_disposition(&self, date: Date) -> Result<Vec<MarketSurveillanceEvent>> {
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/announcement/punish?response=json&startDate={}&endDate={}",
+            twse_date(date),
+            twse_date(date)
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+
+        // 欄位：編號、公布日期、證券代號、證券名稱、累計、處置條件、處置起迄時間、處置措施、處置內容、備註
+        Ok(report
+            .data
+            .iter()
+            .filter_map(|row| {
+                let (start_date, end_date) = Self::parse_period(&cell_text(row, 6))?;
+                Some(MarketSurveillanceEvent {
+                    id: Uuid::new_v4(),
+                    stock_code: cell_text(row, 2),
+                    stock_name: cell_text(row, 3),
+                    market: "TWSE".to_string(),
+                    event_type: SurveillanceType::Disposition,
+                    announced_date: parse_roc_date(&cell_text(row, 1)).unwrap_or(date),
+                    start_date,
+                    end_date,
+                    reason: strip_html(&cell_text(row, 5)),
+                    measure: Some(cell_text(row, 7)).filter(|m| !m.is_empty()),
+                })
+            })
+            .collect())
+    }
+
+    /// 櫃買中心公布注意股
+    async fn crawl_tpex_attention(&self, date: Date) -> Result<Vec<MarketSurveillanceEvent>> {
+        let url = format!(
+            "https://www.tpex.org.tw/web/bulletin/attention_information/trading_attention_information_result.php?l=zh-tw&o=json&sd={}&ed={}",
+            roc_date(date),
+            roc_date(date)
+        );
+        let rows = fetch_tpex_report(&self.client, &url).await?.into_rows();
+
+        // 欄位：編號、證券代號、證券名稱、累計次數、注意交易資訊、公告日期、收盤價、本益比
+        Ok(rows
+            .iter()
+            .filter_map(|row| Self::attention_event(row, "TPEX", date))
+            .collect())
+    }
+
+    /// 櫃買中心公布處置股
+    async fn crawl_tpex_disposition(&self, date: Date) -> Result<Vec<MarketSurveillanceEvent>> {
+        let url = format!(
+            "https://www.tpex.org.tw/web/bulletin/disposal_information/disposal_information_result.php?l=zh-tw&o=json&sd={}&ed={}",
+            roc_date(date),
+            roc_date(date)
+        );
+        let rows = fetch_tpex_report(&self.client, &url).await?.into_rows();
+
+        // 欄位：編號、公布日期、證券代號、證券名稱、累計、處置起訖時間、處置條件、處置內容
+        Ok(rows
+            .iter()
+            .filter_map(|row| {
+                let (start_date, end_date) = Self::parse_period(&cell_text(row, 5))?;
+                let content = strip_html(&cell_text(row, 7));
+                Some(MarketSurveillanceEvent {
+                    id: Uuid::new_v4(),
+                    stock_code: cell_text(row, 2),
+                    stock_name: cell_text(row, 3),
+                    market: "TPEX".to_string(),
+                    event_type: SurveillanceType::Disposition,
+                    announced_date: parse_roc_date(&cell_text(row, 1)).unwrap_or(date),
+                    start_date,
+                    end_date,
+                    reason: strip_html(&cell_text(row, 6)),
+                    // 處置內容的第一行為處置措施，例如「第一次處置」
+                    measure: content.lines().next().map(|m| m.trim().to_string()).filter(|m| !m.is_empty()),
+                })
+            })
+            .collect())
+    }
+
+    /// 將注意股公告的一列轉換為領域模型，注意股的公告期間即為公告當日
+    ///
+    /// 證交所及櫃買中心的注意股欄位順序相同
+    fn attention_event(row: &[Value], market: &str, default_date: Date) -> Option<MarketSurveillanceEvent> {
+        let stock_code = cell_text(row, 1);
+        if stock_code.is_empty() {
+            return None;
+        }
+
+        let announced_date = parse_roc_date(&cell_text(row, 5)).unwrap_or(default_date);
+        Some(MarketSurveillanceEvent {
+            id: Uuid::new_v4(),
+            stock_code,
+            stock_name: cell_text(row, 2),
+            market: market.to_string(),
+            event_type: SurveillanceType::Attention,
+            announced_date,
+            start_date: announced_date,
+            end_date: announced_date,
+            reason: strip_html(&cell_text(row, 4)),
+            measure: None,
+        })
+    }
+
+    /// 解析處置期間，例如 "113/10/16～113/10/29"
+    fn parse_period(text: &str) -> Option<(Date, Date)> {
+        let mut parts = text.split(['～', '~']);
+        let start_date = parse_roc_date(parts.next()?)?;
+        let end_date = parse_roc_date(parts.next()?)?;
+        Some((start_date, end_date))
+    }
+}
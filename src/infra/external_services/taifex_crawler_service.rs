@@ -0,0 +1,217 @@
+// 引入必要的外部庫
+use crate::domain::models::{
+    FuturesDailyPrice, InstitutionType, InstitutionalOpenInterest, OptionType, OptionsDailyPrice,
+};
+use crate::infra::external_services::parsing::{parse_decimal, parse_u64};
+use anyhow::Result;
+use encoding_rs::BIG5;
+use reqwest::Client;
+use time::{Date, Month};
+use tracing::info;
+use uuid::Uuid;
+
+/// 爬取的期貨契約代碼 (臺股期貨、小型臺指)
+pub const FUTURES_CONTRACTS: &[&str] = &["TX", "MTX"];
+
+/// 爬取的選擇權契約代碼 (臺指選擇權)
+pub const OPTIONS_CONTRACTS: &[&str] = &["TXO"];
+
+/// 三大法人未平倉的商品代碼與系統內契約代碼的對照
+const INSTITUTIONAL_CONTRACTS: &[(&str, &str)] = &[("TXF", "TX"), ("MXF", "MTX")];
+
+/// 一般交易時段的標記，盤後交易時段的資料不納入
+const REGULAR_SESSION: &str = "一般";
+
+/// 臺灣期貨交易所 (TAIFEX) 每日行情爬蟲服務
+pub struct TaifexCrawlerService {
+    client: Client,
+}
+
+impl TaifexCrawlerService {
+    /// 創建新的期交所爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 期交所查詢表單使用的日期格式，例如 "2024/01/02"
+    fn taifex_date(date: Date) -> String {
+        format!("{:04}/{:02}/{:02}", date.year(), date.month() as u8, date.day())
+    }
+
+    /// 解析期交所 CSV 的日期欄位
+    fn parse_taifex_date(text: &str) -> Option<Date> {
+        let parts: Vec<&str> = text.trim().split('/').collect();
+        if parts.len() != 3 {
+            return None;
+        }
+
+        let year = parts[0].parse::<i32>().ok()?;
+        let month = Month::try_from(parts[1].parse::<u8>().ok()?).ok()?;
+        let day = parts[2].parse::<u8>().ok()?;
+        Date::from_calendar_date(year, month, day).ok()
+    }
+
+    /// 下載 CSV 並以 BIG5 解碼，回傳去除標題列後的欄位
+    async fn download_csv(&self, url: &str, form: &[(&str, String)]) -> Result<Vec<Vec<String>>> {
+        let response = self.client
+            .post(url)
+            .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+            .form(form)
+            .send()
+            .await?
+            .bytes()
+            .await?;
+
+        let response_text = BIG5.decode(&response).0.into_owned();
+
+        Ok(response_text
+            .lines()
+            .skip(1)
+            .map(|line| line.split(',').map(|cell| cell.trim().to_string()).collect::<Vec<_>>())
+            .filter(|cells| cells.len() > 1)
+            .collect())
+    }
+
+    /// 爬取指定日期的期貨每日行情
+    pub async fn crawl_futures_prices(&self, contract_code: &str, date: Date) -> Result<Vec<FuturesDailyPrice>> {
+        info!("開始爬取 {} {} 的期貨行情...", date, contract_code);
+
+        let rows = self
+            .download_csv(
+                "https://www.taifex.com.tw/cht/3/futDataDown",
+                &[
+                    ("down_type", "1".to_string()),
+                    ("commodity_id", contract_code.to_string()),
+                    ("queryStartDate", Self::taifex_date(date)),
+                    ("queryEndDate", Self::taifex_date(date)),
+                ],
+            )
+            .await?;
+
+        // 欄位：交易日期、契約、到期月份(週別)、開盤價、最高價、最低價、收盤價、漲跌價、漲跌%、
+        // 成交量、結算價、未沖銷契約數、最後最佳買價、最後最佳賣價、歷史最高價、歷史最低價、
+        // 是否因訊息面暫停交易、交易時段
+        let prices: Vec<FuturesDailyPrice> = rows
+            .iter()
+            .filter(|row| row.len() > 17 && row[17] == REGULAR_SESSION)
+            // 價差委託的到期月份形如 "202401/202402"，不屬於單一契約
+            .filter(|row| !row[2].contains('/'))
+            .filter_map(|row| {
+                Some(FuturesDailyPrice {
+                    id: Uuid::new_v4(),
+                    contract_code: row[1].clone(),
+                    delivery_month: row[2].clone(),
+                    date: Self::parse_taifex_date(&row[0])?,
+                    open: parse_decimal(&row[3]),
+                    high: parse_decimal(&row[4]),
+                    low: parse_decimal(&row[5]),
+                    close: parse_decimal(&row[6]),
+                    settlement_price: parse_decimal(&row[10]),
+                    volume: parse_u64(&row[9]).unwrap_or(0),
+                    open_interest: parse_u64(&row[11]).unwrap_or(0),
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆 {} 期貨行情", prices.len(), contract_code);
+        Ok(prices)
+    }
+
+    /// 爬取指定日期的選擇權每日行情
+    pub async fn crawl_options_prices(&self, contract_code: &str, date: Date) -> Result<Vec<OptionsDailyPrice>> {
+        info!("開始爬取 {} {} 的選擇權行情...", date, contract_code);
+
+        let rows = self
+            .download_csv(
+                "https://www.taifex.com.tw/cht/3/optDataDown",
+                &[
+                    ("down_type", "1".to_string()),
+                    ("commodity_id", contract_code.to_string()),
+                    ("queryStartDate", Self::taifex_date(date)),
+                    ("queryEndDate", Self::taifex_date(date)),
+                ],
+            )
+            .await?;
+
+        // 欄位：交易日期、契約、到期月份(週別)、履約價、買賣權、開盤價、最高價、最低價、收盤價、
+        // 成交量、結算價、未沖銷契約數、最後最佳買價、最後最佳賣價、歷史最高價、歷史最低價、
+        // 是否因訊息面暫停交易、交易時段
+        let prices: Vec<OptionsDailyPrice> = rows
+            .iter()
+            .filter(|row| row.len() > 17 && row[17] == REGULAR_SESSION)
+            .filter_map(|row| {
+                Some(OptionsDailyPrice {
+                    id: Uuid::new_v4(),
+                    contract_code: row[1].clone(),
+                    delivery_month: row[2].clone(),
+                    strike_price: parse_decimal(&row[3])?,
+                    option_type: OptionType::parse(&row[4])?,
+                    date: Self::parse_taifex_date(&row[0])?,
+                    open: parse_decimal(&row[5]),
+                    high: parse_decimal(&row[6]),
+                    low: parse_decimal(&row[7]),
+                    close: parse_decimal(&row[8]),
+                    settlement_price: parse_decimal(&row[10]),
+                    volume: parse_u64(&row[9]).unwrap_or(0),
+                    open_interest: parse_u64(&row[11]).unwrap_or(0),
+                })
+            })
+            .collect();
+
+        info!("成功爬取 {} 筆 {} 選擇權行情", prices.len(), contract_code);
+        Ok(prices)
+    }
+
+    /// 爬取指定日期的期貨三大法人未平倉部位
+    pub async fn crawl_institutional_open_interest(&self, date: Date) -> Result<Vec<InstitutionalOpenInterest>> {
+        info!("開始爬取 {} 的期貨三大法人未平倉...", date);
+
+        let mut positions = Vec::new();
+
+        for (commodity_id, contract_code) in INSTITUTIONAL_CONTRACTS {
+            let rows = self
+                .download_csv(
+                    "https://www.taifex.com.tw/cht/3/futContractsDateDown",
+                    &[
+                        ("commodityId", commodity_id.to_string()),
+                        ("queryStartDate", Self::taifex_date(date)),
+                        ("queryEndDate", Self::taifex_date(date)),
+                    ],
+                )
+                .await?;
+
+            // 欄位：日期、商品名稱、身份別、多方交易口數、多方交易契約金額、空方交易口數、
+            // 空方交易契約金額、多空交易口數淨額、多空交易契約金額淨額、多方未平倉口數、
+            // 多方未平倉契約金額、空方未平倉口數、空方未平倉契約金額、多空未平倉口數淨額、
+            // 多空未平倉契約金額淨額
+            for row in rows.iter().filter(|row| row.len() > 14) {
+                let (Some(row_date), Some(institution_type)) = (
+                    Self::parse_taifex_date(&row[0]),
+                    InstitutionType::parse(&row[2]),
+                ) else {
+                    continue;
+                };
+
+                let parse_i64 = |text: &str| text.replace(",", "").parse::<i64>().unwrap_or(0);
+
+                positions.push(InstitutionalOpenInterest {
+                    id: Uuid::new_v4(),
+                    contract_code: contract_code.to_string(),
+                    date: row_date,
+                    institution_type,
+                    long_open_interest: parse_i64(&row[9]),
+                    short_open_interest: parse_i64(&row[11]),
+                    net_open_interest: parse_i64(&row[13]),
+                    long_value: parse_i64(&row[10]),
+                    short_value: parse_i64(&row[12]),
+                    net_value: parse_i64(&row[14]),
+                });
+            }
+        }
+
+        info!("成功爬取 {} 筆期貨三大法人未平倉", positions.len());
+        Ok(positions)
+    }
+}
@@ -0,0 +1,125 @@
+// 引入必要的外部庫
+use crate::domain::models::TreasuryBuyback;
+use crate::infra::external_services::parsing::{parse_decimal, parse_roc_date, parse_u64};
+use anyhow::Result;
+use reqwest::Client;
+use scraper::{Html, Selector};
+use time::Date;
+use tracing::info;
+use uuid::Uuid;
+
+/// 公開資訊觀測站 (MOPS) 庫藏股買回彙總爬蟲服務
+pub struct TreasuryBuybackCrawlerService {
+    client: Client,
+}
+
+impl TreasuryBuybackCrawlerService {
+    /// 創建新的庫藏股爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取董事會決議日期在指定區間內的上市及上櫃公司庫藏股買回計畫
+    pub async fn crawl_buybacks(&self, start_date: Date, end_date: Date) -> Result<Vec<TreasuryBuyback>> {
+        info!("開始爬取 {} ~ {} 的庫藏股買回計畫...", start_date, end_date);
+
+        let mut buybacks = Vec::new();
+        for market in ["sii", "otc"] {
+            let response = self.client
+                .post("https://mops.twse.com.tw/mops/web/ajax_t35sc09")
+                .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+                .form(&[
+                    ("encodeURIComponent", "1".to_string()),
+                    ("step", "1".to_string()),
+                    ("firstin", "1".to_string()),
+                    ("off", "1".to_string()),
+                    ("TYPEK", market.to_string()),
+                    ("d1", Self::roc_date(start_date)),
+                    ("d2", Self::roc_date(end_date)),
+                    ("RD", "1".to_string()),
+                ])
+                .send()
+                .await?
+                .text()
+                .await?;
+
+            // Html 不是 Send，解析放在同步函數中
+            buybacks.extend(Self::parse_buybacks(&response));
+        }
+
+        info!("成功爬取 {} 筆庫藏股買回計畫", buybacks.len());
+        Ok(buybacks)
+    }
+
+    /// 查詢參數使用不含分隔符號的民國日期，例如 "1130502"
+    fn roc_date(date: Date) -> String {
+        format!("{}{:02}{:02}", date.year() - 1911, date.month() as u8, date.day())
+    }
+
+    /// 解析民國日期，可能是 "113/05/02" 或 "1130502"
+    fn parse_date(text: &str) -> Option<Date> {
+        let text = text.trim();
+        if text.len() == 7 && text.chars().all(|c| c.is_ascii_digit()) {
+            return parse_roc_date(&format!("{}/{}/{}", &text[0..3], &text[3..5], &text[5..7]));
+        }
+        parse_roc_date(text)
+    }
+
+    /// 解析庫藏股買回彙總表
+    ///
+    /// 欄位：公司代號、公司名稱、董事會決議日期、買回目的、買回股份總金額上限、預定買回股數、
+    /// 買回價格區間最低、最高、預定買回期間起、迄、是否執行完畢、本次已買回股數、
+    /// 本次執行完畢已註銷或轉讓股數、本次已買回股數佔預定買回股數比例、本次已買回總金額...
+    fn parse_buybacks(html: &str) -> Vec<TreasuryBuyback> {
+        let document = Html::parse_document(html);
+        let tr_selector = Selector::parse("table tr").unwrap();
+        let td_selector = Selector::parse("td").unwrap();
+
+        let mut buybacks = Vec::new();
+
+        for row in document.select(&tr_selector) {
+            let cells: Vec<String> = row
+                .select(&td_selector)
+                .map(|td| td.text().collect::<String>().trim().to_string())
+                .collect();
+            if cells.len() < 15 {
+                continue;
+            }
+
+            let (Some(resolution_date), Some(period_start), Some(period_end)) = (
+                Self::parse_date(&cells[2]),
+                Self::parse_date(&cells[8]),
+                Self::parse_date(&cells[9]),
+            ) else {
+                continue;
+            };
+            let (Some(planned_shares), Some(price_low), Some(price_high)) = (
+                parse_u64(&cells[5]),
+                parse_decimal(&cells[6]),
+                parse_decimal(&cells[7]),
+            ) else {
+                continue;
+            };
+
+            buybacks.push(TreasuryBuyback {
+                id: Uuid::new_v4(),
+                stock_code: cells[0].clone(),
+                company_name: cells[1].clone(),
+                resolution_date,
+                purpose: cells[3].clone(),
+                planned_shares,
+                price_low,
+                price_high,
+                period_start,
+                period_end,
+                executed_shares: parse_u64(&cells[11]).unwrap_or(0),
+                executed_amount: parse_u64(&cells[14]).unwrap_or(0),
+                is_completed: cells[10].contains('是') || cells[10].eq_ignore_ascii_case("Y"),
+            });
+        }
+
+        buybacks
+    }
+}
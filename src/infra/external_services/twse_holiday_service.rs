@@ -0,0 +1,108 @@
+// 引入必要的外部庫
+use crate::domain::services::TradingCalendar;
+use crate::infra::external_services::parsing::parse_roc_date;
+use crate::infra::external_services::twse_report::{cell_text, fetch_twse_report};
+use anyhow::Result;
+use reqwest::Client;
+use time::Date;
+use tracing::info;
+
+/// 休市表中一筆日期對交易日曆的影響
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScheduleEntry {
+    Holiday,      // 休市
+    MakeupDay,    // 週末補行交易
+}
+
+/// 證交所市場開休市日期服務
+pub struct TwseHolidayService {
+    client: Client,
+}
+
+impl TwseHolidayService {
+    /// 創建新的開休市日期服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 讀取指定西元年度的休市表並加入交易日曆，回傳加入的日期數
+    pub async fn load_holiday_schedule(&self, year: i32, calendar: &mut TradingCalendar) -> Result<usize> {
+        let url = format!(
+            "https://www.twse.com.tw/rwd/zh/holidaySchedule/holidaySchedule?response=json&queryYear={}",
+            year - 1911
+        );
+        let report = fetch_twse_report(&self.client, &url).await?;
+
+        let mut count = 0;
+        // 欄位：日期、名稱、說明
+        for row in &report.data {
+            let date_text = cell_text(row, 0);
+            let Some(date) = Self::parse_schedule_date(&date_text) else {
+                continue;
+            };
+            if date.year() != year {
+                continue;
+            }
+
+            let text = format!("{} {}", cell_text(row, 1), cell_text(row, 2));
+            match Self::classify(&text) {
+                Some(ScheduleEntry::Holiday) => calendar.add_holiday(date),
+                Some(ScheduleEntry::MakeupDay) => calendar.add_makeup_day(date),
+                None => continue,
+            }
+            count += 1;
+        }
+
+        info!("成功讀取 {} 年休市表，共 {} 筆", year, count);
+        Ok(count)
+    }
+
+    /// 依名稱及說明判斷該日期是休市、補行交易或只是提示
+    fn classify(text: &str) -> Option<ScheduleEntry> {
+        // 「開始交易日」、「最後交易日」只是提示，當天仍照常交易
+        if text.contains("開始交易") || text.contains("最後交易") {
+            return None;
+        }
+
+        if text.contains("補行") {
+            // 補行上班日多半不開市，只有明確標示交易時才視為交易日
+            let is_trading = text.contains("交易") && !text.contains("不交易") && !text.contains("無交易");
+            return is_trading.then_some(ScheduleEntry::MakeupDay);
+        }
+
+        Some(ScheduleEntry::Holiday)
+    }
+
+    /// 休市表日期可能是西元 "2024-01-01" 或民國 "113/01/01"
+    fn parse_schedule_date(text: &str) -> Option<Date> {
+        Date::parse(text, &time::format_description::well_known::Iso8601::DATE)
+            .ok()
+            .or_else(|| parse_roc_date(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn makeup_workday_without_trading_marker_is_not_a_trading_day() {
+        assert_eq!(TwseHolidayService::classify("補行上班日 補行上班"), None);
+    }
+
+    #[test]
+    fn makeup_day_is_a_trading_day_only_when_marked() {
+        assert_eq!(TwseHolidayService::classify("補行上班日 補行上班，照常交易"), Some(ScheduleEntry::MakeupDay));
+        assert_eq!(TwseHolidayService::classify("補行上班日 補行上班，不交易"), None);
+        assert_eq!(TwseHolidayService::classify("補行上班日 補行上班，無交易"), None);
+    }
+
+    #[test]
+    fn trading_hints_are_ignored_and_other_rows_are_holidays() {
+        assert_eq!(TwseHolidayService::classify("國曆新年開始交易日 國曆新年開始交易"), None);
+        assert_eq!(TwseHolidayService::classify("農曆春節前最後交易日 "), None);
+        assert_eq!(TwseHolidayService::classify("國慶日 依規定放假1日"), Some(ScheduleEntry::Holiday));
+    }
+}
@@ -0,0 +1,143 @@
+// 引入必要的外部庫
+use crate::domain::models::{IntradayQuote, OrderBookLevel};
+use crate::infra::external_services::parsing::{parse_decimal, parse_u64};
+use anyhow::Result;
+use bigdecimal::Zero;
+use reqwest::Client;
+use serde::Deserialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+use tracing::info;
+
+/// MIS 每次請求的股票數量上限，過多代碼會被伺服器拒絕
+const MAX_CODES_PER_REQUEST: usize = 50;
+
+/// MIS 的量以「張」為單位，轉換為股數
+const SHARES_PER_LOT: u64 = 1000;
+
+#[derive(Debug, Deserialize)]
+struct MisResponse {
+    #[serde(rename = "msgArray", default)]
+    msg_array: Vec<MisQuote>,
+}
+
+/// getStockInfo.jsp 回傳的單筆報價，欄位名稱沿用 MIS 的縮寫
+#[derive(Debug, Deserialize)]
+struct MisQuote {
+    c: String,              // 股票代碼
+    #[serde(default)]
+    n: String,              // 股票名稱
+    #[serde(default)]
+    z: String,              // 最近成交價
+    #[serde(default)]
+    v: String,              // 累積成交量 (張)
+    #[serde(default)]
+    o: String,              // 開盤價
+    #[serde(default)]
+    h: String,              // 最高價
+    #[serde(default)]
+    l: String,              // 最低價
+    #[serde(default)]
+    y: String,              // 昨收價
+    #[serde(default)]
+    a: String,              // 五檔賣價，以 "_" 分隔
+    #[serde(default)]
+    f: String,              // 五檔賣量 (張)
+    #[serde(default)]
+    b: String,              // 五檔買價
+    #[serde(default)]
+    g: String,              // 五檔買量 (張)
+    #[serde(default)]
+    tlong: String,          // 報價時間 (epoch 毫秒)
+}
+
+/// 證交所基本市況報導網站 (mis.twse.com.tw) 即時報價服務
+pub struct TwseMisService {
+    client: Client,
+}
+
+impl TwseMisService {
+    /// 創建新的 MIS 即時報價服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 取得多支股票的即時報價
+    pub async fn fetch_quotes(&self, stock_codes: &[String]) -> Result<Vec<IntradayQuote>> {
+        let mut quotes = Vec::new();
+
+        for chunk in stock_codes.chunks(MAX_CODES_PER_REQUEST) {
+            // 不確定上市或上櫃，兩個市場都查詢，MIS 只會回傳存在的代碼
+            let ex_ch = chunk
+                .iter()
+                .map(|code| format!("tse_{}.tw|otc_{}.tw", code, code))
+                .collect::<Vec<_>>()
+                .join("|");
+
+            let url = format!(
+                "https://mis.twse.com.tw/stock/api/getStockInfo.jsp?ex_ch={}&json=1&delay=0",
+                ex_ch
+            );
+
+            let response: MisResponse = self.client
+                .get(&url)
+                .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+                .header("Referer", "https://mis.twse.com.tw/stock/index.jsp")
+                .send()
+                .await?
+                .json()
+                .await?;
+
+            for raw in response.msg_array {
+                if let Some(quote) = self.parse_quote(raw) {
+                    quotes.push(quote);
+                }
+            }
+        }
+
+        info!("成功取得 {} 筆即時報價", quotes.len());
+        Ok(quotes)
+    }
+
+    /// 將 MIS 報價轉換為領域模型
+    fn parse_quote(&self, raw: MisQuote) -> Option<IntradayQuote> {
+        let millis = raw.tlong.parse::<i128>().ok()?;
+        let quote_time = OffsetDateTime::from_unix_timestamp_nanos(millis * 1_000_000).ok()?;
+
+        Some(IntradayQuote {
+            id: Uuid::new_v4(),
+            stock_code: raw.c,
+            stock_name: raw.n,
+            quote_time,
+            last_price: parse_decimal(&raw.z),
+            accumulated_volume: parse_u64(&raw.v).unwrap_or(0) * SHARES_PER_LOT,
+            open: parse_decimal(&raw.o),
+            high: parse_decimal(&raw.h),
+            low: parse_decimal(&raw.l),
+            prev_close: parse_decimal(&raw.y),
+            bids: self.parse_order_book(&raw.b, &raw.g),
+            asks: self.parse_order_book(&raw.a, &raw.f),
+        })
+    }
+
+    /// 解析以 "_" 分隔的五檔價量
+    fn parse_order_book(&self, prices: &str, volumes: &str) -> Vec<OrderBookLevel> {
+        prices
+            .split('_')
+            .zip(volumes.split('_'))
+            .filter_map(|(price, volume)| {
+                let price = parse_decimal(price)?;
+                // 價格為 0 代表該檔為市價委託或尚無委託
+                if price.is_zero() {
+                    return None;
+                }
+                Some(OrderBookLevel {
+                    price,
+                    volume: parse_u64(volume).unwrap_or(0) * SHARES_PER_LOT,
+                })
+            })
+            .collect()
+    }
+}
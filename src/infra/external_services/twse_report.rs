@@ -0,0 +1,103 @@
+use anyhow::Result;
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::Value;
+use time::Date;
+
+/// 證交所 rwd JSON 報表的單一表格
+#[derive(Debug, Default, Deserialize)]
+pub struct TwseReportTable {
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub data: Vec<Vec<Value>>,
+}
+
+/// 證交所 rwd JSON 報表，單表報表的欄位在最外層，多表報表放在 tables 中
+#[derive(Debug, Default, Deserialize)]
+pub struct TwseReport {
+    #[serde(default)]
+    pub stat: String,
+    #[serde(default)]
+    pub fields: Vec<String>,
+    #[serde(default)]
+    pub data: Vec<Vec<Value>>,
+    #[serde(default)]
+    pub tables: Vec<TwseReportTable>,
+}
+
+impl TwseReport {
+    /// 查詢成功時 stat 為 "OK"，非交易日或查無資料時為說明文字
+    pub fn is_ok(&self) -> bool {
+        self.stat == "OK"
+    }
+}
+
+/// 取得證交所報表
+pub async fn fetch_twse_report(client: &Client, url: &str) -> Result<TwseReport> {
+    let report = client
+        .get(url)
+        .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+        .send()
+        .await?
+        .json::<TwseReport>()
+        .await?;
+
+    Ok(report)
+}
+
+/// 將報表儲存格轉為去除前後空白的文字
+pub fn cell_text(row: &[Value], index: usize) -> String {
+    match row.get(index) {
+        Some(Value::String(s)) => s.trim().to_string(),
+        Some(Value::Null) | None => String::new(),
+        Some(v) => v.to_string(),
+    }
+}
+
+/// 依欄位名稱找出欄位位置，報表改版調整欄位順序時仍能正確取值
+pub fn field_index(fields: &[String], name: &str) -> Option<usize> {
+    fields.iter().position(|f| f.trim() == name)
+}
+
+/// 證交所報表查詢參數使用的日期格式，例如 20250102
+pub fn twse_date(date: Date) -> String {
+    format!("{:04}{:02}{:02}", date.year(), date.month() as u8, date.day())
+}
+
+/// 櫃買中心 JSON 報表，舊版資料放在 aaData，新版放在 tables
+#[derive(Debug, Default, Deserialize)]
+pub struct TpexReport {
+    #[serde(rename = "aaData", default)]
+    pub aa_data: Vec<Vec<Value>>,
+    #[serde(default)]
+    pub tables: Vec<TwseReportTable>,
+}
+
+impl TpexReport {
+    /// 取得報表資料列
+    pub fn into_rows(self) -> Vec<Vec<Value>> {
+        if !self.aa_data.is_empty() {
+            return self.aa_data;
+        }
+        self.tables.into_iter().next().map(|t| t.data).unwrap_or_default()
+    }
+}
+
+/// 取得櫃買中心報表
+pub async fn fetch_tpex_report(client: &Client, url: &str) -> Result<TpexReport> {
+    let report = client
+        .get(url)
+        .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+        .send()
+        .await?
+        .json::<TpexReport>()
+        .await?;
+
+    Ok(report)
+}
+
+/// 櫃買中心報表查詢參數使用的民國日期格式，例如 113/01/02
+pub fn roc_date(date: Date) -> String {
+    format!("{}/{:02}/{:02}", date.year() - 1911, date.month() as u8, date.day())
+}
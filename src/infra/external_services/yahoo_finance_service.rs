@@ -0,0 +1,168 @@
+// 引入必要的外部庫
+use crate::domain::models::{PriceSource, StockPrice};
+use crate::domain::services::MarketClock;
+use anyhow::{Result, anyhow};
+use bigdecimal::BigDecimal;
+use reqwest::Client;
+use serde::Deserialize;
+use std::str::FromStr;
+use time::{Date, OffsetDateTime};
+use tracing::info;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+struct ChartResponse {
+    chart: Chart,
+}
+
+#[derive(Debug, Deserialize)]
+struct Chart {
+    #[serde(default)]
+    result: Option<Vec<ChartResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChartResult {
+    #[serde(default)]
+    timestamp: Vec<i64>,
+    indicators: ChartIndicators,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChartIndicators {
+    #[serde(default)]
+    quote: Vec<ChartQuote>,
+}
+
+/// 日 K 欄位，停牌或無成交的日期為 null
+#[derive(Debug, Deserialize)]
+struct ChartQuote {
+    #[serde(default)]
+    open: Vec<Option<f64>>,
+    #[serde(default)]
+    high: Vec<Option<f64>>,
+    #[serde(default)]
+    low: Vec<Option<f64>>,
+    #[serde(default)]
+    close: Vec<Option<f64>>,
+    #[serde(default)]
+    volume: Vec<Option<u64>>,
+}
+
+/// Yahoo Finance 日 K 爬蟲服務，作為證交所無法取得股價時的備援來源
+pub struct YahooFinanceService {
+    client: Client,
+}
+
+impl YahooFinanceService {
+    /// 創建新的 Yahoo Finance 爬蟲服務實例
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// 爬取最近一個月的日 K，先以上市代號 (.TW) 查詢，查無資料時改用上櫃代號 (.TWO)
+    pub async fn crawl_stock_prices(&self, stock_code: &str) -> Result<Vec<StockPrice>> {
+        self.crawl_symbols(stock_code, "range=1mo").await
+    }
+
+    /// 爬取起訖日之間 (皆包含) 的日 K
+    pub async fn crawl_stock_prices_between(&self, stock_code: &str, start_date: Date, end_date: Date) -> Result<Vec<StockPrice>> {
+        let period_start = start_date.midnight().assume_offset(MarketClock::offset()).unix_timestamp();
+        let period_end = end_date.next_day().unwrap_or(end_date).midnight().assume_offset(MarketClock::offset()).unix_timestamp();
+        self.crawl_symbols(stock_code, &format!("period1={}&period2={}", period_start, period_end)).await
+    }
+
+    async fn crawl_symbols(&self, stock_code: &str, range_query: &str) -> Result<Vec<StockPrice>> {
+        info!("開始從 Yahoo Finance 爬取股票 {} 的價格數據...", stock_code);
+
+        let mut prices = self.crawl_chart(&format!("{}.TW", stock_code), range_query).await?;
+        if prices.is_empty() {
+            prices = self.crawl_chart(&format!("{}.TWO", stock_code), range_query).await?;
+        }
+
+        info!("成功從 Yahoo Finance 爬取股票 {} 的 {} 筆價格數據", stock_code, prices.len());
+        Ok(prices)
+    }
+
+    async fn crawl_chart(&self, symbol: &str, range_query: &str) -> Result<Vec<StockPrice>> {
+        let url = format!(
+            "https://query1.finance.yahoo.com/v8/finance/chart/{}?{}&interval=1d",
+            symbol, range_query
+        );
+
+        let response = self.client
+            .get(&url)
+            .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+            .send()
+            .await?;
+
+        // 查無代號時 Yahoo 回傳 404
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+
+        let response = response
+            .error_for_status()?
+            .json::<ChartResponse>()
+            .await?;
+
+        let Some(result) = response.chart.result.and_then(|results| results.into_iter().next()) else {
+            return Ok(Vec::new());
+        };
+        let quote = result
+            .indicators
+            .quote
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("Yahoo Finance 回應缺少報價資料: {}", symbol))?;
+
+        let mut prices: Vec<StockPrice> = Vec::new();
+        for (i, timestamp) in result.timestamp.iter().enumerate() {
+            let field = |values: &[Option<f64>]| values.get(i).copied().flatten().and_then(Self::to_decimal);
+            let (Some(open), Some(high), Some(low), Some(close)) =
+                (field(&quote.open), field(&quote.high), field(&quote.low), field(&quote.close))
+            else {
+                continue;
+            };
+
+            // 時間戳為開盤時間，換算為台北時間的交易日期
+            let date = OffsetDateTime::from_unix_timestamp(*timestamp)?
+                .to_offset(MarketClock::offset())
+                .date();
+            let volume = quote.volume.get(i).copied().flatten().unwrap_or(0);
+
+            let mut price = StockPrice::new(
+                Uuid::nil(),
+                date,
+                open,
+                high,
+                low,
+                close,
+                volume,
+                0,
+                0,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+            price.source = PriceSource::Yahoo;
+            if let Some(previous) = prices.last() {
+                price.calculate_change(previous.close.clone());
+            }
+            prices.push(price);
+        }
+
+        Ok(prices)
+    }
+
+    /// Yahoo 以浮點數表示價格，台股價格最多兩位小數
+    fn to_decimal(value: f64) -> Option<BigDecimal> {
+        BigDecimal::from_str(&format!("{:.2}", value)).ok()
+    }
+}
@@ -1,20 +1,75 @@
 // 引入應用層服務
-use crate::application::services::{StockPriceService, StockService, NotificationService};
+use crate::application::services::{StockPriceService, StockService, NotificationService, IntradayQuoteService, IntradayBarService, IndexService, DerivativesService, MarketSurveillanceService, MaterialAnnouncementService, ForeignOwnershipService, DayTradingService, OffSessionTradingService, BlockTradeService, InsiderHoldingService, TreasuryBuybackService, PublicSubscriptionService, EtfService, ConvertibleBondService, PriceReconciliationService, PriceGapService, StockListingService};
 // 引入應用層 DTO
-use crate::application::dtos::{CreateStockDto, CreateStockPriceDto};
+use crate::application::dtos::{CreateStockDto, CreateStockPriceDto, CreateStockPriceOutcome};
 // 引入基礎設施層的爬蟲服務
 use crate::infra::external_services::stock_crawler_service::StockCrawlerService;
+use crate::infra::external_services::twse_mis_service::TwseMisService;
+use crate::infra::external_services::index_crawler_service::IndexCrawlerService;
+use crate::infra::external_services::twse_holiday_service::TwseHolidayService;
+use crate::infra::external_services::surveillance_crawler_service::SurveillanceCrawlerService;
+use crate::infra::external_services::mops_crawler_service::MopsCrawlerService;
+use crate::infra::external_services::foreign_ownership_crawler_service::ForeignOwnershipCrawlerService;
+use crate::infra::external_services::day_trading_crawler_service::DayTradingCrawlerService;
+use crate::infra::external_services::off_session_trading_crawler_service::OffSessionTradingCrawlerService;
+use crate::infra::external_services::block_trade_crawler_service::BlockTradeCrawlerService;
+use crate::infra::external_services::insider_holding_crawler_service::InsiderHoldingCrawlerService;
+use crate::infra::external_services::treasury_buyback_crawler_service::TreasuryBuybackCrawlerService;
+use crate::infra::external_services::public_subscription_crawler_service::PublicSubscriptionCrawlerService;
+use crate::infra::external_services::etf_crawler_service::EtfCrawlerService;
+use crate::infra::external_services::convertible_bond_crawler_service::ConvertibleBondCrawlerService;
+use crate::infra::external_services::yahoo_finance_service::YahooFinanceService;
+use crate::infra::external_services::taifex_crawler_service::{TaifexCrawlerService, FUTURES_CONTRACTS, OPTIONS_CONTRACTS};
 // 引入基礎設施層的資料庫儲存庫實現
 use crate::infra::db::postgres_stock_repository::PostgresStockRepository;
 use crate::infra::db::postgres_stock_price_repository::PostgresStockPriceRepository;
+use crate::infra::db::postgres_intraday_quote_repository::PostgresIntradayQuoteRepository;
+use crate::infra::db::postgres_intraday_bar_repository::PostgresIntradayBarRepository;
+use crate::infra::db::postgres_index_level_repository::PostgresIndexLevelRepository;
+use crate::infra::db::postgres_derivatives_repository::PostgresDerivativesRepository;
+use crate::infra::db::postgres_market_surveillance_repository::PostgresMarketSurveillanceRepository;
+use crate::infra::db::postgres_material_announcement_repository::PostgresMaterialAnnouncementRepository;
+use crate::infra::db::postgres_foreign_ownership_repository::PostgresForeignOwnershipRepository;
+use crate::infra::db::postgres_day_trading_repository::PostgresDayTradingRepository;
+use crate::infra::db::postgres_off_session_trading_repository::PostgresOffSessionTradingRepository;
+use crate::infra::db::postgres_block_trade_repository::PostgresBlockTradeRepository;
+use crate::infra::db::postgres_insider_holding_repository::PostgresInsiderHoldingRepository;
+use crate::infra::db::postgres_treasury_buyback_repository::PostgresTreasuryBuybackRepository;
+use crate::infra::db::postgres_public_subscription_repository::PostgresPublicSubscriptionRepository;
+use crate::infra::db::postgres_etf_repository::PostgresEtfRepository;
+use crate::infra::db::postgres_convertible_bond_repository::PostgresConvertibleBondRepository;
+use crate::infra::db::postgres_shares_outstanding_repository::PostgresSharesOutstandingRepository;
+use crate::infra::db::postgres_price_discrepancy_repository::PostgresPriceDiscrepancyRepository;
+use crate::infra::db::postgres_quarantined_price_repository::PostgresQuarantinedPriceRepository;
+use crate::infra::db::postgres_price_gap_repository::PostgresPriceGapRepository;
+use crate::infra::db::postgres_isin_listing_repository::PostgresIsinListingRepository;
+use crate::infra::db::postgres_universe_change_repository::PostgresUniverseChangeRepository;
 // 引入資料庫連接池創建函數
 use crate::infra::db::database::create_pool;
 // 引入表現層控制器和處理函數
 use crate::api::controllers::{
-    stock_controller::StockController, 
-    stock_price_controller::StockPriceController,
+    stock_controller::{StockController, get_all_stocks, get_stock_by_code},
+    stock_price_controller::{StockPriceController, get_price_limit, get_stock_prices_by_stock_id},
+    intraday_quote_controller::{IntradayQuoteController, get_all_intraday_quotes, get_intraday_quote},
+    intraday_bar_controller::{IntradayBarController, get_intraday_bars},
+    index_controller::{IndexController, get_index_prices},
+    market_controller::{MarketController, get_market_status},
+    market_surveillance_controller::{MarketSurveillanceController, get_surveillance_events},
+    material_announcement_controller::{MaterialAnnouncementController, get_announcements, get_stock_announcements},
+    foreign_ownership_controller::{ForeignOwnershipController, get_foreign_ownership},
+    off_session_trading_controller::{OffSessionTradingController, get_after_hours_trades, get_odd_lot_quotes},
+    insider_holding_controller::{InsiderHoldingController, get_insider_holdings},
+    treasury_buyback_controller::{TreasuryBuybackController, get_treasury_buybacks},
+    public_subscription_controller::{PublicSubscriptionController, get_public_subscriptions},
+    etf_controller::{EtfController, get_etf_constituents, get_etf_holders, get_etf_navs},
+    price_reconciliation_controller::{PriceReconciliationController, get_price_discrepancies},
+    price_gap_controller::{PriceGapController, get_price_coverage},
+    universe_controller::{UniverseController, get_universe_changes},
+    derivatives_controller::{
+        DerivativesController, get_futures_prices, get_institutional_open_interest,
+        get_futures_basis, get_put_call_ratios,
+    },
 };
-use crate::api::routes::{get_all_stocks, get_stock_by_code, get_stock_prices_by_stock_id};
 // 引入 Axum Web 框架相關組件
 use axum::{
     Router,
@@ -22,38 +77,68 @@ use axum::{
 };
 // 引入環境變數處理庫
 use dotenv::dotenv;
+// 引入高精度十進位數
+use bigdecimal::BigDecimal;
 // 引入標準庫的錯誤處理模組
+use std::collections::BTreeMap;
 use std::error::Error;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 // 引入 Tokio 非同步運行時
 use tokio::net::TcpListener;
 use tokio::time as tokio_time;
 // 引入 time 庫
-use time::{OffsetDateTime, UtcOffset};
+use time::OffsetDateTime;
 // 引入日誌記錄相關組件
-use tracing::{info, error};
+use tracing::{info, error, warn};
 // 引入日誌訂閱器
 use tracing_subscriber;
 
 // 引入領域實體
-use crate::domain::models::StockPrice;
-
-// 引入 Uuid 類別
-use uuid::Uuid;
+use crate::domain::models::{PriceGap, PriceGapStatus, PriceSource, StockPrice};
+use crate::domain::repositories::IntradayQuoteRepository;
+use crate::domain::services::{MarketClock, TradingCalendar, TradingCalendarOverrides};
+use crate::domain::value_objects::{KeywordFilter, Watchlist};
 
 // 定義 AppState 結構體
 #[derive(Clone)]
 pub struct AppState {
     stock_controller: Arc<StockController>,
     price_controller: Arc<StockPriceController>,
+    intraday_quote_controller: Arc<IntradayQuoteController>,
+    intraday_bar_controller: Arc<IntradayBarController>,
+    index_controller: Arc<IndexController>,
+    derivatives_controller: Arc<DerivativesController>,
+    market_controller: Arc<MarketController>,
+    market_surveillance_controller: Arc<MarketSurveillanceController>,
+    material_announcement_controller: Arc<MaterialAnnouncementController>,
+    foreign_ownership_controller: Arc<ForeignOwnershipController>,
+    off_session_trading_controller: Arc<OffSessionTradingController>,
+    insider_holding_controller: Arc<InsiderHoldingController>,
+    treasury_buyback_controller: Arc<TreasuryBuybackController>,
+    public_subscription_controller: Arc<PublicSubscriptionController>,
+    etf_controller: Arc<EtfController>,
+    price_reconciliation_controller: Arc<PriceReconciliationController>,
+    price_gap_controller: Arc<PriceGapController>,
+    universe_controller: Arc<UniverseController>,
 }
 
 // 程式入口點
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     // 初始化系統
-    let (_pool, stock_service, price_service, stock_controller, price_controller) = match initialize_system().await {
+    let SystemContext {
+        pool,
+        market_clock,
+        stock_service,
+        price_service,
+        stock_controller,
+        price_controller,
+        market_surveillance_service,
+        foreign_ownership_service,
+        convertible_bond_service,
+    } = match initialize_system().await {
         Ok(result) => result,
         Err(e) => {
             error!("系統初始化失敗: {}", e);
@@ -61,21 +146,262 @@ async fn main() -> Result<(), Box<dyn Error>> {
         }
     };
     
+    // 讀取自選股清單
+    let watchlist = Watchlist::parse(&std::env::var("WATCHLIST").unwrap_or_default());
+    
+    let market_controller = Arc::new(MarketController::new(market_clock.clone()));
+    let market_surveillance_controller = Arc::new(MarketSurveillanceController::new(market_surveillance_service.clone()));
+    let foreign_ownership_controller = Arc::new(ForeignOwnershipController::new(foreign_ownership_service.clone()));
+    
+    // 初始化重大訊息服務，自選股的重大訊息符合 MATERIAL_INFO_KEYWORDS 時推播
+    let material_announcement_service = Arc::new(MaterialAnnouncementService::new(
+        Arc::new(PostgresMaterialAnnouncementRepository::new((*pool).clone())),
+        watchlist.clone(),
+        KeywordFilter::parse(&std::env::var("MATERIAL_INFO_KEYWORDS").unwrap_or_default()),
+    ));
+    let material_announcement_controller = Arc::new(MaterialAnnouncementController::new(material_announcement_service.clone()));
+    
+    // 初始化盤中即時報價服務，設定 INTRADAY_PERSIST=true 時才寫入資料庫
+    let persist_intraday = std::env::var("INTRADAY_PERSIST")
+        .map(|v| v == "true" || v == "1")
+        .unwrap_or(false);
+    let intraday_quote_repo: Option<Arc<dyn IntradayQuoteRepository>> = if persist_intraday {
+        Some(Arc::new(PostgresIntradayQuoteRepository::new((*pool).clone())))
+    } else {
+        None
+    };
+    let intraday_quote_service = Arc::new(IntradayQuoteService::new(
+        Arc::new(TwseMisService::new()),
+        intraday_quote_repo,
+    ));
+    let intraday_quote_controller = Arc::new(IntradayQuoteController::new(intraday_quote_service.clone()));
+    
+    // 初始化盤中 K 棒服務
+    let intraday_bar_service = Arc::new(IntradayBarService::new(
+        Arc::new(PostgresIntradayBarRepository::new((*pool).clone())),
+        Arc::new(PostgresStockRepository::new((*pool).clone())),
+    ));
+    let intraday_bar_controller = Arc::new(IntradayBarController::new(intraday_bar_service.clone()));
+    
+    // 初始化指數服務
+    let index_service = Arc::new(IndexService::new(
+        Arc::new(PostgresIndexLevelRepository::new((*pool).clone())),
+    ));
+    let index_controller = Arc::new(IndexController::new(index_service.clone()));
+    
+    // 初始化期貨選擇權服務
+    let derivatives_service = Arc::new(DerivativesService::new(
+        Arc::new(PostgresDerivativesRepository::new((*pool).clone())),
+        Arc::new(PostgresIndexLevelRepository::new((*pool).clone())),
+    ));
+    let derivatives_controller = Arc::new(DerivativesController::new(derivatives_service.clone()));
+    
+    // 初始化當沖統計服務，當沖量隨股價查詢一併回傳
+    let day_trading_service = Arc::new(DayTradingService::new(
+        Arc::new(PostgresDayTradingRepository::new((*pool).clone())),
+    ));
+    
+    // 初始化盤中零股及盤後定價服務，與一般交易時段的股價分開保存
+    let off_session_trading_service = Arc::new(OffSessionTradingService::new(
+        Arc::new(PostgresOffSessionTradingRepository::new((*pool).clone())),
+    ));
+    let off_session_trading_controller = Arc::new(OffSessionTradingController::new(off_session_trading_service.clone()));
+    
+    // 初始化鉅額交易服務，鉅額交易隨個股每日股價一併回傳
+    let block_trade_service = Arc::new(BlockTradeService::new(
+        Arc::new(PostgresBlockTradeRepository::new((*pool).clone())),
+    ));
+    
+    // 初始化董監持股服務，自選股的董監設質比率升破 PLEDGE_RATIO_ALERT_THRESHOLD (%) 時推播
+    let pledge_alert_threshold = std::env::var("PLEDGE_RATIO_ALERT_THRESHOLD")
+        .ok()
+        .and_then(|v| BigDecimal::from_str(&v).ok())
+        .unwrap_or_else(|| BigDecimal::from(30));
+    let insider_holding_service = Arc::new(InsiderHoldingService::new(
+        Arc::new(PostgresInsiderHoldingRepository::new((*pool).clone())),
+        foreign_ownership_service.clone(),
+        watchlist.clone(),
+        pledge_alert_threshold,
+    ));
+    let insider_holding_controller = Arc::new(InsiderHoldingController::new(insider_holding_service.clone()));
+    
+    // 初始化庫藏股服務，自選股公告新的買回計畫時推播
+    let treasury_buyback_service = Arc::new(TreasuryBuybackService::new(
+        Arc::new(PostgresTreasuryBuybackRepository::new((*pool).clone())),
+        watchlist.clone(),
+    ));
+    let treasury_buyback_controller = Arc::new(TreasuryBuybackController::new(treasury_buyback_service.clone()));
+    
+    // 初始化公開申購服務，新股掛牌時寫入 stocks
+    let public_subscription_service = Arc::new(PublicSubscriptionService::new(
+        Arc::new(PostgresPublicSubscriptionRepository::new((*pool).clone())),
+        Arc::new(PostgresStockRepository::new((*pool).clone())),
+        Arc::new(PostgresStockPriceRepository::new((*pool).clone())),
+        market_clock.clone(),
+    ));
+    let public_subscription_controller = Arc::new(PublicSubscriptionController::new(public_subscription_service.clone()));
+    
+    // 初始化 ETF 服務，ETF_CONSTITUENT_CODES 指定要爬取成分股的 ETF
+    let etf_service = Arc::new(EtfService::new(
+        Arc::new(PostgresEtfRepository::new((*pool).clone())),
+    ));
+    let etf_controller = Arc::new(EtfController::new(etf_service.clone()));
+    let etf_constituent_codes: Vec<String> = std::env::var("ETF_CONSTITUENT_CODES")
+        .unwrap_or_else(|_| "0050,0056".to_string())
+        .split(',')
+        .map(|code| code.trim().to_string())
+        .filter(|code| !code.is_empty())
+        .collect();
+    
+    // 初始化跨來源股價比對服務，開高低收差異超過 PRICE_RECONCILIATION_TOLERANCE_PERCENT (%)、
+    // 成交量差異超過 PRICE_RECONCILIATION_VOLUME_TOLERANCE_PERCENT (%) 的欄位會被記錄
+    let reconciliation_tolerance = std::env::var("PRICE_RECONCILIATION_TOLERANCE_PERCENT")
+        .ok()
+        .and_then(|v| BigDecimal::from_str(&v).ok())
+        .unwrap_or_else(|| BigDecimal::from_str("0.5").unwrap());
+    let reconciliation_volume_tolerance = std::env::var("PRICE_RECONCILIATION_VOLUME_TOLERANCE_PERCENT")
+        .ok()
+        .and_then(|v| BigDecimal::from_str(&v).ok())
+        .unwrap_or_else(|| BigDecimal::from(5));
+    let price_reconciliation_service = Arc::new(PriceReconciliationService::new(
+        Arc::new(PostgresPriceDiscrepancyRepository::new((*pool).clone())),
+        Arc::new(PostgresStockPriceRepository::new((*pool).clone())),
+        Arc::new(PostgresStockRepository::new((*pool).clone())),
+        reconciliation_tolerance,
+        reconciliation_volume_tolerance,
+    ));
+    let price_reconciliation_controller = Arc::new(PriceReconciliationController::new(price_reconciliation_service.clone()));
+    
+    // 初始化日 K 缺漏偵測服務，檢查最近 PRICE_GAP_LOOKBACK_DAYS 天內的交易日
+    let price_gap_lookback_days = std::env::var("PRICE_GAP_LOOKBACK_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(30);
+    let price_gap_service = Arc::new(PriceGapService::new(
+        Arc::new(PostgresPriceGapRepository::new((*pool).clone())),
+        Arc::new(PostgresStockPriceRepository::new((*pool).clone())),
+        Arc::new(PostgresQuarantinedPriceRepository::new((*pool).clone())),
+        Arc::new(PostgresStockRepository::new((*pool).clone())),
+        market_clock.clone(),
+        price_gap_lookback_days,
+    ));
+    let price_gap_controller = Arc::new(PriceGapController::new(price_gap_service.clone()));
+    
+    // 初始化上市生命週期服務，比對前後兩次 ISIN 清單
+    let stock_listing_service = Arc::new(StockListingService::new(
+        Arc::new(PostgresStockRepository::new((*pool).clone())),
+        Arc::new(PostgresIsinListingRepository::new((*pool).clone())),
+        Arc::new(PostgresUniverseChangeRepository::new((*pool).clone())),
+        market_clock.clone(),
+    ));
+    let universe_controller = Arc::new(UniverseController::new(stock_listing_service.clone()));
+    
     // 創建 API 路由
-    let app = create_api_router(stock_controller.clone(), price_controller.clone());
+    let app = create_api_router(AppState {
+        stock_controller: stock_controller.clone(),
+        price_controller: price_controller.clone(),
+        intraday_quote_controller,
+        intraday_bar_controller,
+        index_controller,
+        derivatives_controller,
+        market_controller,
+        market_surveillance_controller,
+        material_announcement_controller,
+        foreign_ownership_controller,
+        off_session_trading_controller,
+        insider_holding_controller,
+        treasury_buyback_controller,
+        public_subscription_controller,
+        etf_controller,
+        price_reconciliation_controller,
+        price_gap_controller,
+        universe_controller,
+    });
+    
+    // 啟動盤中即時報價輪詢任務
+    if watchlist.is_empty() {
+        info!("未設定 WATCHLIST，不啟動盤中即時報價輪詢");
+    } else {
+        let poll_interval = std::env::var("INTRADAY_POLL_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(5);
+        tokio::spawn(poll_intraday_quotes(
+            intraday_quote_service.clone(),
+            intraday_bar_service.clone(),
+            watchlist.clone(),
+            market_clock.clone(),
+            Duration::from_secs(poll_interval),
+        ));
+    }
     
     // 初始化爬蟲服務
-    let crawler_service = Arc::new(StockCrawlerService::new());
+    let crawler_service = Arc::new(StockCrawlerService::new(market_clock.clone()));
     
     // 執行爬蟲任務
-    if let Err(e) = crawl_and_save_data(crawler_service.clone(), stock_service.clone(), price_service.clone()).await {
+    if let Err(e) = crawl_and_save_data(crawler_service.clone(), stock_service.clone(), price_service.clone(), market_clock.today()).await {
         error!("爬蟲任務執行失敗: {}", e);
     } else {
         info!("爬蟲任務執行成功");
     }
     
+    // 爬取大盤及類股指數、期貨選擇權行情及各項盤後資料，設定 BACKFILL_DAYS 時回補最近 N 天內的交易日
+    let index_crawler_service = Arc::new(IndexCrawlerService::new());
+    let taifex_crawler_service = Arc::new(TaifexCrawlerService::new());
+    let surveillance_crawler_service = Arc::new(SurveillanceCrawlerService::new());
+    let foreign_ownership_crawler_service = Arc::new(ForeignOwnershipCrawlerService::new());
+    let day_trading_crawler_service = Arc::new(DayTradingCrawlerService::new());
+    let off_session_trading_crawler_service = Arc::new(OffSessionTradingCrawlerService::new());
+    let block_trade_crawler_service = Arc::new(BlockTradeCrawlerService::new());
+    let public_subscription_crawler_service = Arc::new(PublicSubscriptionCrawlerService::new());
+    let etf_crawler_service = Arc::new(EtfCrawlerService::new());
+    let convertible_bond_crawler_service = Arc::new(ConvertibleBondCrawlerService::new());
+    let today = market_clock.today();
+    let backfill_days = std::env::var("BACKFILL_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(0);
+    for date in market_clock.trading_calendar().trading_days_between(today - time::Duration::days(backfill_days), today) {
+        if let Err(e) = crawl_and_save_index_levels(index_crawler_service.clone(), index_service.clone(), date).await {
+            error!("指數爬蟲任務執行失敗: {} - {}", date, e);
+        }
+        
+        if let Err(e) = crawl_and_save_derivatives(taifex_crawler_service.clone(), derivatives_service.clone(), date).await {
+            error!("期交所爬蟲任務執行失敗: {} - {}", date, e);
+        }
+        
+        if let Err(e) = crawl_and_save_surveillance_events(surveillance_crawler_service.clone(), market_surveillance_service.clone(), date).await {
+            error!("注意股及處置股爬蟲任務執行失敗: {} - {}", date, e);
+        }
+        
+        if let Err(e) = crawl_and_save_foreign_ownership(foreign_ownership_crawler_service.clone(), foreign_ownership_service.clone(), date).await {
+            error!("外資持股爬蟲任務執行失敗: {} - {}", date, e);
+        }
+        
+        if let Err(e) = crawl_and_save_day_trading(day_trading_crawler_service.clone(), day_trading_service.clone(), date).await {
+            error!("當沖統計爬蟲任務執行失敗: {} - {}", date, e);
+        }
+        
+        if let Err(e) = crawl_and_save_off_session_trading(off_session_trading_crawler_service.clone(), off_session_trading_service.clone(), date).await {
+            error!("零股及盤後定價爬蟲任務執行失敗: {} - {}", date, e);
+        }
+        
+        if let Err(e) = crawl_and_save_block_trades(block_trade_crawler_service.clone(), block_trade_service.clone(), date).await {
+            error!("鉅額交易爬蟲任務執行失敗: {} - {}", date, e);
+        }
+        
+        if let Err(e) = crawl_and_save_convertible_bonds(convertible_bond_crawler_service.clone(), convertible_bond_service.clone(), date).await {
+            error!("可轉債爬蟲任務執行失敗: {} - {}", date, e);
+        }
+    }
+    
+    // 公開申購公告以年度查詢，啟動時爬取一次
+    if let Err(e) = crawl_and_save_public_subscriptions(public_subscription_crawler_service.clone(), public_subscription_service.clone(), today).await {
+        error!("公開申購爬蟲任務執行失敗: {}", e);
+    }
+    
     // 初始化通知服務
-    let notification_service = match NotificationService::new(stock_service.clone(), price_service.clone()) {
+    let notification_service = match NotificationService::new(stock_service.clone(), price_service.clone(), market_clock.clone()) {
         Ok(service) => {
             info!("通知服務初始化成功");
             Arc::new(service)
@@ -86,6 +412,45 @@ async fn main() -> Result<(), Box<dyn Error>> {
         }
     };
     
+    // 比對 ISIN 上市櫃清單，有異動時推播彙總
+    if let Err(e) = sync_stock_listing(crawler_service.clone(), stock_listing_service.clone(), notification_service.clone(), market_clock.today()).await {
+        error!("上市櫃清單比對失敗: {}", e);
+    }
+    
+    // 啟動重大訊息輪詢任務
+    let material_info_poll_interval = std::env::var("MATERIAL_INFO_POLL_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(300);
+    tokio::spawn(poll_material_announcements(
+        Arc::new(MopsCrawlerService::new()),
+        material_announcement_service.clone(),
+        stock_listing_service.clone(),
+        notification_service.clone(),
+        market_clock.clone(),
+        Duration::from_secs(material_info_poll_interval),
+    ));
+    
+    // 啟動董監持股每月爬取任務
+    tokio::spawn(schedule_insider_holdings(
+        Arc::new(InsiderHoldingCrawlerService::new()),
+        insider_holding_service.clone(),
+        stock_service.clone(),
+        notification_service.clone(),
+        market_clock.clone(),
+    ));
+    
+    // 啟動庫藏股買回計畫輪詢任務
+    tokio::spawn(poll_treasury_buybacks(
+        Arc::new(TreasuryBuybackCrawlerService::new()),
+        treasury_buyback_service.clone(),
+        notification_service.clone(),
+        market_clock.clone(),
+    ));
+    
+    // 啟動交易日曆重新載入任務
+    tokio::spawn(reload_trading_calendar(market_clock.clone()));
+    
     // 發送初始通知
     if let Err(e) = notification_service.send_custom_message("台灣股票爬蟲系統已啟動，開始監控股票數據").await {
         error!("初始通知發送失敗: {}", e);
@@ -93,7 +458,6 @@ async fn main() -> Result<(), Box<dyn Error>> {
         info!("初始通知發送成功");
     }
     
-    // 啟動定時通知任務 - 由於 Send 問題，暫時不使用 tokio::spawn
     // 直接在主線程中執行一次通知
     info!("執行一次股票通知測試");
     if let Err(e) = notification_service.send_daily_summary().await {
@@ -102,67 +466,492 @@ async fn main() -> Result<(), Box<dyn Error>> {
         info!("每日摘要通知測試發送成功");
     }
     
-    // 啟動 Web 服務器
-    if let Err(e) = start_web_server(app).await {
-        error!("Web 服務器啟動失敗: {}", e);
-        return Err(e.into());
-    } else {
-        info!("Web 服務器啟動成功");
+    // 爬蟲的 future 不是 Send，無法使用 tokio::spawn，改為與 Web 服務器在同一個任務中並行執行
+    let daily_schedule = schedule_daily_notification(
+        notification_service.clone(),
+        market_clock.clone(),
+        DailyCrawlServices {
+            stock_service: stock_service.clone(),
+            price_service: price_service.clone(),
+            crawler_service: crawler_service.clone(),
+            index_service: index_service.clone(),
+            index_crawler_service: index_crawler_service.clone(),
+            derivatives_service: derivatives_service.clone(),
+            taifex_crawler_service: taifex_crawler_service.clone(),
+            market_surveillance_service: market_surveillance_service.clone(),
+            surveillance_crawler_service: surveillance_crawler_service.clone(),
+            foreign_ownership_service: foreign_ownership_service.clone(),
+            foreign_ownership_crawler_service: foreign_ownership_crawler_service.clone(),
+            day_trading_service: day_trading_service.clone(),
+            day_trading_crawler_service: day_trading_crawler_service.clone(),
+            off_session_trading_service: off_session_trading_service.clone(),
+            off_session_trading_crawler_service: off_session_trading_crawler_service.clone(),
+            block_trade_service: block_trade_service.clone(),
+            block_trade_crawler_service: block_trade_crawler_service.clone(),
+            public_subscription_service: public_subscription_service.clone(),
+            public_subscription_crawler_service: public_subscription_crawler_service.clone(),
+            etf_service: etf_service.clone(),
+            etf_crawler_service: etf_crawler_service.clone(),
+            etf_constituent_codes: etf_constituent_codes.clone(),
+            convertible_bond_service: convertible_bond_service.clone(),
+            convertible_bond_crawler_service: convertible_bond_crawler_service.clone(),
+            price_reconciliation_service: price_reconciliation_service.clone(),
+            yahoo_finance_service: Arc::new(YahooFinanceService::new()),
+            price_gap_service: price_gap_service.clone(),
+            stock_listing_service: stock_listing_service.clone(),
+        },
+    );
+    
+    // 啟動 Web 服務器，排程任務不會結束，只有服務器停止時才離開
+    tokio::select! {
+        result = start_web_server(app) => {
+            if let Err(e) = result {
+                error!("Web 服務器啟動失敗: {}", e);
+                return Err(e.into());
+            }
+        },
+        _ = daily_schedule => {},
     }
     
     Ok(())
 }
 
+/// 每日收盤後爬蟲任務所需的服務
+struct DailyCrawlServices {
+    stock_service: Arc<StockService>,
+    price_service: Arc<StockPriceService>,
+    crawler_service: Arc<StockCrawlerService>,
+    index_service: Arc<IndexService>,
+    index_crawler_service: Arc<IndexCrawlerService>,
+    derivatives_service: Arc<DerivativesService>,
+    taifex_crawler_service: Arc<TaifexCrawlerService>,
+    market_surveillance_service: Arc<MarketSurveillanceService>,
+    surveillance_crawler_service: Arc<SurveillanceCrawlerService>,
+    foreign_ownership_service: Arc<ForeignOwnershipService>,
+    foreign_ownership_crawler_service: Arc<ForeignOwnershipCrawlerService>,
+    day_trading_service: Arc<DayTradingService>,
+    day_trading_crawler_service: Arc<DayTradingCrawlerService>,
+    off_session_trading_service: Arc<OffSessionTradingService>,
+    off_session_trading_crawler_service: Arc<OffSessionTradingCrawlerService>,
+    block_trade_service: Arc<BlockTradeService>,
+    block_trade_crawler_service: Arc<BlockTradeCrawlerService>,
+    public_subscription_service: Arc<PublicSubscriptionService>,
+    public_subscription_crawler_service: Arc<PublicSubscriptionCrawlerService>,
+    etf_service: Arc<EtfService>,
+    etf_crawler_service: Arc<EtfCrawlerService>,
+    etf_constituent_codes: Vec<String>,
+    convertible_bond_service: Arc<ConvertibleBondService>,
+    convertible_bond_crawler_service: Arc<ConvertibleBondCrawlerService>,
+    price_reconciliation_service: Arc<PriceReconciliationService>,
+    yahoo_finance_service: Arc<YahooFinanceService>,
+    price_gap_service: Arc<PriceGapService>,
+    stock_listing_service: Arc<StockListingService>,
+}
+
 /// 排程每日通知任務
 async fn schedule_daily_notification(
     notification_service: Arc<NotificationService>,
-    stock_service: Arc<StockService>,
-    price_service: Arc<StockPriceService>,
-    crawler_service: Arc<StockCrawlerService>
+    market_clock: Arc<MarketClock>,
+    services: DailyCrawlServices
 ) {
     info!("啟動每日通知排程任務");
     
     // 設定每日固定時間發送通知 (例如：每天下午 3:30，台灣股市收盤後)
-    let mut interval = tokio_time::interval(Duration::from_secs(60 * 60 * 24)); // 每24小時執行一次
+    let mut interval = tokio_time::interval(Duration::from_secs(60)); // 每分鐘檢查一次
+    let mut last_run_date = None;
+    let mut last_evening_run_date = None;
     
     loop {
         interval.tick().await;
         
-        // 檢查當前時間是否為工作日且在下午 3:30 左右
-        let now = OffsetDateTime::now_utc().to_offset(UtcOffset::from_hms(8, 0, 0).unwrap());
+        // 檢查當前時間是否為交易日且在下午 3:30 左右
+        let now = market_clock.now();
         let hour = now.hour();
         let minute = now.minute();
-        let weekday = now.weekday();
         
-        // 如果是週一到週五，且時間在下午 3:30 左右
-        if (weekday.number_from_monday() <= 5) && (hour == 15 && minute >= 30 && minute <= 40) {
+        // 如果是交易日，且時間在下午 3:30 左右，每天只執行一次
+        if market_clock.is_trading_day(now.date())
+            && (hour == 15 && (30..=40).contains(&minute))
+            && last_run_date != Some(now.date())
+        {
+            last_run_date = Some(now.date());
             info!("開始執行每日股票數據爬取和通知任務");
             
+            // 比對上市櫃清單，有異動時推播彙總
+            if let Err(e) = sync_stock_listing(services.crawler_service.clone(), services.stock_listing_service.clone(), notification_service.clone(), now.date()).await {
+                error!("上市櫃清單比對失敗: {}", e);
+            }
+            
             // 重新爬取最新數據
-            if let Err(e) = crawl_and_save_data(crawler_service.clone(), stock_service.clone(), price_service.clone()).await {
+            if let Err(e) = crawl_and_save_data(services.crawler_service.clone(), services.stock_service.clone(), services.price_service.clone(), now.date()).await {
                 error!("每日股票數據爬取失敗: {}", e);
             } else {
                 info!("每日股票數據爬取成功");
             }
             
+            // 爬取當日指數
+            if let Err(e) = crawl_and_save_index_levels(services.index_crawler_service.clone(), services.index_service.clone(), now.date()).await {
+                error!("每日指數爬取失敗: {}", e);
+            }
+            
+            // 爬取當日期貨、選擇權行情
+            if let Err(e) = crawl_and_save_derivatives(services.taifex_crawler_service.clone(), services.derivatives_service.clone(), now.date()).await {
+                error!("每日期交所資料爬取失敗: {}", e);
+            }
+            
             // 發送每日摘要通知
             if let Err(e) = notification_service.send_daily_summary().await {
                 error!("每日摘要通知發送失敗: {}", e);
             } else {
                 info!("每日摘要通知發送成功");
             }
+            
+            info!("下一個交易日: {}", market_clock.trading_calendar().next_trading_day(now.date()));
+        }
+        
+        // 注意股、處置股、外資持股、當沖統計、零股、盤後定價、鉅額交易、公開申購、ETF 淨值及可轉債於收盤後陸續公告，晚上 6:30 再爬取，最後比對各來源的當日股價並補抓缺漏的日 K
+        // 下午的爬取任務可能執行到 6:30 之後，因此只要求當天尚未執行且已過 6:30，而非限定在固定的時間窗口內
+        if market_clock.is_trading_day(now.date())
+            && (hour, minute) >= (18, 30)
+            && last_evening_run_date != Some(now.date())
+        {
+            last_evening_run_date = Some(now.date());
+            info!("開始執行盤後公告爬取任務");
+            
+            if let Err(e) = crawl_and_save_surveillance_events(services.surveillance_crawler_service.clone(), services.market_surveillance_service.clone(), now.date()).await {
+                error!("注意股及處置股公告爬取失敗: {}", e);
+            }
+            
+            if let Err(e) = crawl_and_save_foreign_ownership(services.foreign_ownership_crawler_service.clone(), services.foreign_ownership_service.clone(), now.date()).await {
+                error!("外資持股爬取失敗: {}", e);
+            }
+            
+            if let Err(e) = crawl_and_save_day_trading(services.day_trading_crawler_service.clone(), services.day_trading_service.clone(), now.date()).await {
+                error!("當沖統計爬取失敗: {}", e);
+            }
+            
+            if let Err(e) = crawl_and_save_off_session_trading(services.off_session_trading_crawler_service.clone(), services.off_session_trading_service.clone(), now.date()).await {
+                error!("零股及盤後定價爬取失敗: {}", e);
+            }
+            
+            if let Err(e) = crawl_and_save_block_trades(services.block_trade_crawler_service.clone(), services.block_trade_service.clone(), now.date()).await {
+                error!("鉅額交易爬取失敗: {}", e);
+            }
+            
+            if let Err(e) = crawl_and_save_public_subscriptions(services.public_subscription_crawler_service.clone(), services.public_subscription_service.clone(), now.date()).await {
+                error!("公開申購爬取失敗: {}", e);
+            }
+            
+            if let Err(e) = crawl_and_save_etf_data(services.etf_crawler_service.clone(), services.etf_service.clone(), &services.etf_constituent_codes, now.date()).await {
+                error!("ETF 淨值及成分股爬取失敗: {}", e);
+            }
+            
+            if let Err(e) = crawl_and_save_convertible_bonds(services.convertible_bond_crawler_service.clone(), services.convertible_bond_service.clone(), now.date()).await {
+                error!("可轉債爬取失敗: {}", e);
+            }
+            
+            if let Err(e) = reconcile_prices(services.yahoo_finance_service.clone(), services.stock_service.clone(), services.price_reconciliation_service.clone(), notification_service.clone(), now.date()).await {
+                error!("跨來源股價比對失敗: {}", e);
+            }
+            
+            if let Err(e) = repair_price_gaps(services.crawler_service.clone(), services.stock_service.clone(), services.price_service.clone(), services.price_gap_service.clone()).await {
+                error!("日 K 缺漏補抓失敗: {}", e);
+            }
+        }
+    }
+}
+
+/// 盤中輪詢自選股即時報價，並彙整為分鐘 K 棒
+async fn poll_intraday_quotes(
+    intraday_quote_service: Arc<IntradayQuoteService>,
+    intraday_bar_service: Arc<IntradayBarService>,
+    watchlist: Watchlist,
+    market_clock: Arc<MarketClock>,
+    poll_interval: Duration
+) {
+    info!("啟動盤中即時報價輪詢任務，自選股: {:?}", watchlist.codes);
+    
+    let mut interval = tokio_time::interval(poll_interval);
+    let mut was_regular_session = false;
+    
+    loop {
+        interval.tick().await;
+        
+        // 只在一般交易時段輪詢，收盤後再多輪詢一次以取得收盤集合競價的成交價
+        let is_regular_session = market_clock.session_phase().is_regular_session();
+        if !is_regular_session && !was_regular_session {
+            continue;
+        }
+        was_regular_session = is_regular_session;
+        
+        match intraday_quote_service.poll(&watchlist).await {
+            Ok(quotes) => {
+                if let Err(e) = intraday_bar_service.ingest(&quotes).await {
+                    error!("彙整分鐘 K 棒失敗: {}", e);
+                }
+            },
+            Err(e) => error!("輪詢即時報價失敗: {}", e),
+        }
+    }
+}
+
+/// 載入交易日曆：證交所前一年至下一年的休市表，再套用本地調整檔，回傳日曆及當年度休市表是否載入成功
+async fn load_trading_calendar(year: i32) -> (TradingCalendar, bool) {
+    let mut trading_calendar = TradingCalendar::new();
+    let mut current_year_loaded = false;
+    
+    let holiday_service = TwseHolidayService::new();
+    for schedule_year in year - 1..=year + 1 {
+        match holiday_service.load_holiday_schedule(schedule_year, &mut trading_calendar).await {
+            Ok(_) => current_year_loaded |= schedule_year == year,
+            Err(e) => error!("讀取 {} 年休市表失敗: {}", schedule_year, e),
+        }
+    }
+    
+    // 颱風停止交易等臨時異動不在休市表中，由本地調整檔補充
+    let overrides_path = std::env::var("TRADING_CALENDAR_OVERRIDES")
+        .unwrap_or_else(|_| "config/trading_calendar_overrides.json".to_string());
+    match std::fs::read_to_string(&overrides_path) {
+        Ok(content) => {
+            let result = serde_json::from_str::<TradingCalendarOverrides>(&content)
+                .map_err(anyhow::Error::from)
+                .and_then(|overrides| trading_calendar.apply_overrides(&overrides));
+            match result {
+                Ok(_) => info!("已套用交易日調整檔: {}", overrides_path),
+                Err(e) => error!("交易日調整檔格式錯誤: {}, 錯誤: {}", overrides_path, e),
+            }
+        },
+        Err(_) => info!("找不到交易日調整檔: {}", overrides_path),
+    }
+    
+    (trading_calendar, current_year_loaded)
+}
+
+/// 交易日曆重新載入任務
+///
+/// 每天重新載入休市表及本地調整檔，跨年後以新的年度為基準；當年度休市表讀取失敗時沿用原本的日曆
+async fn reload_trading_calendar(market_clock: Arc<MarketClock>) {
+    info!("啟動交易日曆重新載入任務");
+    
+    let mut interval = tokio_time::interval(Duration::from_secs(24 * 60 * 60));
+    // 啟動時已載入過，略過立即觸發的第一次
+    interval.tick().await;
+    
+    loop {
+        interval.tick().await;
+        
+        let year = market_clock.today().year();
+        match load_trading_calendar(year).await {
+            (trading_calendar, true) => {
+                market_clock.replace_trading_calendar(trading_calendar);
+                info!("已重新載入 {} 年交易日曆", year);
+            },
+            (_, false) => error!("{} 年休市表讀取失敗，沿用原本的交易日曆", year),
+        }
+    }
+}
+
+/// 系統初始化後供各任務共用的元件
+struct SystemContext {
+    pool: Arc<sqlx::PgPool>,
+    market_clock: Arc<MarketClock>,
+    stock_service: Arc<StockService>,
+    price_service: Arc<StockPriceService>,
+    stock_controller: Arc<StockController>,
+    price_controller: Arc<StockPriceController>,
+    market_surveillance_service: Arc<MarketSurveillanceService>,
+    foreign_ownership_service: Arc<ForeignOwnershipService>,
+    convertible_bond_service: Arc<ConvertibleBondService>,
+}
+
+/// 輪詢當日重大訊息，自選股有符合關鍵字的新訊息時推播通知，並依暫停或恢復交易公告更新上市狀態
+async fn poll_material_announcements(
+    mops_crawler_service: Arc<MopsCrawlerService>,
+    material_announcement_service: Arc<MaterialAnnouncementService>,
+    stock_listing_service: Arc<StockListingService>,
+    notification_service: Arc<NotificationService>,
+    market_clock: Arc<MarketClock>,
+    poll_interval: Duration
+) {
+    info!("啟動重大訊息輪詢任務");
+    
+    let mut interval = tokio_time::interval(poll_interval);
+    
+    loop {
+        interval.tick().await;
+        
+        // 重大訊息在非交易時段及假日也會發布，每天都輪詢
+        let announcements = match mops_crawler_service.crawl_material_announcements(market_clock.today()).await {
+            Ok(announcements) => announcements,
+            Err(e) => {
+                error!("爬取重大訊息失敗: {}", e);
+                continue;
+            }
+        };
+        
+        // 暫停交易及恢復交易的公告會更新股票的上市狀態
+        for announcement in &announcements {
+            if let Err(e) = stock_listing_service.apply_announcement(announcement).await {
+                error!("依重大訊息更新上市狀態失敗: {} - {}", announcement.stock_code, e);
+            }
+        }
+        
+        let to_notify = match material_announcement_service.ingest(&announcements).await {
+            Ok(to_notify) => to_notify,
+            Err(e) => {
+                error!("保存重大訊息失敗: {}", e);
+                continue;
+            }
+        };
+        
+        for announcement in &to_notify {
+            if let Err(e) = notification_service.send_material_announcement(announcement).await {
+                error!("重大訊息通知發送失敗: {} - {}", announcement.stock_code, e);
+            }
+        }
+    }
+}
+
+/// 庫藏股買回計畫輪詢任務
+///
+/// 每小時爬取最近 90 天董事會決議的買回計畫，新計畫保存後推播，既有計畫則更新執行情形
+async fn poll_treasury_buybacks(
+    treasury_buyback_crawler_service: Arc<TreasuryBuybackCrawlerService>,
+    treasury_buyback_service: Arc<TreasuryBuybackService>,
+    notification_service: Arc<NotificationService>,
+    market_clock: Arc<MarketClock>,
+) {
+    info!("啟動庫藏股買回計畫輪詢任務");
+    
+    let mut interval = tokio_time::interval(Duration::from_secs(60 * 60));
+    
+    loop {
+        interval.tick().await;
+        
+        let today = market_clock.today();
+        let buybacks = match treasury_buyback_crawler_service.crawl_buybacks(today - time::Duration::days(90), today).await {
+            Ok(buybacks) => buybacks,
+            Err(e) => {
+                error!("爬取庫藏股買回計畫失敗: {}", e);
+                continue;
+            }
+        };
+        
+        let to_notify = match treasury_buyback_service.ingest(&buybacks, today).await {
+            Ok(to_notify) => to_notify,
+            Err(e) => {
+                error!("保存庫藏股買回計畫失敗: {}", e);
+                continue;
+            }
+        };
+        
+        for buyback in &to_notify {
+            if let Err(e) = notification_service.send_treasury_buyback(buyback).await {
+                error!("庫藏股通知發送失敗: {} - {}", buyback.stock_code, e);
+            }
+        }
+    }
+}
+
+/// 董監持股每月爬取任務
+///
+/// 董監事持股於次月 15 日前申報，每天 16 日起檢查上個月的資料，尚未爬取時逐家爬取所有股票，
+/// 自選股的設質比率升破門檻時推播通知
+async fn schedule_insider_holdings(
+    insider_holding_crawler_service: Arc<InsiderHoldingCrawlerService>,
+    insider_holding_service: Arc<InsiderHoldingService>,
+    stock_service: Arc<StockService>,
+    notification_service: Arc<NotificationService>,
+    market_clock: Arc<MarketClock>,
+) {
+    info!("啟動董監持股每月爬取任務");
+    
+    let mut interval = tokio_time::interval(Duration::from_secs(6 * 60 * 60));
+    
+    loop {
+        interval.tick().await;
+        
+        let today = market_clock.today();
+        if today.day() < 16 {
+            continue;
+        }
+        
+        // 上個月的第一天
+        let period = (today.replace_day(1).unwrap() - time::Duration::days(1)).replace_day(1).unwrap();
+        match insider_holding_service.is_period_completed(period).await {
+            Ok(true) => continue,
+            Ok(false) => {}
+            Err(e) => {
+                error!("查詢董監持股資料失敗: {}", e);
+                continue;
+            }
+        }
+        
+        let stocks = match stock_service.find_all_stocks().await {
+            Ok(stocks) => stocks,
+            Err(e) => {
+                error!("讀取股票清單失敗: {}", e);
+                continue;
+            }
+        };
+        
+        // 上次中斷前已保存的公司不再重新爬取
+        let saved_codes = match insider_holding_service.get_saved_stock_codes(period).await {
+            Ok(codes) => codes,
+            Err(e) => {
+                error!("查詢董監持股資料失敗: {}", e);
+                continue;
+            }
+        };
+        
+        // 每家公司爬取後立即保存，有任何公司失敗時不標記為完成，下次排程只重試尚未保存的公司
+        let mut failed = 0;
+        for stock in stocks.iter().filter(|s| !saved_codes.contains(&s.code)) {
+            let holding = match insider_holding_crawler_service.crawl_insider_holding(&stock.code, &stock.name, period).await {
+                Ok(holding) => holding,
+                Err(e) => {
+                    error!("爬取董監持股失敗: {} - {}", stock.code, e);
+                    failed += 1;
+                    None
+                }
+            };
+            
+            if let Some(holding) = holding {
+                match insider_holding_service.ingest(holding).await {
+                    Ok(Some(holding)) => {
+                        if let Err(e) = notification_service
+                            .send_pledge_alert(&holding, insider_holding_service.pledge_alert_threshold())
+                            .await
+                        {
+                            error!("董監質押通知發送失敗: {} - {}", holding.stock_code, e);
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        error!("保存董監持股失敗: {} - {}", stock.code, e);
+                        failed += 1;
+                    }
+                }
+            }
+            
+            // 公開資訊觀測站對短時間大量查詢會暫時封鎖，每家公司間隔 3 秒
+            tokio_time::sleep(Duration::from_secs(3)).await;
         }
+        
+        if failed > 0 {
+            warn!("董監持股爬取未完成: {}-{:02}，{} 家公司失敗，下次排程重試", period.year(), period.month() as u8, failed);
+            continue;
+        }
+        if let Err(e) = insider_holding_service.mark_period_completed(period).await {
+            error!("記錄董監持股爬取完成失敗: {}", e);
+            continue;
+        }
+        info!("董監持股爬取完成: {}-{:02}", period.year(), period.month() as u8);
     }
 }
 
-/// 初始化系統：設置日誌、環境變數和資料庫連接
-async fn initialize_system() -> Result<(
-    Arc<sqlx::PgPool>, 
-    Arc<StockService>, 
-    Arc<StockPriceService>, 
-    Arc<StockController>, 
-    Arc<StockPriceController>
-), Box<dyn Error>> {
+/// 初始化系統：設置日誌、環境變數、資料庫連接和交易日曆
+async fn initialize_system() -> Result<SystemContext, Box<dyn Error>> {
     // 初始化日誌系統，使用更明確的配置
     tracing_subscriber::fmt()
         .with_max_level(tracing::Level::INFO)
@@ -191,17 +980,51 @@ async fn initialize_system() -> Result<(
     run_migrations(&database_url).await?;
     info!("資料庫遷移完成");
     
+    // 載入交易日曆，並建立以台北時間判斷日期及交易時段的市場時鐘
+    let current_year = OffsetDateTime::now_utc().to_offset(MarketClock::offset()).year();
+    let (trading_calendar, _) = load_trading_calendar(current_year).await;
+    let market_clock = Arc::new(MarketClock::new(Arc::new(trading_calendar)));
+    
     // 初始化儲存庫
     let stock_repo = Arc::new(PostgresStockRepository::new(pool.clone()));
     let price_repo = Arc::new(PostgresStockPriceRepository::new(pool.clone()));
+    let market_surveillance_repo = Arc::new(PostgresMarketSurveillanceRepository::new(pool.clone()));
+    let foreign_ownership_repo = Arc::new(PostgresForeignOwnershipRepository::new(pool.clone()));
+    let block_trade_repo = Arc::new(PostgresBlockTradeRepository::new(pool.clone()));
+    let convertible_bond_repo = Arc::new(PostgresConvertibleBondRepository::new(pool.clone()));
+    let shares_outstanding_repo = Arc::new(PostgresSharesOutstandingRepository::new(pool.clone()));
+    let quarantined_price_repo = Arc::new(PostgresQuarantinedPriceRepository::new(pool.clone()));
     info!("儲存庫初始化完成");
     
     // 將連接池包裝在 Arc 中
     let pool_arc = Arc::new(pool);
     
     // 初始化應用服務
-    let stock_service = Arc::new(StockService::new(stock_repo.clone()));
-    let price_service = Arc::new(StockPriceService::new(price_repo.clone()));
+    let market_surveillance_service = Arc::new(MarketSurveillanceService::new(
+        market_surveillance_repo.clone(),
+        market_clock.clone(),
+    ));
+    let foreign_ownership_service = Arc::new(ForeignOwnershipService::new(
+        foreign_ownership_repo.clone(),
+        shares_outstanding_repo.clone(),
+    ));
+    let convertible_bond_service = Arc::new(ConvertibleBondService::new(
+        convertible_bond_repo.clone(),
+        stock_repo.clone(),
+        price_repo.clone(),
+    ));
+    let stock_service = Arc::new(StockService::new(
+        stock_repo.clone(),
+        market_surveillance_service.clone(),
+        foreign_ownership_service.clone(),
+        convertible_bond_service.clone(),
+    ));
+    let price_service = Arc::new(StockPriceService::new(
+        price_repo.clone(),
+        stock_repo.clone(),
+        block_trade_repo.clone(),
+        quarantined_price_repo.clone(),
+    ));
     info!("應用服務初始化完成");
     
     // 初始化控制器
@@ -209,32 +1032,62 @@ async fn initialize_system() -> Result<(
     let price_controller = Arc::new(StockPriceController::new(price_service.clone()));
     info!("控制器初始化完成");
     
-    Ok((pool_arc, stock_service, price_service, stock_controller, price_controller))
+    Ok(SystemContext {
+        pool: pool_arc,
+        market_clock,
+        stock_service,
+        price_service,
+        stock_controller,
+        price_controller,
+        market_surveillance_service,
+        foreign_ownership_service,
+        convertible_bond_service,
+    })
 }
 
 /// 創建 API 路由
-fn create_api_router(
-    stock_controller: Arc<StockController>,
-    price_controller: Arc<StockPriceController>
-) -> Router {
+fn create_api_router(state: AppState) -> Router {
     let app = Router::new()
         .route("/api/stocks", get(get_all_stocks))
         .route("/api/stocks/:code", get(get_stock_by_code))
         .route("/api/stocks/:code/prices", get(get_stock_prices_by_stock_id))
-        .with_state(AppState {
-            stock_controller,
-            price_controller,
-        });
+        .route("/api/stocks/:code/price-limit", get(get_price_limit))
+        .route("/api/stocks/:code/surveillance", get(get_surveillance_events))
+        .route("/api/stocks/:code/foreign-ownership", get(get_foreign_ownership))
+        .route("/api/stocks/:code/odd-lot", get(get_odd_lot_quotes))
+        .route("/api/stocks/:code/after-hours", get(get_after_hours_trades))
+        .route("/api/stocks/:code/insider-holdings", get(get_insider_holdings))
+        .route("/api/stocks/:code/treasury-buybacks", get(get_treasury_buybacks))
+        .route("/api/stocks/:code/announcements", get(get_stock_announcements))
+        .route("/api/announcements", get(get_announcements))
+        .route("/api/ipos", get(get_public_subscriptions))
+        .route("/api/etfs/:code/nav", get(get_etf_navs))
+        .route("/api/etfs/:code/constituents", get(get_etf_constituents))
+        .route("/api/stocks/:code/etf-holders", get(get_etf_holders))
+        .route("/api/intraday/quotes", get(get_all_intraday_quotes))
+        .route("/api/intraday/quotes/:code", get(get_intraday_quote))
+        .route("/api/stocks/:code/bars", get(get_intraday_bars))
+        .route("/api/indices/:code/prices", get(get_index_prices))
+        .route("/api/market/status", get(get_market_status))
+        .route("/api/futures/:contract/prices", get(get_futures_prices))
+        .route("/api/futures/:contract/institutional", get(get_institutional_open_interest))
+        .route("/api/futures/:contract/basis", get(get_futures_basis))
+        .route("/api/options/:contract/put-call-ratio", get(get_put_call_ratios))
+        .route("/api/admin/price-discrepancies", get(get_price_discrepancies))
+        .route("/api/stocks/:code/coverage", get(get_price_coverage))
+        .route("/api/universe/changes", get(get_universe_changes))
+        .with_state(state);
     
     info!("API 路由初始化完成");
     app
 }
 
-/// 爬取股票和價格數據並保存到資料庫
+/// 爬取股票和指定日期所屬月份的價格數據並保存到資料庫
 async fn crawl_and_save_data(
     crawler_service: Arc<StockCrawlerService>,
     stock_service: Arc<StockService>,
-    price_service: Arc<StockPriceService>
+    price_service: Arc<StockPriceService>,
+    date: time::Date
 ) -> Result<(), Box<dyn Error>> {
     info!("開始股票爬蟲程序...");
     
@@ -257,23 +1110,24 @@ async fn crawl_and_save_data(
                         info!("保存股票成功: {} - {}", stock_code, stock_name);
                         
                         // 爬取股票價格
-                        match crawler_service.crawl_stock_prices(&stock_code).await {
+                        match crawler_service.crawl_stock_prices(&stock_code, date).await {
                             Ok(prices) => {
                                 info!("成功爬取 {} 的價格數據, 共 {} 筆", stock_code, prices.len());
                                 
                                 // 從資料庫中查詢股票的 ID
-                                match stock_service.get_stock_by_code(&stock_code).await {
+                                match stock_service.find_stock_by_code(&stock_code).await {
                                     Ok(Some(db_stock)) => {
                                         // 保存價格到資料庫，使用從資料庫中查詢到的股票 ID
                                         for price in prices {
-                                            let stock_id = Uuid::parse_str(&db_stock.id).unwrap_or_default();
+                                            let stock_id = db_stock.id;
                                             let price_with_stock_id = StockPrice {
                                                 stock_id,
                                                 ..price
                                             };
                                             
                                             match price_service.create_stock_price(CreateStockPriceDto::from(price_with_stock_id)).await {
-                                                Ok(_) => info!("保存價格成功: {} - {}", stock_code, price.date),
+                                                Ok(CreateStockPriceOutcome::Saved { .. }) => info!("保存價格成功: {} - {}", stock_code, price.date),
+                                                Ok(CreateStockPriceOutcome::Quarantined { reasons }) => warn!("價格未通過驗證，已移至隔離區: {} - {}, 原因: {}", stock_code, price.date, reasons.join("、")),
                                                 Err(e) => error!("保存價格失敗: {} - {}, 錯誤: {}", stock_code, price.date, e),
                                             }
                                         }
@@ -298,6 +1152,313 @@ async fn crawl_and_save_data(
     Ok(())
 }
 
+/// 爬取指定日期的大盤及類股指數並保存到資料庫
+async fn crawl_and_save_index_levels(
+    index_crawler_service: Arc<IndexCrawlerService>,
+    index_service: Arc<IndexService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let index_levels = index_crawler_service.crawl_index_levels(date).await?;
+    
+    for index_level in index_levels {
+        match index_service.save_index_level(&index_level).await {
+            Ok(_) => info!("保存指數成功: {} - {}", index_level.index_code, index_level.date),
+            Err(e) => error!("保存指數失敗: {} - {}, 錯誤: {}", index_level.index_code, index_level.date, e),
+        }
+    }
+    
+    Ok(())
+}
+
+/// 爬取指定日期的期貨、選擇權行情及期貨三大法人未平倉並保存到資料庫
+async fn crawl_and_save_derivatives(
+    taifex_crawler_service: Arc<TaifexCrawlerService>,
+    derivatives_service: Arc<DerivativesService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    // 單一契約失敗時記錄後繼續處理其他契約
+    for contract_code in FUTURES_CONTRACTS {
+        let result = async {
+            let prices = taifex_crawler_service.crawl_futures_prices(contract_code, date).await?;
+            derivatives_service.save_futures_prices(&prices).await?;
+            Ok::<_, anyhow::Error>(prices.len())
+        }.await;
+        match result {
+            Ok(count) => info!("保存期貨行情成功: {} - {}, 共 {} 筆", contract_code, date, count),
+            Err(e) => error!("爬取期貨行情失敗: {} - {}, 錯誤: {}", contract_code, date, e),
+        }
+    }
+    
+    for contract_code in OPTIONS_CONTRACTS {
+        let result = async {
+            let prices = taifex_crawler_service.crawl_options_prices(contract_code, date).await?;
+            derivatives_service.save_options_prices(&prices).await?;
+            Ok::<_, anyhow::Error>(prices.len())
+        }.await;
+        match result {
+            Ok(count) => info!("保存選擇權行情成功: {} - {}, 共 {} 筆", contract_code, date, count),
+            Err(e) => error!("爬取選擇權行情失敗: {} - {}, 錯誤: {}", contract_code, date, e),
+        }
+    }
+    
+    let positions = taifex_crawler_service.crawl_institutional_open_interest(date).await?;
+    derivatives_service.save_institutional_open_interest(&positions).await?;
+    info!("保存期貨三大法人未平倉成功: {}, 共 {} 筆", date, positions.len());
+    
+    Ok(())
+}
+
+/// 爬取指定日期公告的注意股、處置股並保存到資料庫
+async fn crawl_and_save_surveillance_events(
+    surveillance_crawler_service: Arc<SurveillanceCrawlerService>,
+    market_surveillance_service: Arc<MarketSurveillanceService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let events = surveillance_crawler_service.crawl_events(date).await?;
+    market_surveillance_service.save_events(&events).await?;
+    info!("保存注意股及處置股公告成功: {}, 共 {} 筆", date, events.len());
+    
+    Ok(())
+}
+
+/// 爬取指定日期的外資及陸資持股比例並保存到資料庫
+async fn crawl_and_save_foreign_ownership(
+    foreign_ownership_crawler_service: Arc<ForeignOwnershipCrawlerService>,
+    foreign_ownership_service: Arc<ForeignOwnershipService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let records = foreign_ownership_crawler_service.crawl_foreign_ownership(date).await?;
+    foreign_ownership_service.save_records(&records).await?;
+    info!("保存外資持股成功: {}, 共 {} 筆", date, records.len());
+    
+    Ok(())
+}
+
+/// 爬取指定日期的個股當日沖銷交易統計並保存到資料庫
+async fn crawl_and_save_day_trading(
+    day_trading_crawler_service: Arc<DayTradingCrawlerService>,
+    day_trading_service: Arc<DayTradingService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let stats = day_trading_crawler_service.crawl_day_trading(date).await?;
+    day_trading_service.save_stats(&stats).await?;
+    info!("保存當沖統計成功: {}, 共 {} 筆", date, stats.len());
+    
+    Ok(())
+}
+
+/// 爬取指定日期的盤中零股行情及盤後定價交易並保存到資料庫
+async fn crawl_and_save_off_session_trading(
+    off_session_trading_crawler_service: Arc<OffSessionTradingCrawlerService>,
+    off_session_trading_service: Arc<OffSessionTradingService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let quotes = off_session_trading_crawler_service.crawl_odd_lot_quotes(date).await?;
+    off_session_trading_service.save_odd_lot_quotes(&quotes).await?;
+    info!("保存盤中零股行情成功: {}, 共 {} 筆", date, quotes.len());
+    
+    let trades = off_session_trading_crawler_service.crawl_after_hours_trades(date).await?;
+    off_session_trading_service.save_after_hours_trades(&trades).await?;
+    info!("保存盤後定價交易成功: {}, 共 {} 筆", date, trades.len());
+    
+    Ok(())
+}
+
+/// 爬取指定日期的鉅額交易並保存到資料庫
+async fn crawl_and_save_block_trades(
+    block_trade_crawler_service: Arc<BlockTradeCrawlerService>,
+    block_trade_service: Arc<BlockTradeService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let trades = block_trade_crawler_service.crawl_block_trades(date).await?;
+    block_trade_service.save_trades(date, &trades).await?;
+    info!("保存鉅額交易成功: {}, 共 {} 筆", date, trades.len());
+    
+    Ok(())
+}
+
+/// 爬取指定日期所屬年度的公開申購公告並保存到資料庫，再將已掛牌的新股加入 stocks
+async fn crawl_and_save_public_subscriptions(
+    public_subscription_crawler_service: Arc<PublicSubscriptionCrawlerService>,
+    public_subscription_service: Arc<PublicSubscriptionService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let subscriptions = public_subscription_crawler_service.crawl_public_subscriptions(date.year()).await?;
+    public_subscription_service.save_subscriptions(&subscriptions).await?;
+    info!("保存公開申購公告成功: {} 年, 共 {} 筆", date.year(), subscriptions.len());
+    
+    let created = public_subscription_service.sync_listed_stocks().await?;
+    if created > 0 {
+        info!("新增 {} 支新掛牌股票", created);
+    }
+    
+    Ok(())
+}
+
+/// 爬取所有 ETF 的當日淨值及指定 ETF 的成分股並保存到資料庫
+///
+/// 淨值來源只提供即時資料，無法回補歷史日期
+async fn crawl_and_save_etf_data(
+    etf_crawler_service: Arc<EtfCrawlerService>,
+    etf_service: Arc<EtfService>,
+    constituent_codes: &[String],
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let navs = etf_crawler_service.crawl_navs().await?;
+    etf_service.save_navs(&navs).await?;
+    info!("保存 ETF 淨值成功: {}, 共 {} 筆", date, navs.len());
+    
+    for etf_code in constituent_codes {
+        match etf_crawler_service.crawl_constituents(etf_code, date).await {
+            Ok(constituents) if !constituents.is_empty() => {
+                etf_service.save_constituents(etf_code, date, &constituents).await?;
+            }
+            Ok(_) => info!("{} 查無成分股資料", etf_code),
+            Err(e) => error!("爬取 {} 成分股失敗: {}", etf_code, e),
+        }
+    }
+    
+    Ok(())
+}
+
+/// 爬取指定日期的可轉債發行資料及行情並保存到資料庫
+///
+/// 轉換溢價率以標的股票當日收盤價計算，需在當日股價爬取之後執行
+async fn crawl_and_save_convertible_bonds(
+    convertible_bond_crawler_service: Arc<ConvertibleBondCrawlerService>,
+    convertible_bond_service: Arc<ConvertibleBondService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let bonds = convertible_bond_crawler_service.crawl_bonds(date).await?;
+    convertible_bond_service.save_bonds(&bonds).await?;
+    
+    let quotes = convertible_bond_crawler_service.crawl_quotes(date).await?;
+    let quote_count = quotes.len();
+    convertible_bond_service.save_quotes(date, &bonds, quotes).await?;
+    info!("保存可轉債資料成功: {}, 發行資料 {} 筆, 行情 {} 筆", date, bonds.len(), quote_count);
+    
+    Ok(())
+}
+
+/// 以 Yahoo Finance 日 K 比對資料庫中的股價，並將指定日期的差異彙總推播
+async fn reconcile_prices(
+    yahoo_finance_service: Arc<YahooFinanceService>,
+    stock_service: Arc<StockService>,
+    price_reconciliation_service: Arc<PriceReconciliationService>,
+    notification_service: Arc<NotificationService>,
+    date: time::Date
+) -> Result<(), Box<dyn Error>> {
+    let stocks = stock_service.find_all_stocks().await?;
+    let mut discrepancies = Vec::new();
+    
+    for stock in stocks {
+        match yahoo_finance_service.crawl_stock_prices(&stock.code).await {
+            Ok(prices) => match price_reconciliation_service.reconcile(&stock.code, &prices).await {
+                Ok(found) => discrepancies.extend(found.into_iter().filter(|d| d.date == date)),
+                Err(e) => error!("跨來源股價比對失敗: {}, 錯誤: {}", stock.code, e),
+            },
+            Err(e) => error!("Yahoo Finance 爬取失敗: {}, 錯誤: {}", stock.code, e),
+        }
+        
+        // 避免請求過於頻繁
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    
+    info!("跨來源股價比對完成: {}, 共 {} 筆差異", date, discrepancies.len());
+    if !discrepancies.is_empty() {
+        notification_service.send_price_discrepancy_digest(date, &discrepancies).await?;
+    }
+    
+    Ok(())
+}
+
+/// 依交易日曆偵測各股缺漏的日 K，並以月份為單位重新爬取補齊
+async fn repair_price_gaps(
+    crawler_service: Arc<StockCrawlerService>,
+    stock_service: Arc<StockService>,
+    price_service: Arc<StockPriceService>,
+    price_gap_service: Arc<PriceGapService>,
+) -> Result<(), Box<dyn Error>> {
+    price_gap_service.detect_gaps().await?;
+    
+    // 證交所個股日成交資訊以月份查詢，同一股票同一月份的缺漏只爬取一次
+    let mut gaps_by_month: BTreeMap<(String, i32, u8), Vec<PriceGap>> = BTreeMap::new();
+    for gap in price_gap_service.get_open_gaps().await? {
+        gaps_by_month
+            .entry((gap.stock_code.clone(), gap.date.year(), gap.date.month() as u8))
+            .or_default()
+            .push(gap);
+    }
+    
+    for ((stock_code, _, _), gaps) in gaps_by_month {
+        let Some(stock) = stock_service.find_stock_by_code(&stock_code).await? else {
+            continue;
+        };
+        let stock_id = stock.id;
+        
+        let prices = match crawler_service.crawl_stock_prices_for_month(&stock_code, gaps[0].date).await {
+            Ok(prices) => prices,
+            Err(e) => {
+                error!("補抓日 K 失敗: {} {}, 錯誤: {}", stock_code, gaps[0].date, e);
+                Vec::new()
+            }
+        };
+        
+        for gap in &gaps {
+            let status = match prices.iter().find(|p| p.date == gap.date) {
+                Some(price) => {
+                    let price_with_stock_id = StockPrice {
+                        stock_id,
+                        ..price.clone()
+                    };
+                    match price_service.create_stock_price(CreateStockPriceDto::from(price_with_stock_id)).await {
+                        Ok(CreateStockPriceOutcome::Saved { .. }) => PriceGapStatus::Repaired,
+                        Ok(CreateStockPriceOutcome::Quarantined { reasons }) => {
+                            warn!("補抓的日 K 未通過驗證，已移至隔離區: {} - {}, 原因: {}", stock_code, gap.date, reasons.join("、"));
+                            PriceGapStatus::Quarantined
+                        },
+                        Err(e) => {
+                            error!("保存補抓的日 K 失敗: {} - {}, 錯誤: {}", stock_code, gap.date, e);
+                            PriceGapStatus::Failed
+                        }
+                    }
+                },
+                // 證交所有當月資料卻沒有這一天，表示當日無成交 (暫停交易)；
+                // Yahoo Finance 可能單純漏掉某天，不能據此判定，記為失敗留待重試
+                None if prices.iter().any(|p| p.source == PriceSource::Twse) => {
+                    info!("股票 {} 於 {} 無成交，不再視為缺漏", stock_code, gap.date);
+                    PriceGapStatus::NoTrade
+                },
+                None => PriceGapStatus::Failed,
+            };
+            price_gap_service.record_repair(gap, status).await?;
+        }
+        info!("補抓日 K 完成: {} {}-{:02}, 共 {} 筆缺漏", stock_code, gaps[0].date.year(), gaps[0].date.month() as u8, gaps.len());
+        
+        // 避免請求過於頻繁
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    
+    Ok(())
+}
+
+/// 爬取完整的 ISIN 上市櫃清單，與上次的快照比對後更新股票的上市狀態及名稱，並推播偵測到的異動
+async fn sync_stock_listing(
+    crawler_service: Arc<StockCrawlerService>,
+    stock_listing_service: Arc<StockListingService>,
+    notification_service: Arc<NotificationService>,
+    date: time::Date,
+) -> Result<(), Box<dyn Error>> {
+    let listings = crawler_service.crawl_isin_listing().await?;
+    let changes = stock_listing_service.sync_listing(&listings).await?;
+    
+    if !changes.is_empty() {
+        notification_service.send_universe_change_digest(date, &changes).await?;
+    }
+    
+    Ok(())
+}
+
 /// 啟動 Web 服務器
 async fn start_web_server(app: Router) -> Result<(), Box<dyn Error>> {
     let listener = TcpListener::bind("0.0.0.0:3000").await?;